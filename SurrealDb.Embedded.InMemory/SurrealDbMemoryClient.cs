@@ -9,6 +9,8 @@ namespace SurrealDb.Embedded.InMemory;
 
 public class SurrealDbMemoryClient : BaseSurrealDbClient
 {
+    private readonly SurrealDbEmbeddedEngine _engine;
+
     private const string ENDPOINT = "mem://";
 
     /// <summary>
@@ -41,13 +43,29 @@ public class SurrealDbMemoryClient : BaseSurrealDbClient
     {
         Uri = new Uri(ENDPOINT);
 
-        var engine = new SurrealDbEmbeddedEngine(options);
+        _engine = new SurrealDbEmbeddedEngine(options);
         InitializeAndSetProviderEngine(
-            engine,
+            _engine,
             parameters,
             configureCborOptions,
             loggerFactory,
             null
         );
     }
+
+    /// <summary>
+    /// Serves the database over the <c>/rpc</c> (WebSocket) and <c>/sql</c> (HTTP) endpoints of SurrealDB,
+    /// so that tools such as Surrealist can inspect it while debugging.
+    /// By default, the server listens on <c>127.0.0.1:8000</c> and requires clients to sign in.
+    /// </summary>
+    /// <param name="options">The configuration of the server.</param>
+    /// <param name="cancellationToken">The cancellationToken enables graceful cancellation of asynchronous operations</param>
+    /// <returns>The running server, stopped once disposed.</returns>
+    public Task<SurrealDbEmbeddedServer> ServeAsync(
+        SurrealDbEmbeddedServeOptions? options = null,
+        CancellationToken cancellationToken = default
+    )
+    {
+        return _engine.Serve(options, cancellationToken);
+    }
 }
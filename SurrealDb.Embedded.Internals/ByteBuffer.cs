@@ -1,9 +1,18 @@
-﻿namespace SurrealDb.Embedded.Internals;
+﻿using SurrealDb.Net.Exceptions.Serialization;
+
+namespace SurrealDb.Embedded.Internals;
 
 internal partial struct ByteBuffer
 {
     public readonly unsafe ReadOnlySpan<byte> AsReadOnly()
     {
-        return new ReadOnlySpan<byte>(ptr, length);
+        if (length > int.MaxValue)
+        {
+            throw new SurrealDbSerializationException(
+                $"The native buffer ({length} bytes) exceeds the maximum size of a .NET buffer."
+            );
+        }
+
+        return new ReadOnlySpan<byte>(ptr, (int)length);
     }
 }
@@ -0,0 +1,49 @@
+﻿using Dahomey.Cbor.Attributes;
+using SurrealDb.Net.Exceptions.Embedded;
+using SurrealDb.Net.Exceptions.Serialization;
+
+namespace SurrealDb.Embedded.Internals;
+
+/// <summary>
+/// Error payload sent by the native layer to the failure callback.
+/// </summary>
+internal sealed class EmbeddedError
+{
+    [CborProperty("kind")]
+    public SurrealDbEmbeddedErrorKind Kind { get; set; }
+
+    [CborProperty("code")]
+    public long? Code { get; set; }
+
+    [CborProperty("message")]
+    public string Message { get; set; } = string.Empty;
+
+    [CborProperty("details")]
+    public object? Details { get; set; }
+
+    public Exception ToException()
+    {
+        return Kind switch
+        {
+            SurrealDbEmbeddedErrorKind.Cancelled => new OperationCanceledException(Message),
+            SurrealDbEmbeddedErrorKind.Serialization => new SurrealDbSerializationException(
+                Message
+            ),
+            SurrealDbEmbeddedErrorKind.NotFound => new SurrealDbEmbeddedNotFoundException(
+                Code,
+                Message
+            ),
+            SurrealDbEmbeddedErrorKind.InvalidParams => new SurrealDbEmbeddedInvalidParamsException(
+                Code,
+                Message
+            ),
+            SurrealDbEmbeddedErrorKind.Permission => new SurrealDbEmbeddedPermissionException(
+                Code,
+                Message
+            ),
+            SurrealDbEmbeddedErrorKind.TransactionConflict =>
+                new SurrealDbEmbeddedTransactionConflictException(Code, Message),
+            _ => new SurrealDbEmbeddedException(Kind, Code, Message),
+        };
+    }
+}
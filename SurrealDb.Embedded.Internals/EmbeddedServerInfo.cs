@@ -0,0 +1,18 @@
+﻿using Dahomey.Cbor.Attributes;
+
+namespace SurrealDb.Embedded.Internals;
+
+/// <summary>
+/// Payload sent by the native layer to the success callback of <c>serve</c>.
+/// </summary>
+internal sealed class EmbeddedServerInfo
+{
+    /// <summary>
+    /// The id used to stop the server.
+    /// </summary>
+    [CborProperty("id")]
+    public ulong Id { get; set; }
+
+    [CborProperty("address")]
+    public string Address { get; set; } = string.Empty;
+}
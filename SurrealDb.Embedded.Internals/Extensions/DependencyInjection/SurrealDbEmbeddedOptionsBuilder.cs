@@ -12,6 +12,211 @@ public sealed class SurrealDbEmbeddedOptionsBuilder
         return this;
     }
 
+    public SurrealDbEmbeddedOptionsBuilder WithAuthentication(bool enabled)
+    {
+        EnsuresAuthCreated().Enabled = enabled;
+        return this;
+    }
+
+    public SurrealDbEmbeddedOptionsBuilder WithAuthentication(string username, string password)
+    {
+        var auth = EnsuresAuthCreated();
+        auth.Enabled = true;
+        auth.Username = username;
+        auth.Password = password;
+        return this;
+    }
+
+    public SurrealDbEmbeddedOptionsBuilder WithTransactionIdleTimeout(TimeSpan idleTimeout)
+    {
+        EnsuresTransactionsCreated().IdleTimeout = idleTimeout;
+        return this;
+    }
+
+    public SurrealDbEmbeddedOptionsBuilder WithTransactionMaxLifetime(TimeSpan maxLifetime)
+    {
+        EnsuresTransactionsCreated().MaxLifetime = maxLifetime;
+        return this;
+    }
+
+    public SurrealDbEmbeddedOptionsBuilder WithRestore(string backupPath)
+    {
+        _inner.Restore = backupPath;
+        return this;
+    }
+
+    public SurrealDbEmbeddedOptionsBuilder WithScripting(bool enabled)
+    {
+        EnsuresCapabilitiesCreated().Scripting = enabled;
+        return this;
+    }
+
+    public SurrealDbEmbeddedOptionsBuilder WithGuestAccess(bool enabled)
+    {
+        EnsuresCapabilitiesCreated().GuestAccess = enabled;
+        return this;
+    }
+
+    public SurrealDbEmbeddedOptionsBuilder WithFunctions(bool enabled)
+    {
+        var allow = EnsuresAllowCreated(EnsuresFunctionsCreated());
+        allow.Bool = enabled;
+        allow.Array = null;
+        return this;
+    }
+
+    public SurrealDbEmbeddedOptionsBuilder WithFunctions(IEnumerable<string> targets)
+    {
+        var allow = EnsuresAllowCreated(EnsuresFunctionsCreated());
+        allow.Bool = null;
+        allow.Array = targets;
+        return this;
+    }
+
+    public SurrealDbEmbeddedOptionsBuilder WithoutFunctions(bool enabled)
+    {
+        var deny = EnsuresDenyCreated(EnsuresFunctionsCreated());
+        deny.Bool = enabled;
+        deny.Array = null;
+        return this;
+    }
+
+    public SurrealDbEmbeddedOptionsBuilder WithoutFunctions(IEnumerable<string> targets)
+    {
+        var deny = EnsuresDenyCreated(EnsuresFunctionsCreated());
+        deny.Bool = null;
+        deny.Array = targets;
+        return this;
+    }
+
+    public SurrealDbEmbeddedOptionsBuilder WithNetworkTargets(bool enabled)
+    {
+        var allow = EnsuresAllowCreated(EnsuresNetworkTargetsCreated());
+        allow.Bool = enabled;
+        allow.Array = null;
+        return this;
+    }
+
+    public SurrealDbEmbeddedOptionsBuilder WithNetworkTargets(IEnumerable<string> targets)
+    {
+        var allow = EnsuresAllowCreated(EnsuresNetworkTargetsCreated());
+        allow.Bool = null;
+        allow.Array = targets;
+        return this;
+    }
+
+    public SurrealDbEmbeddedOptionsBuilder WithoutNetworkTargets(bool enabled)
+    {
+        var deny = EnsuresDenyCreated(EnsuresNetworkTargetsCreated());
+        deny.Bool = enabled;
+        deny.Array = null;
+        return this;
+    }
+
+    public SurrealDbEmbeddedOptionsBuilder WithoutNetworkTargets(IEnumerable<string> targets)
+    {
+        var deny = EnsuresDenyCreated(EnsuresNetworkTargetsCreated());
+        deny.Bool = null;
+        deny.Array = targets;
+        return this;
+    }
+
+    public SurrealDbEmbeddedOptionsBuilder WithRpcMethods(bool enabled)
+    {
+        var allow = EnsuresAllowCreated(EnsuresRpcMethodsCreated());
+        allow.Bool = enabled;
+        allow.Array = null;
+        return this;
+    }
+
+    public SurrealDbEmbeddedOptionsBuilder WithRpcMethods(IEnumerable<string> targets)
+    {
+        var allow = EnsuresAllowCreated(EnsuresRpcMethodsCreated());
+        allow.Bool = null;
+        allow.Array = targets;
+        return this;
+    }
+
+    public SurrealDbEmbeddedOptionsBuilder WithoutRpcMethods(bool enabled)
+    {
+        var deny = EnsuresDenyCreated(EnsuresRpcMethodsCreated());
+        deny.Bool = enabled;
+        deny.Array = null;
+        return this;
+    }
+
+    public SurrealDbEmbeddedOptionsBuilder WithoutRpcMethods(IEnumerable<string> targets)
+    {
+        var deny = EnsuresDenyCreated(EnsuresRpcMethodsCreated());
+        deny.Bool = null;
+        deny.Array = targets;
+        return this;
+    }
+
+    public SurrealDbEmbeddedOptionsBuilder WithHttpRoutes(bool enabled)
+    {
+        var allow = EnsuresAllowCreated(EnsuresHttpRoutesCreated());
+        allow.Bool = enabled;
+        allow.Array = null;
+        return this;
+    }
+
+    public SurrealDbEmbeddedOptionsBuilder WithHttpRoutes(IEnumerable<string> targets)
+    {
+        var allow = EnsuresAllowCreated(EnsuresHttpRoutesCreated());
+        allow.Bool = null;
+        allow.Array = targets;
+        return this;
+    }
+
+    public SurrealDbEmbeddedOptionsBuilder WithoutHttpRoutes(bool enabled)
+    {
+        var deny = EnsuresDenyCreated(EnsuresHttpRoutesCreated());
+        deny.Bool = enabled;
+        deny.Array = null;
+        return this;
+    }
+
+    public SurrealDbEmbeddedOptionsBuilder WithoutHttpRoutes(IEnumerable<string> targets)
+    {
+        var deny = EnsuresDenyCreated(EnsuresHttpRoutesCreated());
+        deny.Bool = null;
+        deny.Array = targets;
+        return this;
+    }
+
+    public SurrealDbEmbeddedOptionsBuilder WithArbitraryQuery(bool enabled)
+    {
+        var allow = EnsuresAllowCreated(EnsuresArbitraryQueryCreated());
+        allow.Bool = enabled;
+        allow.Array = null;
+        return this;
+    }
+
+    public SurrealDbEmbeddedOptionsBuilder WithArbitraryQuery(IEnumerable<string> targets)
+    {
+        var allow = EnsuresAllowCreated(EnsuresArbitraryQueryCreated());
+        allow.Bool = null;
+        allow.Array = targets;
+        return this;
+    }
+
+    public SurrealDbEmbeddedOptionsBuilder WithoutArbitraryQuery(bool enabled)
+    {
+        var deny = EnsuresDenyCreated(EnsuresArbitraryQueryCreated());
+        deny.Bool = enabled;
+        deny.Array = null;
+        return this;
+    }
+
+    public SurrealDbEmbeddedOptionsBuilder WithoutArbitraryQuery(IEnumerable<string> targets)
+    {
+        var deny = EnsuresDenyCreated(EnsuresArbitraryQueryCreated());
+        deny.Bool = null;
+        deny.Array = targets;
+        return this;
+    }
+
     public SurrealDbEmbeddedOptionsBuilder WithExperimentalFeatures(bool enabled)
     {
         EnsuresAllowExperimentalCreated().Bool = true;
@@ -42,41 +247,107 @@ public sealed class SurrealDbEmbeddedOptionsBuilder
 
     private SurrealDbEmbeddedTargetsConfig EnsuresAllowExperimentalCreated()
     {
-        var experimental = EnsuresExperimentalCreated();
+        return EnsuresAllowCreated(EnsuresExperimentalCreated());
+    }
 
-        if (experimental.Allow is not null)
-            return experimental.Allow;
+    private SurrealDbEmbeddedTargetsConfig EnsuresDenyExperimentalCreated()
+    {
+        return EnsuresDenyCreated(EnsuresExperimentalCreated());
+    }
+
+    private SurrealDbEmbeddedTargets EnsuresFunctionsCreated()
+    {
+        return EnsuresTargetsCreated(c => c.Functions, (c, t) => c.Functions = t);
+    }
+
+    private SurrealDbEmbeddedTargets EnsuresNetworkTargetsCreated()
+    {
+        return EnsuresTargetsCreated(c => c.NetworkTargets, (c, t) => c.NetworkTargets = t);
+    }
+
+    private SurrealDbEmbeddedTargets EnsuresRpcMethodsCreated()
+    {
+        return EnsuresTargetsCreated(c => c.RpcMethods, (c, t) => c.RpcMethods = t);
+    }
+
+    private SurrealDbEmbeddedTargets EnsuresHttpRoutesCreated()
+    {
+        return EnsuresTargetsCreated(c => c.HttpRoutes, (c, t) => c.HttpRoutes = t);
+    }
+
+    private SurrealDbEmbeddedTargets EnsuresArbitraryQueryCreated()
+    {
+        return EnsuresTargetsCreated(c => c.ArbitraryQuery, (c, t) => c.ArbitraryQuery = t);
+    }
+
+    private SurrealDbEmbeddedTargets EnsuresExperimentalCreated()
+    {
+        return EnsuresTargetsCreated(c => c.Experimental, (c, t) => c.Experimental = t);
+    }
+
+    private static SurrealDbEmbeddedTargetsConfig EnsuresAllowCreated(
+        SurrealDbEmbeddedTargets targets
+    )
+    {
+        if (targets.Allow is not null)
+            return targets.Allow;
 
         var allow = new SurrealDbEmbeddedTargetsConfig();
-        experimental.Allow = allow;
+        targets.Allow = allow;
 
         return allow;
     }
 
-    private SurrealDbEmbeddedTargetsConfig EnsuresDenyExperimentalCreated()
+    private static SurrealDbEmbeddedTargetsConfig EnsuresDenyCreated(
+        SurrealDbEmbeddedTargets targets
+    )
     {
-        var experimental = EnsuresExperimentalCreated();
-
-        if (experimental.Deny is not null)
-            return experimental.Deny;
+        if (targets.Deny is not null)
+            return targets.Deny;
 
         var deny = new SurrealDbEmbeddedTargetsConfig();
-        experimental.Deny = deny;
+        targets.Deny = deny;
 
         return deny;
     }
 
-    private SurrealDbEmbeddedTargets EnsuresExperimentalCreated()
+    private SurrealDbEmbeddedTargets EnsuresTargetsCreated(
+        Func<SurrealDbEmbeddedCapabilities, SurrealDbEmbeddedTargets?> get,
+        Action<SurrealDbEmbeddedCapabilities, SurrealDbEmbeddedTargets> set
+    )
     {
         var capabilities = EnsuresCapabilitiesCreated();
 
-        if (capabilities.Experimental is not null)
-            return capabilities.Experimental;
+        var existing = get(capabilities);
+        if (existing is not null)
+            return existing;
+
+        var targets = new SurrealDbEmbeddedTargets();
+        set(capabilities, targets);
+
+        return targets;
+    }
+
+    private SurrealDbEmbeddedAuth EnsuresAuthCreated()
+    {
+        if (_inner.Auth is not null)
+            return _inner.Auth;
+
+        var auth = new SurrealDbEmbeddedAuth();
+        _inner.Auth = auth;
+
+        return auth;
+    }
+
+    private SurrealDbEmbeddedTransactions EnsuresTransactionsCreated()
+    {
+        if (_inner.Transactions is not null)
+            return _inner.Transactions;
 
-        var experimental = new SurrealDbEmbeddedTargets();
-        capabilities.Experimental = experimental;
+        var transactions = new SurrealDbEmbeddedTransactions();
+        _inner.Transactions = transactions;
 
-        return experimental;
+        return transactions;
     }
 
     private SurrealDbEmbeddedCapabilities EnsuresCapabilitiesCreated()
@@ -92,6 +363,7 @@ public sealed class SurrealDbEmbeddedOptionsBuilder
 
     public SurrealDbEmbeddedOptions Build()
     {
+        _inner.Validate();
         return _inner;
     }
 }
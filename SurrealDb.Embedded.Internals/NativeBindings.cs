@@ -3,6 +3,10 @@ using System.Runtime.InteropServices;
 
 namespace SurrealDb.Embedded.Internals;
 
+/// <summary>
+/// The callbacks invoked by the native library.
+/// A buffer given to a callback is only read during the callback, then given back to the native library for reuse.
+/// </summary>
 internal static class NativeBindings
 {
     [UnmanagedCallersOnly(CallConvs = [typeof(CallConvCdecl)])]
@@ -11,15 +15,76 @@ internal static class NativeBindings
         GCHandle.FromIntPtr(ptr).Free();
     }
 
+    [UnmanagedCallersOnly(CallConvs = [typeof(CallConvCdecl)])]
+    public static void ReleasePinnedParams(nint ptr)
+    {
+        var handle = GCHandle.FromIntPtr(ptr);
+        (handle.Target as PinnedParams)!.Release();
+        handle.Free();
+    }
+
     [UnmanagedCallersOnly(CallConvs = [typeof(CallConvCdecl)])]
     public static unsafe void SuccessCallback(nint ptr, ByteBuffer* value)
     {
-        (GCHandle.FromIntPtr(ptr).Target as Action<ByteBuffer>)!.Invoke(*value);
+        try
+        {
+            (GCHandle.FromIntPtr(ptr).Target as Action<ByteBuffer>)!.Invoke(*value);
+        }
+        finally
+        {
+            NativeMethods.free_u8_buffer(value);
+        }
     }
 
     [UnmanagedCallersOnly(CallConvs = [typeof(CallConvCdecl)])]
     public static unsafe void FailureCallback(nint ptr, ByteBuffer* value)
     {
-        (GCHandle.FromIntPtr(ptr).Target as Action<ByteBuffer>)!.Invoke(*value);
+        try
+        {
+            (GCHandle.FromIntPtr(ptr).Target as Action<ByteBuffer>)!.Invoke(*value);
+        }
+        finally
+        {
+            NativeMethods.free_u8_buffer(value);
+        }
+    }
+
+    [UnmanagedCallersOnly(CallConvs = [typeof(CallConvCdecl)])]
+    public static unsafe void ChunkCallback(nint ptr, ByteBuffer* value)
+    {
+        try
+        {
+            (GCHandle.FromIntPtr(ptr).Target as Action<ByteBuffer>)!.Invoke(*value);
+        }
+        finally
+        {
+            NativeMethods.free_u8_buffer(value);
+        }
+    }
+
+    [UnmanagedCallersOnly(CallConvs = [typeof(CallConvCdecl)])]
+    public static unsafe void NotificationCallback(nint ptr, int id, ByteBuffer* value)
+    {
+        try
+        {
+            (GCHandle.FromIntPtr(ptr).Target as Action<int, ByteBuffer>)!.Invoke(id, *value);
+        }
+        finally
+        {
+            NativeMethods.free_u8_buffer(value);
+        }
+    }
+
+    [UnmanagedCallersOnly(CallConvs = [typeof(CallConvCdecl)])]
+    public static unsafe void LogCallback(nint ptr, ByteBuffer* value)
+    {
+        try
+        {
+            (GCHandle.FromIntPtr(ptr).Target as Action<ByteBuffer>)!.Invoke(*value);
+        }
+        finally
+        {
+            NativeMethods.free_u8_buffer(value);
+        }
     }
 }
@@ -0,0 +1,28 @@
+﻿using Dahomey.Cbor.Attributes;
+using Microsoft.Extensions.Logging;
+
+namespace SurrealDb.Embedded.Internals;
+
+/// <summary>
+/// Log event payload sent by the native layer to the log callback.
+/// </summary>
+internal sealed class NativeLogEvent
+{
+    [CborProperty("level")]
+    public LogLevel Level { get; set; }
+
+    [CborProperty("target")]
+    public string Target { get; set; } = string.Empty;
+
+    [CborProperty("message")]
+    public string Message { get; set; } = string.Empty;
+
+    [CborProperty("fields")]
+    public Dictionary<string, object?>? Fields { get; set; }
+
+    /// <summary>
+    /// The engine the event was emitted for, if any.
+    /// </summary>
+    [CborProperty("engine_id")]
+    public int? EngineId { get; set; }
+}
@@ -0,0 +1,244 @@
+﻿using System.Collections.Concurrent;
+using System.Runtime.InteropServices;
+using Microsoft.Extensions.Logging;
+using SurrealDb.Net.Internals.Cbor;
+
+namespace SurrealDb.Embedded.Internals;
+
+/// <summary>
+/// Forwards the log events of the native library to the <see cref="ILogger"/> of each embedded engine.
+/// </summary>
+internal static class NativeLogs
+{
+    private static readonly ConcurrentDictionary<int, ILogger> _loggers = new();
+    private static readonly object _lock = new();
+
+    private static bool _isRegistered;
+    private static string? _filterDirectives;
+
+    /// <summary>
+    /// Forwards the log events of an engine to the given logger,
+    /// registering the log callback of the native library the first time.
+    /// </summary>
+    public static void Register(int engineId, ILogger logger)
+    {
+        lock (_lock)
+        {
+            _loggers[engineId] = logger;
+
+            if (_isRegistered)
+            {
+                SetFilter(GetFilter());
+            }
+            else
+            {
+                RegisterAction(GetFilter());
+                _isRegistered = true;
+            }
+        }
+    }
+
+    /// <summary>
+    /// Stops forwarding the log events of an engine.
+    /// </summary>
+    public static void Unregister(int engineId)
+    {
+        lock (_lock)
+        {
+            if (_loggers.TryRemove(engineId, out _) && _isRegistered)
+            {
+                SetFilter(GetFilter());
+            }
+        }
+    }
+
+    /// <summary>
+    /// Overrides the filter directives, or restores the ones matching the loggers when <c>null</c>.
+    /// </summary>
+    public static void SetFilterDirectives(string? directives)
+    {
+        lock (_lock)
+        {
+            _filterDirectives = directives;
+
+            if (_isRegistered)
+            {
+                SetFilter(GetFilter());
+            }
+        }
+    }
+
+    /// <summary>
+    /// Forgets the log callback, released by the native library when its runtime is shut down.
+    /// </summary>
+    public static void Reset()
+    {
+        lock (_lock)
+        {
+            _isRegistered = false;
+        }
+    }
+
+    private static string GetFilter()
+    {
+        if (_loggers.IsEmpty)
+        {
+            return "off";
+        }
+
+        if (_filterDirectives is not null)
+        {
+            return _filterDirectives;
+        }
+
+        var minimumLevel = _loggers.Values.Min(GetMinimumLevel);
+        return minimumLevel switch
+        {
+            LogLevel.Trace => "trace",
+            LogLevel.Debug => "debug",
+            LogLevel.Information => "info",
+            LogLevel.Warning => "warn",
+            LogLevel.Error or LogLevel.Critical => "error",
+            _ => "off",
+        };
+    }
+
+    private static LogLevel GetMinimumLevel(ILogger logger)
+    {
+        for (var level = LogLevel.Trace; level < LogLevel.None; level++)
+        {
+            if (logger.IsEnabled(level))
+            {
+                return level;
+            }
+        }
+
+        return LogLevel.None;
+    }
+
+    private static void OnLog(ByteBuffer byteBuffer)
+    {
+        var logEvent = CborSerializer.Deserialize<NativeLogEvent>(
+            byteBuffer.AsReadOnly(),
+            SurrealDbCborOptions.GetCborSerializerOptions(null)
+        );
+
+        // Events not emitted on behalf of an engine go to the logger of any engine
+        var logger = logEvent.EngineId.HasValue
+            ? _loggers.GetValueOrDefault(logEvent.EngineId.Value)
+            : _loggers.Values.FirstOrDefault();
+
+        if (logger is null || !logger.IsEnabled(logEvent.Level))
+        {
+            return;
+        }
+
+        var state = logEvent.Fields ?? new Dictionary<string, object?>();
+        state["Target"] = logEvent.Target;
+        state["EngineId"] = logEvent.EngineId;
+
+        using (logger.BeginScope(state))
+        {
+            logger.Log(logEvent.Level, "{Message}", logEvent.Message);
+        }
+    }
+
+    private static unsafe void RegisterAction(string directives)
+    {
+        Action<ByteBuffer> log = OnLog;
+        var logHandle = GCHandle.Alloc(log);
+
+        var logAction = new LogAction()
+        {
+            handle = new RustGCHandle()
+            {
+                ptr = GCHandle.ToIntPtr(logHandle),
+                drop_callback = &NativeBindings.DropGcHandle,
+            },
+            callback = &NativeBindings.LogCallback,
+        };
+
+        Invoke(
+            (successAction, failureAction) =>
+            {
+                fixed (char* p = directives)
+                {
+                    NativeMethods.register_log_action(
+                        logAction,
+                        (ushort*)p,
+                        (nuint)directives.Length,
+                        successAction,
+                        failureAction
+                    );
+                }
+            }
+        );
+    }
+
+    private static unsafe void SetFilter(string directives)
+    {
+        Invoke(
+            (successAction, failureAction) =>
+            {
+                fixed (char* p = directives)
+                {
+                    NativeMethods.set_log_filter(
+                        (ushort*)p,
+                        (nuint)directives.Length,
+                        successAction,
+                        failureAction
+                    );
+                }
+            }
+        );
+    }
+
+    private static void Invoke(Action<SuccessAction, FailureAction> call)
+    {
+        Exception? exception = null;
+
+        // Both callbacks are invoked synchronously
+        Action<ByteBuffer> success = (_) => { };
+        Action<ByteBuffer> fail = (byteBuffer) =>
+        {
+            var error = CborSerializer.Deserialize<EmbeddedError>(
+                byteBuffer.AsReadOnly(),
+                SurrealDbCborOptions.GetCborSerializerOptions(null)
+            );
+            exception = error.ToException();
+        };
+
+        var successHandle = GCHandle.Alloc(success);
+        var failureHandle = GCHandle.Alloc(fail);
+
+        unsafe
+        {
+            var successAction = new SuccessAction()
+            {
+                handle = new RustGCHandle()
+                {
+                    ptr = GCHandle.ToIntPtr(successHandle),
+                    drop_callback = &NativeBindings.DropGcHandle,
+                },
+                callback = &NativeBindings.SuccessCallback,
+            };
+
+            var failureAction = new FailureAction()
+            {
+                handle = new RustGCHandle()
+                {
+                    ptr = GCHandle.ToIntPtr(failureHandle),
+                    drop_callback = &NativeBindings.DropGcHandle,
+                },
+                callback = &NativeBindings.FailureCallback,
+            };
+
+            call(successAction, failureAction);
+        }
+
+        if (exception is not null)
+        {
+            throw exception;
+        }
+    }
+}
@@ -0,0 +1,347 @@
+﻿using System.Diagnostics;
+using System.Diagnostics.Metrics;
+using System.Runtime.InteropServices;
+using SurrealDb.Net.Internals.Cbor;
+
+namespace SurrealDb.Embedded.Internals;
+
+/// <summary>
+/// Publishes the metrics of the native library through a <see cref="Meter"/> named after the assembly
+/// (e.g. <c>SurrealDb.Embedded.InMemory</c>), whose instruments are observed from <c>get_metrics</c>.
+/// </summary>
+internal static class NativeMetrics
+{
+    private const string ENGINE_ID_TAG = "surrealdb.engine.id";
+    private const string REQUEST_NAME_TAG = "surrealdb.request.name";
+
+    /// <summary>
+    /// The instruments observed by a single collection share the same snapshot.
+    /// </summary>
+    private const int SNAPSHOT_MAX_AGE_MS = 1000;
+    private const int SNAPSHOT_TIMEOUT_MS = 5000;
+
+    public static readonly string MeterName = typeof(NativeMetrics).Assembly.GetName().Name!;
+
+    private static readonly object _lock = new();
+
+    private static Meter? _meter;
+    private static NativeMetricsSnapshot? _snapshot;
+    private static NativeBufferPoolStats? _bufferPoolStats;
+    private static long _snapshotTimestamp;
+
+    /// <summary>
+    /// Creates the meter and its instruments the first time.
+    /// </summary>
+    public static void EnsureCreated()
+    {
+        lock (_lock)
+        {
+            if (_meter is not null)
+            {
+                return;
+            }
+
+            _meter = new Meter(MeterName);
+            CreateInstruments(_meter);
+        }
+    }
+
+    private static void CreateInstruments(Meter meter)
+    {
+        meter.CreateObservableUpDownCounter(
+            "surrealdb.embedded.sessions",
+            () =>
+                ObserveEach(snapshot =>
+                    snapshot.Engines.Select(engine => Measure(engine.Sessions, engine))
+                ),
+            "{session}",
+            "The sessions of each engine, including the default session."
+        );
+        meter.CreateObservableUpDownCounter(
+            "surrealdb.embedded.transactions",
+            () =>
+                ObserveEach(snapshot =>
+                    snapshot.Engines.Select(engine => Measure(engine.Transactions, engine))
+                ),
+            "{transaction}",
+            "The open transactions of each engine."
+        );
+        meter.CreateObservableUpDownCounter(
+            "surrealdb.embedded.live_queries",
+            () =>
+                ObserveEach(snapshot =>
+                    snapshot.Engines.Select(engine => Measure(engine.LiveQueries, engine))
+                ),
+            "{live_query}",
+            "The live queries of each engine."
+        );
+        meter.CreateObservableUpDownCounter(
+            "surrealdb.embedded.active_requests",
+            () =>
+                ObserveEach(snapshot =>
+                    snapshot.Engines.Select(engine => Measure(engine.Requests, engine))
+                ),
+            "{request}",
+            "The requests in flight on each engine."
+        );
+
+        meter.CreateObservableCounter(
+            "surrealdb.embedded.requests",
+            () =>
+                ObserveEach(snapshot =>
+                    snapshot.Latencies.Select(latency => Measure(latency.Value.Count, latency.Key))
+                ),
+            "{request}",
+            "The completed requests, per request name."
+        );
+        meter.CreateObservableCounter(
+            "surrealdb.embedded.request.duration",
+            () =>
+                ObserveEach(snapshot =>
+                    snapshot.Latencies.Select(latency =>
+                        Measure(ToSeconds(latency.Value.TotalMicroseconds), latency.Key)
+                    )
+                ),
+            "s",
+            "The total execution time of the completed requests, per request name."
+        );
+
+        meter.CreateObservableCounter(
+            "surrealdb.embedded.bytes_in",
+            () => Observe(snapshot => snapshot.BytesIn),
+            "By",
+            "The bytes sent by .NET to the native library."
+        );
+        meter.CreateObservableCounter(
+            "surrealdb.embedded.bytes_out",
+            () => Observe(snapshot => snapshot.BytesOut),
+            "By",
+            "The bytes sent by the native library to .NET."
+        );
+
+        meter.CreateObservableUpDownCounter(
+            "surrealdb.embedded.runtime.workers",
+            () => Observe(snapshot => snapshot.Runtime.Workers),
+            "{thread}",
+            "The worker threads of the native runtime."
+        );
+        meter.CreateObservableUpDownCounter(
+            "surrealdb.embedded.runtime.alive_tasks",
+            () => Observe(snapshot => snapshot.Runtime.AliveTasks),
+            "{task}",
+            "The tasks alive in the native runtime."
+        );
+        meter.CreateObservableUpDownCounter(
+            "surrealdb.embedded.runtime.global_queue_depth",
+            () => Observe(snapshot => snapshot.Runtime.GlobalQueueDepth),
+            "{task}",
+            "The tasks waiting in the global queue of the native runtime."
+        );
+        meter.CreateObservableCounter(
+            "surrealdb.embedded.runtime.busy_duration",
+            () => Observe(snapshot => ToSeconds(snapshot.Runtime.BusyDurationMicroseconds)),
+            "s",
+            "The time spent busy by the worker threads of the native runtime."
+        );
+        meter.CreateObservableCounter(
+            "surrealdb.embedded.runtime.park_count",
+            () => Observe(snapshot => snapshot.Runtime.ParkCount),
+            "{park}",
+            "The times the worker threads of the native runtime were parked."
+        );
+
+        meter.CreateObservableCounter(
+            "surrealdb.embedded.buffer_pool.hits",
+            () => ObserveBufferPool(stats => stats.Hits),
+            "{buffer}",
+            "The result buffers taken from the pool."
+        );
+        meter.CreateObservableCounter(
+            "surrealdb.embedded.buffer_pool.misses",
+            () => ObserveBufferPool(stats => stats.Misses),
+            "{buffer}",
+            "The result buffers allocated as none could be taken from the pool."
+        );
+        meter.CreateObservableCounter(
+            "surrealdb.embedded.buffer_pool.released",
+            () => ObserveBufferPool(stats => stats.Released),
+            "{buffer}",
+            "The result buffers given back to the pool."
+        );
+        meter.CreateObservableCounter(
+            "surrealdb.embedded.buffer_pool.discarded",
+            () => ObserveBufferPool(stats => stats.Discarded),
+            "{buffer}",
+            "The result buffers freed instead of being given back to the pool."
+        );
+        meter.CreateObservableUpDownCounter(
+            "surrealdb.embedded.buffer_pool.buffers",
+            () => ObserveBufferPool(stats => stats.PooledBuffers),
+            "{buffer}",
+            "The result buffers kept in the pool."
+        );
+        meter.CreateObservableUpDownCounter(
+            "surrealdb.embedded.buffer_pool.size",
+            () => ObserveBufferPool(stats => stats.PooledBytes),
+            "By",
+            "The capacity of the result buffers kept in the pool."
+        );
+    }
+
+    private static Measurement<T> Measure<T>(T value, NativeEngineMetrics engine)
+        where T : struct
+    {
+        return new Measurement<T>(
+            value,
+            new KeyValuePair<string, object?>(ENGINE_ID_TAG, engine.Id)
+        );
+    }
+
+    private static Measurement<T> Measure<T>(T value, string requestName)
+        where T : struct
+    {
+        return new Measurement<T>(
+            value,
+            new KeyValuePair<string, object?>(REQUEST_NAME_TAG, requestName)
+        );
+    }
+
+    private static double ToSeconds(long microseconds)
+    {
+        return microseconds / 1_000_000d;
+    }
+
+    private static IEnumerable<Measurement<T>> Observe<T>(Func<NativeMetricsSnapshot, T> observe)
+        where T : struct
+    {
+        var (snapshot, _) = GetSnapshot();
+        return snapshot is null ? [] : [new Measurement<T>(observe(snapshot))];
+    }
+
+    private static IEnumerable<Measurement<T>> ObserveEach<T>(
+        Func<NativeMetricsSnapshot, IEnumerable<Measurement<T>>> observe
+    )
+        where T : struct
+    {
+        var (snapshot, _) = GetSnapshot();
+        return snapshot is null ? [] : observe(snapshot);
+    }
+
+    private static IEnumerable<Measurement<long>> ObserveBufferPool(
+        Func<NativeBufferPoolStats, long> observe
+    )
+    {
+        var (_, stats) = GetSnapshot();
+        return stats is null ? [] : [new Measurement<long>(observe(stats))];
+    }
+
+    /// <summary>
+    /// Gets the latest metrics of the native library, fetched again once older than <see cref="SNAPSHOT_MAX_AGE_MS"/>.
+    /// The metrics are missing if the native runtime has been shut down.
+    /// </summary>
+    private static (NativeMetricsSnapshot?, NativeBufferPoolStats?) GetSnapshot()
+    {
+        lock (_lock)
+        {
+            if (
+                _snapshotTimestamp == 0
+                || Stopwatch.GetElapsedTime(_snapshotTimestamp).TotalMilliseconds
+                    > SNAPSHOT_MAX_AGE_MS
+            )
+            {
+                _snapshot = FetchSnapshot();
+                _bufferPoolStats = FetchBufferPoolStats();
+                _snapshotTimestamp = Stopwatch.GetTimestamp();
+            }
+
+            return (_snapshot, _bufferPoolStats);
+        }
+    }
+
+    private static NativeMetricsSnapshot? FetchSnapshot()
+    {
+        var taskCompletionSource = new TaskCompletionSource<NativeMetricsSnapshot?>(
+            TaskCreationOptions.RunContinuationsAsynchronously
+        );
+
+        Action<ByteBuffer> success = (byteBuffer) =>
+        {
+            try
+            {
+                taskCompletionSource.TrySetResult(
+                    CborSerializer.Deserialize<NativeMetricsSnapshot>(
+                        byteBuffer.AsReadOnly(),
+                        SurrealDbCborOptions.GetCborSerializerOptions(null)
+                    )
+                );
+            }
+            catch (Exception e)
+            {
+                taskCompletionSource.TrySetException(e);
+            }
+        };
+        Action<ByteBuffer> fail = (_) => taskCompletionSource.TrySetResult(null);
+
+        var (successAction, failureAction) = CreateActions(success, fail);
+        var requestId = NativeMethods.get_metrics(successAction, failureAction);
+
+        if (!taskCompletionSource.Task.Wait(SNAPSHOT_TIMEOUT_MS))
+        {
+            NativeMethods.cancel_request(requestId);
+            return null;
+        }
+
+        return taskCompletionSource.Task.GetAwaiter().GetResult();
+    }
+
+    private static NativeBufferPoolStats? FetchBufferPoolStats()
+    {
+        NativeBufferPoolStats? stats = null;
+
+        // Both callbacks are invoked synchronously
+        Action<ByteBuffer> success = (byteBuffer) =>
+        {
+            stats = CborSerializer.Deserialize<NativeBufferPoolStats>(
+                byteBuffer.AsReadOnly(),
+                SurrealDbCborOptions.GetCborSerializerOptions(null)
+            );
+        };
+        Action<ByteBuffer> fail = (_) => { };
+
+        var (successAction, failureAction) = CreateActions(success, fail);
+        NativeMethods.get_buffer_pool_stats(successAction, failureAction);
+
+        return stats;
+    }
+
+    private static unsafe (SuccessAction, FailureAction) CreateActions(
+        Action<ByteBuffer> success,
+        Action<ByteBuffer> fail
+    )
+    {
+        var successHandle = GCHandle.Alloc(success);
+        var failureHandle = GCHandle.Alloc(fail);
+
+        var successAction = new SuccessAction()
+        {
+            handle = new RustGCHandle()
+            {
+                ptr = GCHandle.ToIntPtr(successHandle),
+                drop_callback = &NativeBindings.DropGcHandle,
+            },
+            callback = &NativeBindings.SuccessCallback,
+        };
+
+        var failureAction = new FailureAction()
+        {
+            handle = new RustGCHandle()
+            {
+                ptr = GCHandle.ToIntPtr(failureHandle),
+                drop_callback = &NativeBindings.DropGcHandle,
+            },
+            callback = &NativeBindings.FailureCallback,
+        };
+
+        return (successAction, failureAction);
+    }
+}
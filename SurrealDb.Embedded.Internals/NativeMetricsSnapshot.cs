@@ -0,0 +1,99 @@
+﻿using Dahomey.Cbor.Attributes;
+
+namespace SurrealDb.Embedded.Internals;
+
+/// <summary>
+/// Metrics payload sent by the native layer to the success callback of <c>get_metrics</c>.
+/// </summary>
+internal sealed class NativeMetricsSnapshot
+{
+    [CborProperty("engines")]
+    public List<NativeEngineMetrics> Engines { get; set; } = [];
+
+    /// <summary>
+    /// The count and total execution time of each request, per request name.
+    /// </summary>
+    [CborProperty("latencies")]
+    public Dictionary<string, NativeLatencyMetrics> Latencies { get; set; } = [];
+
+    [CborProperty("bytes_in")]
+    public long BytesIn { get; set; }
+
+    [CborProperty("bytes_out")]
+    public long BytesOut { get; set; }
+
+    [CborProperty("runtime")]
+    public NativeRuntimeMetrics Runtime { get; set; } = new();
+}
+
+internal sealed class NativeEngineMetrics
+{
+    [CborProperty("id")]
+    public int Id { get; set; }
+
+    [CborProperty("sessions")]
+    public long Sessions { get; set; }
+
+    [CborProperty("transactions")]
+    public long Transactions { get; set; }
+
+    [CborProperty("live_queries")]
+    public long LiveQueries { get; set; }
+
+    /// <summary>
+    /// The requests in flight.
+    /// </summary>
+    [CborProperty("requests")]
+    public long Requests { get; set; }
+}
+
+internal sealed class NativeLatencyMetrics
+{
+    [CborProperty("count")]
+    public long Count { get; set; }
+
+    [CborProperty("total_us")]
+    public long TotalMicroseconds { get; set; }
+}
+
+internal sealed class NativeRuntimeMetrics
+{
+    [CborProperty("workers")]
+    public long Workers { get; set; }
+
+    [CborProperty("alive_tasks")]
+    public long AliveTasks { get; set; }
+
+    [CborProperty("global_queue_depth")]
+    public long GlobalQueueDepth { get; set; }
+
+    [CborProperty("busy_duration_us")]
+    public long BusyDurationMicroseconds { get; set; }
+
+    [CborProperty("park_count")]
+    public long ParkCount { get; set; }
+}
+
+/// <summary>
+/// Statistics payload sent by the native layer to the success callback of <c>get_buffer_pool_stats</c>.
+/// </summary>
+internal sealed class NativeBufferPoolStats
+{
+    [CborProperty("hits")]
+    public long Hits { get; set; }
+
+    [CborProperty("misses")]
+    public long Misses { get; set; }
+
+    [CborProperty("released")]
+    public long Released { get; set; }
+
+    [CborProperty("discarded")]
+    public long Discarded { get; set; }
+
+    [CborProperty("pooled_buffers")]
+    public long PooledBuffers { get; set; }
+
+    [CborProperty("pooled_bytes")]
+    public long PooledBytes { get; set; }
+}
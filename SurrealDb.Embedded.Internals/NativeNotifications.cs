@@ -0,0 +1,80 @@
+﻿using System.Collections.Concurrent;
+using System.Runtime.InteropServices;
+
+namespace SurrealDb.Embedded.Internals;
+
+/// <summary>
+/// Forwards the live query notifications of the native library to the embedded engine they belong to.
+/// </summary>
+internal static class NativeNotifications
+{
+    private static readonly ConcurrentDictionary<int, Action<ReadOnlyMemory<byte>>> _handlers =
+        new();
+    private static readonly object _lock = new();
+
+    private static bool _isRegistered;
+
+    /// <summary>
+    /// Forwards the notifications of an engine to the given handler,
+    /// registering the notification callback of the native library the first time.
+    /// </summary>
+    public static void Register(int engineId, Action<ReadOnlyMemory<byte>> handler)
+    {
+        lock (_lock)
+        {
+            _handlers[engineId] = handler;
+
+            if (!_isRegistered)
+            {
+                RegisterAction();
+                _isRegistered = true;
+            }
+        }
+    }
+
+    /// <summary>
+    /// Stops forwarding the notifications of an engine.
+    /// </summary>
+    public static void Unregister(int engineId)
+    {
+        _handlers.TryRemove(engineId, out _);
+    }
+
+    /// <summary>
+    /// Forgets the notification callback, released by the native library when its runtime is shut down.
+    /// </summary>
+    public static void Reset()
+    {
+        lock (_lock)
+        {
+            _isRegistered = false;
+        }
+    }
+
+    private static void OnNotification(int engineId, ByteBuffer byteBuffer)
+    {
+        if (_handlers.TryGetValue(engineId, out var handler))
+        {
+            // The native buffer is only valid for the duration of the callback
+            handler(byteBuffer.AsReadOnly().ToArray());
+        }
+    }
+
+    private static unsafe void RegisterAction()
+    {
+        Action<int, ByteBuffer> notify = OnNotification;
+        var notifyHandle = GCHandle.Alloc(notify);
+
+        var notificationAction = new NotificationAction()
+        {
+            handle = new RustGCHandle()
+            {
+                ptr = GCHandle.ToIntPtr(notifyHandle),
+                drop_callback = &NativeBindings.DropGcHandle,
+            },
+            callback = &NativeBindings.NotificationCallback,
+        };
+
+        NativeMethods.register_notification_action(notificationAction);
+    }
+}
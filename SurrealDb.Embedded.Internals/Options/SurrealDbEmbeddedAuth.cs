@@ -0,0 +1,27 @@
+﻿using Dahomey.Cbor.Attributes;
+
+namespace SurrealDb.Embedded.Options;
+
+public sealed class SurrealDbEmbeddedAuth
+{
+    /// <summary>
+    /// Enables authentication, so that permissions are enforced as they would be on a server.
+    /// </summary>
+    [CborProperty("enabled")]
+    [CborIgnoreIfDefault]
+    public bool? Enabled { get; internal set; }
+
+    /// <summary>
+    /// Username of the root user created on connect, if no root user exists yet.
+    /// </summary>
+    [CborProperty("username")]
+    [CborIgnoreIfDefault]
+    public string? Username { get; internal set; }
+
+    /// <summary>
+    /// Password of the root user created on connect, if no root user exists yet.
+    /// </summary>
+    [CborProperty("password")]
+    [CborIgnoreIfDefault]
+    public string? Password { get; internal set; }
+}
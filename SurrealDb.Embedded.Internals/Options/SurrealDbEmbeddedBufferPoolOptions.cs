@@ -0,0 +1,20 @@
+﻿using Dahomey.Cbor.Attributes;
+
+namespace SurrealDb.Embedded.Options;
+
+public sealed class SurrealDbEmbeddedBufferPoolOptions
+{
+    /// <summary>
+    /// Largest buffer (in bytes) kept for reuse, larger buffers being freed once read. Defaults to 64 KiB.
+    /// </summary>
+    [CborProperty("max_buffer_size")]
+    [CborIgnoreIfDefault]
+    public long? MaxBufferSize { get; set; }
+
+    /// <summary>
+    /// Maximum number of buffers kept for reuse, <c>0</c> disabling the pool. Defaults to 64.
+    /// </summary>
+    [CborProperty("max_buffers")]
+    [CborIgnoreIfDefault]
+    public int? MaxBuffers { get; set; }
+}
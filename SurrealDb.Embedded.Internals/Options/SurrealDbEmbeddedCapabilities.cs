@@ -4,6 +4,55 @@ namespace SurrealDb.Embedded.Options;
 
 public sealed class SurrealDbEmbeddedCapabilities
 {
+    /// <summary>
+    /// Enables the execution of embedded scripting functions.
+    /// </summary>
+    [CborProperty("scripting")]
+    [CborIgnoreIfDefault]
+    public bool? Scripting { get; internal set; }
+
+    /// <summary>
+    /// Allows guest (unauthenticated) users to execute queries.
+    /// </summary>
+    [CborProperty("guest_access")]
+    [CborIgnoreIfDefault]
+    public bool? GuestAccess { get; internal set; }
+
+    /// <summary>
+    /// Targets configuration for functions.
+    /// </summary>
+    [CborProperty("functions")]
+    [CborIgnoreIfDefault]
+    public SurrealDbEmbeddedTargets? Functions { get; internal set; }
+
+    /// <summary>
+    /// Targets configuration for network access (e.g. <c>http::get</c>).
+    /// </summary>
+    [CborProperty("network_targets")]
+    [CborIgnoreIfDefault]
+    public SurrealDbEmbeddedTargets? NetworkTargets { get; internal set; }
+
+    /// <summary>
+    /// Targets configuration for RPC methods.
+    /// </summary>
+    [CborProperty("rpc_methods")]
+    [CborIgnoreIfDefault]
+    public SurrealDbEmbeddedTargets? RpcMethods { get; internal set; }
+
+    /// <summary>
+    /// Targets configuration for HTTP routes.
+    /// </summary>
+    [CborProperty("http_routes")]
+    [CborIgnoreIfDefault]
+    public SurrealDbEmbeddedTargets? HttpRoutes { get; internal set; }
+
+    /// <summary>
+    /// Targets configuration for arbitrary queries (e.g. <c>guest</c>, <c>record</c>, <c>system</c>).
+    /// </summary>
+    [CborProperty("arbitrary_query")]
+    [CborIgnoreIfDefault]
+    public SurrealDbEmbeddedTargets? ArbitraryQuery { get; internal set; }
+
     /// <summary>
     /// Targets configuration for Experimental features.
     /// </summary>
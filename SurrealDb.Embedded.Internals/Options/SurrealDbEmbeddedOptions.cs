@@ -1,5 +1,9 @@
-﻿using Dahomey.Cbor.Attributes;
+﻿using System.Buffers;
+using System.Runtime.InteropServices;
+using Dahomey.Cbor.Attributes;
 using Microsoft.Extensions.DependencyInjection;
+using SurrealDb.Embedded.Internals;
+using SurrealDb.Net.Internals.Cbor;
 
 namespace SurrealDb.Embedded.Options;
 
@@ -7,6 +11,12 @@ public sealed class SurrealDbEmbeddedOptions
 {
     /// <summary>
     /// Enables strict mode of the SurrealDB embedded engine.
+    /// In strict mode, switching to an undefined namespace or database fails instead of creating it,
+    /// whether with the "use" method or with the <c>USE</c> statements of queries and imports.
+    /// Databases must be defined as <c>STRICT</c>, so undefined tables are rejected as well.
+    /// Statements that cannot be checked beforehand are rejected: <c>USE</c> statements not naming their namespace
+    /// and database, and the removal of a namespace or database.
+    /// Imports from a <see cref="System.IO.Stream"/> or a file are rejected too, as their statements are only read while running.
     /// </summary>
     [CborProperty("strict")]
     [CborIgnoreIfDefault]
@@ -19,8 +29,95 @@ public sealed class SurrealDbEmbeddedOptions
     [CborIgnoreIfDefault]
     public SurrealDbEmbeddedCapabilities? Capabilities { get; internal set; }
 
+    /// <summary>
+    /// Authentication configuration of the SurrealDB embedded engine.
+    /// </summary>
+    [CborProperty("auth")]
+    [CborIgnoreIfDefault]
+    public SurrealDbEmbeddedAuth? Auth { get; internal set; }
+
+    /// <summary>
+    /// Limits applied to transactions, so that abandoned transactions are eventually cancelled.
+    /// </summary>
+    [CborProperty("transactions")]
+    [CborIgnoreIfDefault]
+    public SurrealDbEmbeddedTransactions? Transactions { get; internal set; }
+
+    /// <summary>
+    /// Path of a backup directory, replacing the data of the RocksDB or SurrealKV engine before connecting.
+    /// </summary>
+    [CborProperty("restore")]
+    [CborIgnoreIfDefault]
+    public string? Restore { get; internal set; }
+
     public static SurrealDbEmbeddedOptionsBuilder Create()
     {
         return new SurrealDbEmbeddedOptionsBuilder();
     }
+
+    /// <summary>
+    /// Validates the options with the native library, without opening a datastore.
+    /// Every invalid entry is reported at once.
+    /// </summary>
+    internal void Validate()
+    {
+        var cborOptions = SurrealDbCborOptions.GetCborSerializerOptions(null);
+
+        var buffer = new ArrayBufferWriter<byte>();
+        CborSerializer.Serialize(this, buffer, cborOptions);
+
+        Exception? exception = null;
+
+        // Both callbacks are invoked synchronously
+        Action<ByteBuffer> success = (_) => { };
+        Action<ByteBuffer> fail = (byteBuffer) =>
+        {
+            var error = CborSerializer.Deserialize<EmbeddedError>(
+                byteBuffer.AsReadOnly(),
+                cborOptions
+            );
+            exception = error.ToException();
+        };
+
+        var successHandle = GCHandle.Alloc(success);
+        var failureHandle = GCHandle.Alloc(fail);
+
+        unsafe
+        {
+            var successAction = new SuccessAction()
+            {
+                handle = new RustGCHandle()
+                {
+                    ptr = GCHandle.ToIntPtr(successHandle),
+                    drop_callback = &NativeBindings.DropGcHandle,
+                },
+                callback = &NativeBindings.SuccessCallback,
+            };
+
+            var failureAction = new FailureAction()
+            {
+                handle = new RustGCHandle()
+                {
+                    ptr = GCHandle.ToIntPtr(failureHandle),
+                    drop_callback = &NativeBindings.DropGcHandle,
+                },
+                callback = &NativeBindings.FailureCallback,
+            };
+
+            fixed (byte* payload = buffer.WrittenSpan)
+            {
+                NativeMethods.validate_options(
+                    payload,
+                    (nuint)buffer.WrittenCount,
+                    successAction,
+                    failureAction
+                );
+            }
+        }
+
+        if (exception is not null)
+        {
+            throw exception;
+        }
+    }
 }
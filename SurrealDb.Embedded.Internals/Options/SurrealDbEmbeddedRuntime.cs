@@ -0,0 +1,141 @@
+﻿using System.Buffers;
+using System.Runtime.InteropServices;
+using SurrealDb.Embedded.Internals;
+using SurrealDb.Net.Internals.Cbor;
+
+namespace SurrealDb.Embedded.Options;
+
+/// <summary>
+/// The native runtime shared by every embedded engine.
+/// </summary>
+public static class SurrealDbEmbeddedRuntime
+{
+    /// <summary>
+    /// The name of the <see cref="System.Diagnostics.Metrics.Meter"/> publishing the metrics of the native library
+    /// (engines, requests, runtime and buffer pool), which is the name of the engine assembly (e.g. <c>SurrealDb.Embedded.InMemory</c>).
+    /// </summary>
+    public static string MeterName => NativeMetrics.MeterName;
+
+    /// <summary>
+    /// Creates the native runtime with specific options.
+    /// Must be called before the first embedded engine is created, as the runtime is only created once.
+    /// </summary>
+    /// <param name="options">The configuration of the native runtime.</param>
+    public static void Configure(SurrealDbEmbeddedRuntimeOptions options)
+    {
+        Create(options);
+    }
+
+    /// <summary>
+    /// Shuts the native runtime down, disposing every embedded engine first.
+    /// Allows the assembly to be unloaded (e.g. from a collectible <c>AssemblyLoadContext</c>).
+    /// </summary>
+    public static void Shutdown()
+    {
+        NativeMethods.shutdown_global_runtime();
+        NativeLogs.Reset();
+        NativeNotifications.Reset();
+    }
+
+    /// <summary>
+    /// Changes the filter of the log events forwarded by the native library (e.g. <c>"warn"</c> or <c>"surrealdb=debug"</c>).
+    /// By default, the filter matches the minimum level enabled for the <c>SurrealDB.Embedded</c> logger category.
+    /// </summary>
+    /// <param name="directives">The filter directives, or <c>null</c> to restore the default filter.</param>
+    public static void SetLogFilter(string? directives)
+    {
+        NativeLogs.SetFilterDirectives(directives);
+    }
+
+    /// <summary>
+    /// Configures the pool keeping the native buffers of results for reuse, once read.
+    /// Can be called at any time, buffers that no longer fit the new options being freed.
+    /// </summary>
+    /// <param name="options">The configuration of the pool, or <c>null</c> to restore the default configuration.</param>
+    public static void ConfigureBufferPool(SurrealDbEmbeddedBufferPoolOptions? options)
+    {
+        unsafe
+        {
+            Invoke(options, &NativeMethods.configure_buffer_pool);
+        }
+    }
+
+    internal static void EnsureCreated()
+    {
+        Create(null);
+    }
+
+    private static void Create(SurrealDbEmbeddedRuntimeOptions? options)
+    {
+        unsafe
+        {
+            Invoke(options, &NativeMethods.create_global_runtime);
+        }
+
+        NativeMetrics.EnsureCreated();
+    }
+
+    /// <summary>
+    /// Calls a native method taking CBOR options, which invokes its callbacks synchronously.
+    /// </summary>
+    private static unsafe void Invoke<TOptions>(
+        TOptions? options,
+        delegate*<byte*, nuint, SuccessAction, FailureAction, void> call
+    )
+        where TOptions : class
+    {
+        var cborOptions = SurrealDbCborOptions.GetCborSerializerOptions(null);
+
+        var buffer = new ArrayBufferWriter<byte>();
+        if (options is not null)
+        {
+            CborSerializer.Serialize(options, buffer, cborOptions);
+        }
+
+        Exception? exception = null;
+
+        // Both callbacks are invoked synchronously
+        Action<ByteBuffer> success = (_) => { };
+        Action<ByteBuffer> fail = (byteBuffer) =>
+        {
+            var error = CborSerializer.Deserialize<EmbeddedError>(
+                byteBuffer.AsReadOnly(),
+                cborOptions
+            );
+            exception = error.ToException();
+        };
+
+        var successHandle = GCHandle.Alloc(success);
+        var failureHandle = GCHandle.Alloc(fail);
+
+        var successAction = new SuccessAction()
+        {
+            handle = new RustGCHandle()
+            {
+                ptr = GCHandle.ToIntPtr(successHandle),
+                drop_callback = &NativeBindings.DropGcHandle,
+            },
+            callback = &NativeBindings.SuccessCallback,
+        };
+
+        var failureAction = new FailureAction()
+        {
+            handle = new RustGCHandle()
+            {
+                ptr = GCHandle.ToIntPtr(failureHandle),
+                drop_callback = &NativeBindings.DropGcHandle,
+            },
+            callback = &NativeBindings.FailureCallback,
+        };
+
+        fixed (byte* payload = buffer.WrittenSpan)
+        {
+            call(payload, (nuint)buffer.WrittenCount, successAction, failureAction);
+        }
+
+        if (exception is not null)
+        {
+            throw exception;
+        }
+    }
+}
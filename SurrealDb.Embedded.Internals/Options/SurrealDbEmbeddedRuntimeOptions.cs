@@ -0,0 +1,41 @@
+﻿using Dahomey.Cbor.Attributes;
+
+namespace SurrealDb.Embedded.Options;
+
+public sealed class SurrealDbEmbeddedRuntimeOptions
+{
+    /// <summary>
+    /// Number of worker threads of the native runtime. Defaults to the number of CPU cores.
+    /// </summary>
+    [CborProperty("worker_threads")]
+    [CborIgnoreIfDefault]
+    public int? WorkerThreads { get; set; }
+
+    /// <summary>
+    /// Maximum number of threads spawned for blocking operations.
+    /// </summary>
+    [CborProperty("max_blocking_threads")]
+    [CborIgnoreIfDefault]
+    public int? MaxBlockingThreads { get; set; }
+
+    /// <summary>
+    /// Name given to the threads of the native runtime.
+    /// </summary>
+    [CborProperty("thread_name")]
+    [CborIgnoreIfDefault]
+    public string? ThreadName { get; set; }
+
+    /// <summary>
+    /// Stack size (in bytes) of the threads of the native runtime.
+    /// </summary>
+    [CborProperty("thread_stack_size")]
+    [CborIgnoreIfDefault]
+    public int? ThreadStackSize { get; set; }
+
+    /// <summary>
+    /// Runs every task on a single thread, for constrained environments (e.g. containers with a CPU quota).
+    /// </summary>
+    [CborProperty("current_thread")]
+    [CborIgnoreIfDefault]
+    public bool? CurrentThread { get; set; }
+}
@@ -0,0 +1,21 @@
+﻿using Dahomey.Cbor.Attributes;
+
+namespace SurrealDb.Embedded.Options;
+
+public sealed class SurrealDbEmbeddedServeOptions
+{
+    /// <summary>
+    /// IP address and port to listen on, port <c>0</c> binding any available port. Defaults to <c>127.0.0.1:8000</c>.
+    /// </summary>
+    [CborProperty("address")]
+    [CborIgnoreIfDefault]
+    public string? Address { get; set; }
+
+    /// <summary>
+    /// Requires clients to sign in. Defaults to <c>true</c>.
+    /// When disabled, every client is given owner rights, so no untrusted client should be able to reach the address.
+    /// </summary>
+    [CborProperty("auth")]
+    [CborIgnoreIfDefault]
+    public bool? Auth { get; set; }
+}
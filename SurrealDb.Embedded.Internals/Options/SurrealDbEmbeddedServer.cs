@@ -0,0 +1,37 @@
+﻿namespace SurrealDb.Embedded.Options;
+
+/// <summary>
+/// A server exposing an embedded engine over the <c>/rpc</c> and <c>/sql</c> endpoints of SurrealDB,
+/// so that tools such as Surrealist can inspect it while debugging.
+/// The server is stopped once disposed, or along with its engine.
+/// </summary>
+public sealed class SurrealDbEmbeddedServer : IAsyncDisposable
+{
+    private readonly Func<CancellationToken, Task> _stop;
+
+    /// <summary>
+    /// The IP address and port the server listens on (e.g. <c>127.0.0.1:8000</c>).
+    /// </summary>
+    public string Address { get; }
+
+    internal SurrealDbEmbeddedServer(string address, Func<CancellationToken, Task> stop)
+    {
+        Address = address;
+        _stop = stop;
+    }
+
+    /// <summary>
+    /// Stops the server, closing its connections.
+    /// Does nothing if the server has already been stopped.
+    /// </summary>
+    /// <param name="cancellationToken">The cancellationToken enables graceful cancellation of asynchronous operations</param>
+    public Task StopAsync(CancellationToken cancellationToken = default)
+    {
+        return _stop(cancellationToken);
+    }
+
+    public async ValueTask DisposeAsync()
+    {
+        await StopAsync().ConfigureAwait(false);
+    }
+}
@@ -0,0 +1,22 @@
+﻿using Dahomey.Cbor.Attributes;
+
+namespace SurrealDb.Embedded.Options;
+
+public sealed class SurrealDbEmbeddedTransactions
+{
+    /// <summary>
+    /// Cancels transactions that have not been used for this long.
+    /// Further calls on an expired transaction fail with a "Transaction expired" error.
+    /// </summary>
+    [CborProperty("idle_timeout")]
+    [CborIgnoreIfDefault]
+    public TimeSpan? IdleTimeout { get; internal set; }
+
+    /// <summary>
+    /// Cancels transactions that have been open for this long, once the calls running within them have completed.
+    /// Further calls on an expired transaction fail with a "Transaction expired" error.
+    /// </summary>
+    [CborProperty("max_lifetime")]
+    [CborIgnoreIfDefault]
+    public TimeSpan? MaxLifetime { get; internal set; }
+}
@@ -0,0 +1,50 @@
+﻿using System.Runtime.InteropServices;
+
+namespace SurrealDb.Embedded.Internals;
+
+/// <summary>
+/// The serialized params of a method, owned by the engine until pinned.
+/// Once pinned, the native library reads them without a copy and releases them when the request no longer needs them,
+/// which may happen after the call has been cancelled or has timed out.
+/// </summary>
+internal sealed class PinnedParams : IAsyncDisposable
+{
+    private readonly MemoryStream _stream;
+    private GCHandle _pin;
+    private bool _pinned;
+
+    public PinnedParams(MemoryStream stream)
+    {
+        _stream = stream;
+    }
+
+    /// <summary>
+    /// Pins the buffer of the stream, handing the stream over to the native library.
+    /// </summary>
+    /// <param name="bytes">The buffer of the stream.</param>
+    /// <param name="pointer">The address of the params within the pinned buffer.</param>
+    /// <returns>The action releasing the stream, once invoked by the native library.</returns>
+    public unsafe ReleaseAction Pin(ArraySegment<byte> bytes, out byte* pointer)
+    {
+        _pin = GCHandle.Alloc(bytes.Array!, GCHandleType.Pinned);
+        _pinned = true;
+        pointer = (byte*)_pin.AddrOfPinnedObject() + bytes.Offset;
+
+        return new ReleaseAction()
+        {
+            ptr = GCHandle.ToIntPtr(GCHandle.Alloc(this)),
+            callback = &NativeBindings.ReleasePinnedParams,
+        };
+    }
+
+    internal void Release()
+    {
+        _pin.Free();
+        _stream.Dispose();
+    }
+
+    public ValueTask DisposeAsync()
+    {
+        return _pinned ? default : _stream.DisposeAsync();
+    }
+}
@@ -1,6 +1,8 @@
-﻿using System.Diagnostics;
+﻿using System.Buffers;
+using System.Diagnostics;
 using System.Reactive;
 using System.Runtime.InteropServices;
+using System.Threading.Channels;
 using Dahomey.Cbor;
 using Microsoft.Extensions.DependencyInjection;
 using Microsoft.Extensions.Logging;
@@ -32,6 +34,26 @@ namespace SurrealDb.Embedded.Internals;
 
 internal sealed partial class SurrealDbEmbeddedEngine : ISurrealDbProviderEngine
 {
+    /// <summary>
+    /// Id returned by the native methods that do not start a request.
+    /// </summary>
+    private const ulong NO_REQUEST = 0;
+
+    /// <summary>
+    /// Size of the chunks of a SurrealQL script sent to the native library during an import.
+    /// </summary>
+    private const int IMPORT_CHUNK_SIZE = 64 * 1024;
+
+    /// <summary>
+    /// Number of chunks of a streamed export waiting to be written to the output stream.
+    /// </summary>
+    private const int EXPORT_CHUNK_CAPACITY = 16;
+
+    /// <summary>
+    /// Size of the params of a method from which they are read by the native library from pinned memory, rather than copied.
+    /// </summary>
+    private const int PINNED_PARAMS_MIN_SIZE = 1024 * 1024;
+
     private static int _globalId;
 
     private SurrealDbOptions? _parameters;
@@ -41,6 +63,12 @@ internal sealed partial class SurrealDbEmbeddedEngine : ISurrealDbProviderEngine
     private ISessionizer? _sessionizer;
 
     private readonly int _id;
+    private readonly SurrealDbLiveQueryDispatcher _liveQueryDispatcher;
+    private readonly Channel<ReadOnlyMemory<byte>> _notifications =
+        Channel.CreateUnbounded<ReadOnlyMemory<byte>>(
+            new UnboundedChannelOptions { SingleReader = true }
+        );
+    private Task? _notificationsTask;
 
     private bool _isConnected;
     private bool _isInitialized;
@@ -51,14 +79,13 @@ internal sealed partial class SurrealDbEmbeddedEngine : ISurrealDbProviderEngine
     public Uri Uri { get; private set; } = new("unknown://");
     public EmbeddedSessionInfos SessionInfos { get; } = new();
 
-    static SurrealDbEmbeddedEngine()
-    {
-        NativeMethods.create_global_runtime();
-    }
-
     public SurrealDbEmbeddedEngine()
     {
+        // The runtime may have been configured beforehand, or shut down since the previous engine
+        SurrealDbEmbeddedRuntime.EnsureCreated();
+
         _id = Interlocked.Increment(ref _globalId);
+        _liveQueryDispatcher = new(_id.ToString());
     }
 
     public SurrealDbEmbeddedEngine(SurrealDbEmbeddedOptions? options)
@@ -93,21 +120,32 @@ internal sealed partial class SurrealDbEmbeddedEngine : ISurrealDbProviderEngine
             .ConfigureAwait(false);
     }
 
-    public Task Authenticate(
+    public async Task Authenticate(
         Tokens tokens,
         Guid? sessionId,
         Guid? transactionId,
         CancellationToken cancellationToken
     )
     {
-        throw new NotSupportedException("Authentication is not enabled in embedded mode.");
+        await SendRequestAsync<Unit>(
+                Method.Authenticate,
+                [tokens.Access],
+                sessionId,
+                transactionId,
+                cancellationToken
+            )
+            .ConfigureAwait(false);
     }
 
-    public async Task<Guid> Begin(Guid? sessionId, CancellationToken cancellationToken)
+    public async Task<Guid> Begin(
+        Guid? sessionId,
+        TransactionOptions? options,
+        CancellationToken cancellationToken
+    )
     {
         var transactionId = await SendRequestAsync<Guid>(
                 Method.Begin,
-                null,
+                options is null ? null : [options],
                 sessionId,
                 null,
                 cancellationToken
@@ -197,6 +235,14 @@ internal sealed partial class SurrealDbEmbeddedEngine : ISurrealDbProviderEngine
 
             PreConnect();
 
+            if (_surrealDbLoggerFactory?.Embedded is { } embeddedLogger)
+            {
+                NativeLogs.Register(_id, embeddedLogger);
+            }
+
+            NativeNotifications.Register(_id, OnNotification);
+            _notificationsTask ??= Task.Run(DispatchNotificationsAsync);
+
             await using var stream = MemoryStreamProvider.MemoryStreamManager.GetStream();
 
             await CborSerializer
@@ -233,11 +279,11 @@ internal sealed partial class SurrealDbEmbeddedEngine : ISurrealDbProviderEngine
                     );
                 }
 
-                string error = CborSerializer.Deserialize<string>(
+                var error = CborSerializer.Deserialize<EmbeddedError>(
                     byteBuffer.AsReadOnly(),
                     GetCborOptions()
                 );
-                taskCompletionSource.SetException(new SurrealDbEmbeddedException(error));
+                taskCompletionSource.SetException(error.ToException());
             };
 
             var successHandle = GCHandle.Alloc(success);
@@ -271,9 +317,9 @@ internal sealed partial class SurrealDbEmbeddedEngine : ISurrealDbProviderEngine
                     NativeMethods.apply_connect(
                         _id,
                         (ushort*)p,
-                        _parameters.Endpoint!.Length,
+                        (nuint)_parameters.Endpoint!.Length,
                         payload,
-                        bytes.Count,
+                        (nuint)bytes.Count,
                         successAction,
                         failureAction
                     );
@@ -411,104 +457,33 @@ internal sealed partial class SurrealDbEmbeddedEngine : ISurrealDbProviderEngine
 
     public void Dispose()
     {
-        if (_disposed)
-        {
-            return;
-        }
-
-        NativeMethods.dispose(_id);
-
-        _disposed = true;
-    }
-
-    public ValueTask DisposeAsync()
-    {
-        Dispose();
-
-#if NET6_0_OR_GREATER
-        return ValueTask.CompletedTask;
-#else
-        return new ValueTask(Task.CompletedTask);
-#endif
+        DisposeAsync().AsTask().GetAwaiter().GetResult();
     }
 
-    public async Task<string> Export(ExportOptions? options, CancellationToken cancellationToken)
+    public async ValueTask DisposeAsync()
     {
-        using var timeoutCts = new CancellationTokenSource(TimeSpan.FromSeconds(30));
-        cancellationToken.Register(timeoutCts.Cancel);
-
-        await using var stream = MemoryStreamProvider.MemoryStreamManager.GetStream();
-
-        try
-        {
-            await CborSerializer
-                .SerializeAsync(options ?? new(), stream, GetCborOptions(), timeoutCts.Token)
-                .ConfigureAwait(false);
-        }
-        catch (OperationCanceledException)
-        {
-            if (!cancellationToken.IsCancellationRequested)
-            {
-                throw new TimeoutException();
-            }
-
-            throw;
-        }
-
-        if (_surrealDbLoggerFactory?.Serialization?.IsEnabled(LogLevel.Debug) == true)
+        if (_disposed)
         {
-            string cborData = CborDebugHelper.CborBinaryToHexa(stream);
-            _surrealDbLoggerFactory?.Serialization?.LogSerializationDataSerialized(cborData);
+            return;
         }
 
-        bool canGetBuffer = stream.TryGetBuffer(out var bytes);
-        if (!canGetBuffer)
-        {
-            throw new SurrealDbSerializationException("Failed to retrieve serialized buffer.");
-        }
+        _disposed = true;
 
-        var taskCompletionSource = new TaskCompletionSource<string>(
+        var taskCompletionSource = new TaskCompletionSource<bool>(
             TaskCreationOptions.RunContinuationsAsynchronously
         );
-        timeoutCts.Token.Register(() =>
-        {
-            taskCompletionSource.TrySetCanceled();
-        });
 
-        Action<ByteBuffer> success = (byteBuffer) =>
+        Action<ByteBuffer> success = (_) =>
         {
-            if (_surrealDbLoggerFactory?.Serialization?.IsEnabled(LogLevel.Debug) == true)
-            {
-                string cborData = CborDebugHelper.CborBinaryToHexa(byteBuffer.AsReadOnly());
-                _surrealDbLoggerFactory?.Serialization?.LogSerializationDataDeserialized(cborData);
-            }
-
-            try
-            {
-                var result = CborSerializer.Deserialize<string>(
-                    byteBuffer.AsReadOnly(),
-                    GetCborOptions()
-                );
-                taskCompletionSource.SetResult(result!);
-            }
-            catch (Exception e)
-            {
-                taskCompletionSource.SetException(e);
-            }
+            taskCompletionSource.SetResult(true);
         };
         Action<ByteBuffer> fail = (byteBuffer) =>
         {
-            if (_surrealDbLoggerFactory?.Serialization?.IsEnabled(LogLevel.Debug) == true)
-            {
-                string cborData = CborDebugHelper.CborBinaryToHexa(byteBuffer.AsReadOnly());
-                _surrealDbLoggerFactory?.Serialization?.LogSerializationDataDeserialized(cborData);
-            }
-
-            string error = CborSerializer.Deserialize<string>(
+            var error = CborSerializer.Deserialize<EmbeddedError>(
                 byteBuffer.AsReadOnly(),
                 GetCborOptions()
             );
-            taskCompletionSource.SetException(new SurrealDbEmbeddedException(error));
+            taskCompletionSource.SetException(error.ToException());
         };
 
         var successHandle = GCHandle.Alloc(success);
@@ -536,25 +511,204 @@ internal sealed partial class SurrealDbEmbeddedEngine : ISurrealDbProviderEngine
                 callback = &NativeBindings.FailureCallback,
             };
 
-            fixed (byte* payload = bytes.AsSpan())
+            // Storage is released once the engine is fully closed
+            NativeMethods.dispose(_id, successAction, failureAction);
+        }
+
+        try
+        {
+            await taskCompletionSource.Task.ConfigureAwait(false);
+        }
+        finally
+        {
+            NativeLogs.Unregister(_id);
+            NativeNotifications.Unregister(_id);
+
+            // Pending notifications are still dispatched, before every live query is closed
+            _notifications.Writer.TryComplete();
+            if (_notificationsTask is not null)
             {
-                NativeMethods.export(_id, payload, bytes.Count, successAction, failureAction);
+                await _notificationsTask.ConfigureAwait(false);
             }
         }
+    }
 
-        try
+    public async Task<string> Export(
+        ExportOptions? options,
+        Guid? sessionId,
+        CancellationToken cancellationToken
+    )
+    {
+        await InternalConnectAsync(true, cancellationToken).ConfigureAwait(false);
+
+        await using var stream = MemoryStreamProvider.MemoryStreamManager.GetStream();
+
+        await CborSerializer
+            .SerializeAsync(options ?? new(), stream, GetCborOptions(), cancellationToken)
+            .ConfigureAwait(false);
+
+        if (_surrealDbLoggerFactory?.Serialization?.IsEnabled(LogLevel.Debug) == true)
         {
-            return await taskCompletionSource.Task.ConfigureAwait(false);
+            string cborData = CborDebugHelper.CborBinaryToHexa(stream);
+            _surrealDbLoggerFactory?.Serialization?.LogSerializationDataSerialized(cborData);
         }
-        catch (OperationCanceledException)
+
+        bool canGetBuffer = stream.TryGetBuffer(out var bytes);
+        if (!canGetBuffer)
         {
-            if (!cancellationToken.IsCancellationRequested)
+            throw new SurrealDbSerializationException("Failed to retrieve serialized buffer.");
+        }
+
+        var sessionBytes = sessionId.HasValue ? sessionId.Value.ToByteArray() : [];
+
+        return await InvokeNativeAsync<string>(
+                (successAction, failureAction) =>
+                {
+                    unsafe
+                    {
+                        fixed (byte* session = sessionBytes.AsSpan())
+                        fixed (byte* payload = bytes.AsSpan())
+                        {
+                            return NativeMethods.export(
+                                _id,
+                                session,
+                                (nuint)sessionBytes.Length,
+                                payload,
+                                (nuint)bytes.Count,
+                                successAction,
+                                failureAction
+                            );
+                        }
+                    }
+                },
+                cancellationToken
+            )
+            .ConfigureAwait(false);
+    }
+
+    public async Task Export(
+        Stream output,
+        ExportOptions? options,
+        Guid? sessionId,
+        CancellationToken cancellationToken
+    )
+    {
+        await InternalConnectAsync(true, cancellationToken).ConfigureAwait(false);
+
+        await using var stream = MemoryStreamProvider.MemoryStreamManager.GetStream();
+
+        await CborSerializer
+            .SerializeAsync(options ?? new(), stream, GetCborOptions(), cancellationToken)
+            .ConfigureAwait(false);
+
+        if (_surrealDbLoggerFactory?.Serialization?.IsEnabled(LogLevel.Debug) == true)
+        {
+            string cborData = CborDebugHelper.CborBinaryToHexa(stream);
+            _surrealDbLoggerFactory?.Serialization?.LogSerializationDataSerialized(cborData);
+        }
+
+        bool canGetBuffer = stream.TryGetBuffer(out var bytes);
+        if (!canGetBuffer)
+        {
+            throw new SurrealDbSerializationException("Failed to retrieve serialized buffer.");
+        }
+
+        var sessionBytes = sessionId.HasValue ? sessionId.Value.ToByteArray() : [];
+
+        // Bounded, so that the export is not held in memory when the stream is slower than the datastore
+        var chunks = Channel.CreateBounded<byte[]>(
+            new BoundedChannelOptions(EXPORT_CHUNK_CAPACITY)
             {
-                throw new TimeoutException();
+                SingleReader = true,
+                SingleWriter = true,
+            }
+        );
+
+        Action<ByteBuffer> chunk = (byteBuffer) =>
+        {
+            var data = byteBuffer.AsReadOnly().ToArray();
+
+            // The native library only waits while the channel is full, never for the stream itself.
+            // Chunks are dropped once the channel is completed, i.e. when they can no longer be written.
+            while (!chunks.Writer.TryWrite(data))
+            {
+                if (!chunks.Writer.WaitToWriteAsync().AsTask().GetAwaiter().GetResult())
+                {
+                    return;
+                }
+            }
+        };
+
+        using var exportCts = CancellationTokenSource.CreateLinkedTokenSource(cancellationToken);
+
+        async Task ExportChunksAsync()
+        {
+            try
+            {
+                await InvokeNativeAsync<Unit>(
+                        (successAction, failureAction) =>
+                        {
+                            unsafe
+                            {
+                                var chunkAction = new ChunkAction()
+                                {
+                                    handle = new RustGCHandle()
+                                    {
+                                        ptr = GCHandle.ToIntPtr(GCHandle.Alloc(chunk)),
+                                        drop_callback = &NativeBindings.DropGcHandle,
+                                    },
+                                    callback = &NativeBindings.ChunkCallback,
+                                };
+
+                                fixed (byte* session = sessionBytes.AsSpan())
+                                fixed (byte* payload = bytes.AsSpan())
+                                {
+                                    return NativeMethods.export_stream(
+                                        _id,
+                                        session,
+                                        (nuint)sessionBytes.Length,
+                                        payload,
+                                        (nuint)bytes.Count,
+                                        chunkAction,
+                                        successAction,
+                                        failureAction
+                                    );
+                                }
+                            }
+                        },
+                        exportCts.Token
+                    )
+                    .ConfigureAwait(false);
+            }
+            finally
+            {
+                // Every chunk has been sent once the export is complete
+                chunks.Writer.TryComplete();
+            }
+        }
+
+        var export = ExportChunksAsync();
+
+        try
+        {
+            await foreach (
+                var data in chunks.Reader.ReadAllAsync(cancellationToken).ConfigureAwait(false)
+            )
+            {
+                await output.WriteAsync(data, cancellationToken).ConfigureAwait(false);
             }
 
+            await export.ConfigureAwait(false);
+        }
+        catch
+        {
+            // Stops producing chunks when they can no longer be written
+            exportCts.Cancel();
+            chunks.Writer.TryComplete();
             throw;
         }
+
+        await output.FlushAsync(cancellationToken).ConfigureAwait(false);
     }
 
     public async Task<bool> Health(CancellationToken cancellationToken)
@@ -571,98 +725,304 @@ internal sealed partial class SurrealDbEmbeddedEngine : ISurrealDbProviderEngine
         }
     }
 
-    public async Task Import(string input, CancellationToken cancellationToken)
+    public async Task Import(
+        string input,
+        Guid? sessionId,
+        Guid? transactionId,
+        CancellationToken cancellationToken
+    )
     {
-        using var timeoutCts = new CancellationTokenSource(TimeSpan.FromSeconds(30));
-        cancellationToken.Register(timeoutCts.Cancel);
+        await InternalConnectAsync(true, cancellationToken).ConfigureAwait(false);
 
-        var taskCompletionSource = new TaskCompletionSource<Unit>(
-            TaskCreationOptions.RunContinuationsAsynchronously
-        );
-        timeoutCts.Token.Register(() =>
-        {
-            taskCompletionSource.TrySetCanceled();
-        });
+        var sessionBytes = sessionId.HasValue ? sessionId.Value.ToByteArray() : [];
+        var transactionBytes = transactionId.HasValue ? transactionId.Value.ToByteArray() : [];
 
-        Action<ByteBuffer> success = (_) =>
+        await InvokeNativeAsync<Unit>(
+                (successAction, failureAction) =>
+                {
+                    unsafe
+                    {
+                        fixed (byte* session = sessionBytes.AsSpan())
+                        fixed (byte* transaction = transactionBytes.AsSpan())
+                        fixed (char* p = input.AsSpan())
+                        {
+                            return NativeMethods.import(
+                                _id,
+                                session,
+                                (nuint)sessionBytes.Length,
+                                transaction,
+                                (nuint)transactionBytes.Length,
+                                (ushort*)p,
+                                (nuint)input.Length,
+                                successAction,
+                                failureAction
+                            );
+                        }
+                    }
+                },
+                cancellationToken
+            )
+            .ConfigureAwait(false);
+    }
+
+    public async Task Import(
+        Stream input,
+        Guid? sessionId,
+        Guid? transactionId,
+        CancellationToken cancellationToken
+    )
+    {
+        await InternalConnectAsync(true, cancellationToken).ConfigureAwait(false);
+
+        var sessionBytes = sessionId.HasValue ? sessionId.Value.ToByteArray() : [];
+        var transactionBytes = transactionId.HasValue ? transactionId.Value.ToByteArray() : [];
+
+        var importId = await InvokeNativeAsync<Guid>(
+                (successAction, failureAction) =>
+                {
+                    unsafe
+                    {
+                        fixed (byte* session = sessionBytes.AsSpan())
+                        fixed (byte* transaction = transactionBytes.AsSpan())
+                        {
+                            NativeMethods.begin_import(
+                                _id,
+                                session,
+                                (nuint)sessionBytes.Length,
+                                transaction,
+                                (nuint)transactionBytes.Length,
+                                successAction,
+                                failureAction
+                            );
+                        }
+                    }
+                    return NO_REQUEST;
+                },
+                cancellationToken
+            )
+            .ConfigureAwait(false);
+
+        var importBytes = importId.ToByteArray();
+        var buffer = ArrayPool<byte>.Shared.Rent(IMPORT_CHUNK_SIZE);
+
+        try
         {
-            try
-            {
-                taskCompletionSource.SetResult(default);
-            }
-            catch (Exception e)
+            int read;
+            while (
+                (
+                    read = await input
+                        .ReadAsync(buffer.AsMemory(0, IMPORT_CHUNK_SIZE), cancellationToken)
+                        .ConfigureAwait(false)
+                ) > 0
+            )
             {
-                taskCompletionSource.SetException(e);
+                // The chunk is copied by the native library before the call returns
+                await InvokeNativeAsync<Unit>(
+                        (successAction, failureAction) =>
+                        {
+                            unsafe
+                            {
+                                fixed (byte* import = importBytes.AsSpan())
+                                fixed (byte* chunk = buffer.AsSpan())
+                                {
+                                    return NativeMethods.write_import_chunk(
+                                        _id,
+                                        import,
+                                        (nuint)importBytes.Length,
+                                        chunk,
+                                        (nuint)read,
+                                        successAction,
+                                        failureAction
+                                    );
+                                }
+                            }
+                        },
+                        cancellationToken
+                    )
+                    .ConfigureAwait(false);
             }
-        };
-        Action<ByteBuffer> fail = (byteBuffer) =>
+
+            await InvokeNativeAsync<Unit>(
+                    (successAction, failureAction) =>
+                    {
+                        unsafe
+                        {
+                            fixed (byte* import = importBytes.AsSpan())
+                            {
+                                return NativeMethods.finish_import(
+                                    _id,
+                                    import,
+                                    (nuint)importBytes.Length,
+                                    successAction,
+                                    failureAction
+                                );
+                            }
+                        }
+                    },
+                    cancellationToken
+                )
+                .ConfigureAwait(false);
+        }
+        catch
         {
-            if (_surrealDbLoggerFactory?.Serialization?.IsEnabled(LogLevel.Debug) == true)
+            // Discards the chunks already written, rather than importing part of the script
+            unsafe
             {
-                string cborData = CborDebugHelper.CborBinaryToHexa(byteBuffer.AsReadOnly());
-                _surrealDbLoggerFactory?.Serialization?.LogSerializationDataDeserialized(cborData);
+                fixed (byte* import = importBytes.AsSpan())
+                {
+                    NativeMethods.abort_import(_id, import, (nuint)importBytes.Length);
+                }
             }
+            throw;
+        }
+        finally
+        {
+            ArrayPool<byte>.Shared.Return(buffer);
+        }
+    }
 
-            string error = CborSerializer.Deserialize<string>(
-                byteBuffer.AsReadOnly(),
-                GetCborOptions()
-            );
-            taskCompletionSource.SetException(new SurrealDbEmbeddedException(error));
-        };
+    public async Task ImportFile(
+        string path,
+        Guid? sessionId,
+        Guid? transactionId,
+        CancellationToken cancellationToken
+    )
+    {
+        await InternalConnectAsync(true, cancellationToken).ConfigureAwait(false);
 
-        var successHandle = GCHandle.Alloc(success);
-        var failureHandle = GCHandle.Alloc(fail);
+        var sessionBytes = sessionId.HasValue ? sessionId.Value.ToByteArray() : [];
+        var transactionBytes = transactionId.HasValue ? transactionId.Value.ToByteArray() : [];
 
-        unsafe
-        {
-            var successAction = new SuccessAction()
-            {
-                handle = new RustGCHandle()
+        await InvokeNativeAsync<Unit>(
+                (successAction, failureAction) =>
                 {
-                    ptr = GCHandle.ToIntPtr(successHandle),
-                    drop_callback = &NativeBindings.DropGcHandle,
+                    unsafe
+                    {
+                        fixed (byte* session = sessionBytes.AsSpan())
+                        fixed (byte* transaction = transactionBytes.AsSpan())
+                        fixed (char* p = path.AsSpan())
+                        {
+                            return NativeMethods.import_file(
+                                _id,
+                                session,
+                                (nuint)sessionBytes.Length,
+                                transaction,
+                                (nuint)transactionBytes.Length,
+                                (ushort*)p,
+                                (nuint)path.Length,
+                                successAction,
+                                failureAction
+                            );
+                        }
+                    }
                 },
-                callback = &NativeBindings.SuccessCallback,
-            };
+                cancellationToken
+            )
+            .ConfigureAwait(false);
+    }
 
-            var failureAction = new FailureAction()
-            {
-                handle = new RustGCHandle()
+    public async Task Backup(string path, CancellationToken cancellationToken)
+    {
+        await InternalConnectAsync(true, cancellationToken).ConfigureAwait(false);
+
+        await InvokeNativeAsync<Unit>(
+                (successAction, failureAction) =>
                 {
-                    ptr = GCHandle.ToIntPtr(failureHandle),
-                    drop_callback = &NativeBindings.DropGcHandle,
+                    unsafe
+                    {
+                        fixed (char* p = path.AsSpan())
+                        {
+                            return NativeMethods.backup(
+                                _id,
+                                (ushort*)p,
+                                (nuint)path.Length,
+                                successAction,
+                                failureAction
+                            );
+                        }
+                    }
                 },
-                callback = &NativeBindings.FailureCallback,
-            };
+                cancellationToken
+            )
+            .ConfigureAwait(false);
+    }
 
-            fixed (char* p = input.AsSpan())
-            {
-                NativeMethods.import(_id, (ushort*)p, input.Length, successAction, failureAction);
-            }
-        }
+    public async Task<SurrealDbEmbeddedServer> Serve(
+        SurrealDbEmbeddedServeOptions? options,
+        CancellationToken cancellationToken
+    )
+    {
+        await InternalConnectAsync(true, cancellationToken).ConfigureAwait(false);
 
-        try
+        await using var stream = MemoryStreamProvider.MemoryStreamManager.GetStream();
+
+        await CborSerializer
+            .SerializeAsync(options ?? new(), stream, GetCborOptions(), cancellationToken)
+            .ConfigureAwait(false);
+
+        if (_surrealDbLoggerFactory?.Serialization?.IsEnabled(LogLevel.Debug) == true)
         {
-            await taskCompletionSource.Task.ConfigureAwait(false);
+            string cborData = CborDebugHelper.CborBinaryToHexa(stream);
+            _surrealDbLoggerFactory?.Serialization?.LogSerializationDataSerialized(cborData);
         }
-        catch (OperationCanceledException)
-        {
-            if (!cancellationToken.IsCancellationRequested)
-            {
-                throw new TimeoutException();
-            }
 
-            throw;
+        bool canGetBuffer = stream.TryGetBuffer(out var bytes);
+        if (!canGetBuffer)
+        {
+            throw new SurrealDbSerializationException("Failed to retrieve serialized buffer.");
         }
+
+        var server = await InvokeNativeAsync<EmbeddedServerInfo>(
+                (successAction, failureAction) =>
+                {
+                    unsafe
+                    {
+                        fixed (byte* payload = bytes.AsSpan())
+                        {
+                            return NativeMethods.serve(
+                                _id,
+                                payload,
+                                (nuint)bytes.Count,
+                                successAction,
+                                failureAction
+                            );
+                        }
+                    }
+                },
+                cancellationToken
+            )
+            .ConfigureAwait(false);
+
+        return new SurrealDbEmbeddedServer(
+            server.Address,
+            (token) => StopServing(server.Id, token)
+        );
     }
 
-    public Task<T> Info<T>(
+    private async Task StopServing(ulong serverId, CancellationToken cancellationToken)
+    {
+        await InvokeNativeAsync<Unit>(
+                (successAction, failureAction) =>
+                    NativeMethods.stop_serving(serverId, successAction, failureAction),
+                cancellationToken
+            )
+            .ConfigureAwait(false);
+    }
+
+    public async Task<T> Info<T>(
         Guid? sessionId,
         Guid? transactionId,
         CancellationToken cancellationToken
     )
     {
-        throw new NotSupportedException("Authentication is not enabled in embedded mode.");
+        return await SendRequestAsync<T>(
+                Method.Info,
+                null,
+                sessionId,
+                transactionId,
+                cancellationToken
+            )
+            .ConfigureAwait(false);
     }
 
     public async Task<IEnumerable<T>> Insert<T>(
@@ -733,16 +1093,23 @@ internal sealed partial class SurrealDbEmbeddedEngine : ISurrealDbProviderEngine
         return result.Single();
     }
 
-    public Task Invalidate(
+    public async Task Invalidate(
         Guid? sessionId,
         Guid? transactionId,
         CancellationToken cancellationToken
     )
     {
-        throw new NotSupportedException("Authentication is not enabled in embedded mode.");
+        await SendRequestAsync<Unit>(
+                Method.Invalidate,
+                null,
+                sessionId,
+                transactionId,
+                cancellationToken
+            )
+            .ConfigureAwait(false);
     }
 
-    public Task Kill(
+    public async Task Kill(
         Guid queryUuid,
         SurrealDbLiveQueryClosureReason reason,
         Guid? sessionId,
@@ -750,12 +1117,21 @@ internal sealed partial class SurrealDbEmbeddedEngine : ISurrealDbProviderEngine
         CancellationToken cancellationToken
     )
     {
-        throw new NotSupportedException();
+        await _liveQueryDispatcher.CloseAsync(queryUuid, reason).ConfigureAwait(false);
+
+        await SendRequestAsync<Unit>(
+                Method.Kill,
+                [queryUuid],
+                sessionId,
+                transactionId,
+                cancellationToken
+            )
+            .ConfigureAwait(false);
     }
 
     public SurrealDbLiveQuery<T> ListenLive<T>(Guid queryUuid, Guid? sessionId, Guid? transactionId)
     {
-        throw new NotSupportedException();
+        return _liveQueryDispatcher.Listen<T>(this, queryUuid, sessionId, transactionId);
     }
 
     public Task<SurrealDbLiveQuery<T>> LiveQuery<T>(
@@ -765,10 +1141,17 @@ internal sealed partial class SurrealDbEmbeddedEngine : ISurrealDbProviderEngine
         CancellationToken cancellationToken
     )
     {
-        throw new NotSupportedException();
+        var (formattedQuery, parameters) = query.ExtractRawQueryParams();
+        return LiveRawQuery<T>(
+            formattedQuery,
+            parameters,
+            sessionId,
+            transactionId,
+            cancellationToken
+        );
     }
 
-    public Task<SurrealDbLiveQuery<T>> LiveRawQuery<T>(
+    public async Task<SurrealDbLiveQuery<T>> LiveRawQuery<T>(
         string query,
         IReadOnlyDictionary<string, object?> parameters,
         Guid? sessionId,
@@ -776,10 +1159,31 @@ internal sealed partial class SurrealDbEmbeddedEngine : ISurrealDbProviderEngine
         CancellationToken cancellationToken
     )
     {
-        throw new NotSupportedException();
+        var dbResponse = await RawQuery(
+                query,
+                parameters,
+                sessionId,
+                transactionId,
+                cancellationToken
+            )
+            .ConfigureAwait(false);
+
+        if (dbResponse.HasErrors)
+        {
+            throw new SurrealDbErrorResultException(dbResponse.FirstError!);
+        }
+
+        if (dbResponse.FirstOk is null)
+        {
+            throw new SurrealDbErrorResultException();
+        }
+
+        var queryUuid = dbResponse.FirstOk.GetValue<Guid>()!;
+
+        return ListenLive<T>(queryUuid, sessionId, transactionId);
     }
 
-    public Task<SurrealDbLiveQuery<T>> LiveTable<T>(
+    public async Task<SurrealDbLiveQuery<T>> LiveTable<T>(
         string table,
         bool diff,
         Guid? sessionId,
@@ -787,7 +1191,16 @@ internal sealed partial class SurrealDbEmbeddedEngine : ISurrealDbProviderEngine
         CancellationToken cancellationToken
     )
     {
-        throw new NotSupportedException();
+        var queryUuid = await SendRequestAsync<Guid>(
+                Method.Live,
+                [table, diff],
+                sessionId,
+                transactionId,
+                cancellationToken
+            )
+            .ConfigureAwait(false);
+
+        return ListenLive<T>(queryUuid, sessionId, transactionId);
     }
 
     public async Task<TOutput> Merge<TMerge, TOutput>(
@@ -1147,37 +1560,58 @@ internal sealed partial class SurrealDbEmbeddedEngine : ISurrealDbProviderEngine
             .ConfigureAwait(false);
     }
 
-    public Task SignIn(
+    public async Task SignIn(
         RootAuth root,
         Guid? sessionId,
         Guid? transactionId,
         CancellationToken cancellationToken
     )
     {
-        throw new NotSupportedException("Authentication is not enabled in embedded mode.");
+        await SendRequestAsync<Unit>(
+                Method.Signin,
+                [root],
+                sessionId,
+                transactionId,
+                cancellationToken
+            )
+            .ConfigureAwait(false);
     }
 
-    public Task<Tokens> SignIn(
+    public async Task<Tokens> SignIn(
         NamespaceAuth nsAuth,
         Guid? sessionId,
         Guid? transactionId,
         CancellationToken cancellationToken
     )
     {
-        throw new NotSupportedException("Authentication is not enabled in embedded mode.");
+        return await SendRequestAsync<Tokens>(
+                Method.Signin,
+                [nsAuth],
+                sessionId,
+                transactionId,
+                cancellationToken
+            )
+            .ConfigureAwait(false);
     }
 
-    public Task<Tokens> SignIn(
+    public async Task<Tokens> SignIn(
         DatabaseAuth dbAuth,
         Guid? sessionId,
         Guid? transactionId,
         CancellationToken cancellationToken
     )
     {
-        throw new NotSupportedException("Authentication is not enabled in embedded mode.");
+        return await SendRequestAsync<Tokens>(
+                Method.Signin,
+                [dbAuth],
+                sessionId,
+                transactionId,
+                cancellationToken
+            )
+            .ConfigureAwait(false);
     }
 
-    public Task<Tokens> SignIn<T>(
+    public async Task<Tokens> SignIn<T>(
         T scopeAuth,
         Guid? sessionId,
         Guid? transactionId,
@@ -1185,10 +1619,17 @@ internal sealed partial class SurrealDbEmbeddedEngine : ISurrealDbProviderEngine
     )
         where T : ScopeAuth
     {
-        throw new NotSupportedException("Authentication is not enabled in embedded mode.");
+        return await SendRequestAsync<Tokens>(
+                Method.Signin,
+                [scopeAuth],
+                sessionId,
+                transactionId,
+                cancellationToken
+            )
+            .ConfigureAwait(false);
     }
 
-    public Task<Tokens> SignUp<T>(
+    public async Task<Tokens> SignUp<T>(
         T scopeAuth,
         Guid? sessionId,
         Guid? transactionId,
@@ -1196,12 +1637,19 @@ internal sealed partial class SurrealDbEmbeddedEngine : ISurrealDbProviderEngine
     )
         where T : ScopeAuth
     {
-        throw new NotSupportedException("Authentication is not enabled in embedded mode.");
+        return await SendRequestAsync<Tokens>(
+                Method.Signup,
+                [scopeAuth],
+                sessionId,
+                transactionId,
+                cancellationToken
+            )
+            .ConfigureAwait(false);
     }
 
     public SurrealDbLiveQueryChannel SubscribeToLiveQuery(Guid id)
     {
-        throw new NotSupportedException();
+        return _liveQueryDispatcher.Subscribe(id);
     }
 
     public Task<bool> TryResetAsync()
@@ -1490,6 +1938,40 @@ internal sealed partial class SurrealDbEmbeddedEngine : ISurrealDbProviderEngine
         return SurrealDbCborOptions.GetCborSerializerOptions(_configureCborOptions);
     }
 
+    private void OnNotification(ReadOnlyMemory<byte> notification)
+    {
+        // Called from the native library, which must not wait for the live query channels
+        _notifications.Writer.TryWrite(notification);
+    }
+
+    private async Task DispatchNotificationsAsync()
+    {
+        await foreach (
+            var notification in _notifications.Reader.ReadAllAsync().ConfigureAwait(false)
+        )
+        {
+            try
+            {
+                await _liveQueryDispatcher
+                    .NotifyAsync(notification, GetCborOptions())
+                    .ConfigureAwait(false);
+            }
+            catch (Exception e)
+            {
+                // A notification that cannot be read must not stop the delivery of the next ones
+                _surrealDbLoggerFactory?.Embedded?.LogError(
+                    e,
+                    "Failed to read a live query notification"
+                );
+            }
+        }
+
+        // Live queries are killed by the native library when the engine is disposed
+        await _liveQueryDispatcher
+            .CloseAllAsync(SurrealDbLiveQueryClosureReason.ConnectionTerminated)
+            .ConfigureAwait(false);
+    }
+
     private readonly SemaphoreSlim _semaphoreConnect = new(1, 1);
 
     /// <summary>
@@ -1530,6 +2012,106 @@ internal sealed partial class SurrealDbEmbeddedEngine : ISurrealDbProviderEngine
         }
     }
 
+    /// <summary>
+    /// Calls a native method, given the callbacks it reports to, and waits for its result.
+    /// The request returned by the native method is cancelled along with the <paramref name="cancellationToken"/>.
+    /// </summary>
+    private async Task<T> InvokeNativeAsync<T>(
+        Func<SuccessAction, FailureAction, ulong> call,
+        CancellationToken cancellationToken
+    )
+    {
+        cancellationToken.ThrowIfCancellationRequested();
+
+        var taskCompletionSource = new TaskCompletionSource<T>(
+            TaskCreationOptions.RunContinuationsAsynchronously
+        );
+
+        bool expectOutput = typeof(T) != typeof(Unit);
+
+        Action<ByteBuffer> success = (byteBuffer) =>
+        {
+            if (!expectOutput)
+            {
+                taskCompletionSource.TrySetResult(default!);
+                return;
+            }
+
+            try
+            {
+                if (_surrealDbLoggerFactory?.Serialization?.IsEnabled(LogLevel.Debug) == true)
+                {
+                    string cborData = CborDebugHelper.CborBinaryToHexa(byteBuffer.AsReadOnly());
+                    _surrealDbLoggerFactory?.Serialization?.LogSerializationDataDeserialized(
+                        cborData
+                    );
+                }
+
+                var result = CborSerializer.Deserialize<T>(
+                    byteBuffer.AsReadOnly(),
+                    GetCborOptions()
+                );
+                taskCompletionSource.TrySetResult(result!);
+            }
+            catch (Exception e)
+            {
+                taskCompletionSource.TrySetException(e);
+            }
+        };
+        Action<ByteBuffer> fail = (byteBuffer) =>
+        {
+            if (_surrealDbLoggerFactory?.Serialization?.IsEnabled(LogLevel.Debug) == true)
+            {
+                string cborData = CborDebugHelper.CborBinaryToHexa(byteBuffer.AsReadOnly());
+                _surrealDbLoggerFactory?.Serialization?.LogSerializationDataDeserialized(cborData);
+            }
+
+            var error = CborSerializer.Deserialize<EmbeddedError>(
+                byteBuffer.AsReadOnly(),
+                GetCborOptions()
+            );
+            taskCompletionSource.TrySetException(error.ToException());
+        };
+
+        var successHandle = GCHandle.Alloc(success);
+        var failureHandle = GCHandle.Alloc(fail);
+
+        ulong requestId;
+
+        unsafe
+        {
+            var successAction = new SuccessAction()
+            {
+                handle = new RustGCHandle()
+                {
+                    ptr = GCHandle.ToIntPtr(successHandle),
+                    drop_callback = &NativeBindings.DropGcHandle,
+                },
+                callback = &NativeBindings.SuccessCallback,
+            };
+
+            var failureAction = new FailureAction()
+            {
+                handle = new RustGCHandle()
+                {
+                    ptr = GCHandle.ToIntPtr(failureHandle),
+                    drop_callback = &NativeBindings.DropGcHandle,
+                },
+                callback = &NativeBindings.FailureCallback,
+            };
+
+            requestId = call(successAction, failureAction);
+        }
+
+        using var cancellationRegistration = cancellationToken.Register(() =>
+        {
+            taskCompletionSource.TrySetCanceled(cancellationToken);
+            NativeMethods.cancel_request(requestId);
+        });
+
+        return await taskCompletionSource.Task.ConfigureAwait(false);
+    }
+
     private async Task<T> SendRequestAsync<T>(
         Method method,
         object?[]? parameters,
@@ -1572,7 +2154,8 @@ internal sealed partial class SurrealDbEmbeddedEngine : ISurrealDbProviderEngine
                 .ConfigureAwait(false);
         }
 
-        await using var stream = MemoryStreamProvider.MemoryStreamManager.GetStream();
+        var stream = MemoryStreamProvider.MemoryStreamManager.GetStream();
+        await using var pinnedParams = new PinnedParams(stream);
 
         try
         {
@@ -1621,30 +2204,32 @@ internal sealed partial class SurrealDbEmbeddedEngine : ISurrealDbProviderEngine
         {
             if (expectOutput)
             {
-                if (_surrealDbLoggerFactory?.Serialization?.IsEnabled(LogLevel.Debug) == true)
-                {
-                    string cborData = CborDebugHelper.CborBinaryToHexa(byteBuffer.AsReadOnly());
-                    _surrealDbLoggerFactory?.Serialization?.LogSerializationDataDeserialized(
-                        cborData
-                    );
-                }
-
                 try
                 {
+                    if (_surrealDbLoggerFactory?.Serialization?.IsEnabled(LogLevel.Debug) == true)
+                    {
+                        string cborData = CborDebugHelper.CborBinaryToHexa(
+                            byteBuffer.AsReadOnly()
+                        );
+                        _surrealDbLoggerFactory?.Serialization?.LogSerializationDataDeserialized(
+                            cborData
+                        );
+                    }
+
                     var result = CborSerializer.Deserialize<T>(
                         byteBuffer.AsReadOnly(),
                         GetCborOptions()
                     );
-                    taskCompletionSource.SetResult(result!);
+                    taskCompletionSource.TrySetResult(result!);
                 }
                 catch (Exception e)
                 {
-                    taskCompletionSource.SetException(e);
+                    taskCompletionSource.TrySetException(e);
                 }
             }
             else
             {
-                taskCompletionSource.SetResult(default!);
+                taskCompletionSource.TrySetResult(default!);
             }
         };
         Action<ByteBuffer> fail = (byteBuffer) =>
@@ -1655,16 +2240,18 @@ internal sealed partial class SurrealDbEmbeddedEngine : ISurrealDbProviderEngine
                 _surrealDbLoggerFactory?.Serialization?.LogSerializationDataDeserialized(cborData);
             }
 
-            string error = CborSerializer.Deserialize<string>(
+            var error = CborSerializer.Deserialize<EmbeddedError>(
                 byteBuffer.AsReadOnly(),
                 GetCborOptions()
             );
-            taskCompletionSource.SetException(new SurrealDbEmbeddedException(error));
+            taskCompletionSource.TrySetException(error.ToException());
         };
 
         var successHandle = GCHandle.Alloc(success);
         var failureHandle = GCHandle.Alloc(fail);
 
+        ulong requestId;
+
         unsafe
         {
             var successAction = new SuccessAction()
@@ -1692,23 +2279,51 @@ internal sealed partial class SurrealDbEmbeddedEngine : ISurrealDbProviderEngine
 
             fixed (byte* session = sessionBytes.AsSpan())
             fixed (byte* transaction = transactionBytes.AsSpan())
-            fixed (byte* payload = bytes.AsSpan())
             {
-                NativeMethods.execute(
-                    _id,
-                    method,
-                    session,
-                    sessionBytes.Length,
-                    transaction,
-                    transactionBytes.Length,
-                    payload,
-                    bytes.Count,
-                    successAction,
-                    failureAction
-                );
+                if (bytes.Count >= PINNED_PARAMS_MIN_SIZE)
+                {
+                    var releaseAction = pinnedParams.Pin(bytes, out byte* payload);
+
+                    requestId = NativeMethods.execute_pinned(
+                        _id,
+                        method,
+                        session,
+                        (nuint)sessionBytes.Length,
+                        transaction,
+                        (nuint)transactionBytes.Length,
+                        payload,
+                        (nuint)bytes.Count,
+                        releaseAction,
+                        successAction,
+                        failureAction
+                    );
+                }
+                else
+                {
+                    fixed (byte* payload = bytes.AsSpan())
+                    {
+                        requestId = NativeMethods.execute(
+                            _id,
+                            method,
+                            session,
+                            (nuint)sessionBytes.Length,
+                            transaction,
+                            (nuint)transactionBytes.Length,
+                            payload,
+                            (nuint)bytes.Count,
+                            successAction,
+                            failureAction
+                        );
+                    }
+                }
             }
         }
 
+        using var cancellationRegistration = timeoutCts.Token.Register(() =>
+        {
+            NativeMethods.cancel_request(requestId);
+        });
+
         try
         {
 #if NET7_0_OR_GREATER
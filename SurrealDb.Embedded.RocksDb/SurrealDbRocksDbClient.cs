@@ -9,6 +9,8 @@ namespace SurrealDb.Embedded.RocksDb;
 
 public class SurrealDbRocksDbClient : BaseSurrealDbClient
 {
+    private readonly SurrealDbEmbeddedEngine _engine;
+
     internal const string BASE_ENDPOINT = "rocksdb://";
 
     /// <summary>
@@ -47,13 +49,42 @@ public class SurrealDbRocksDbClient : BaseSurrealDbClient
 
         Uri = new Uri(parameters.Endpoint);
 
-        var engine = new SurrealDbEmbeddedEngine(options);
+        _engine = new SurrealDbEmbeddedEngine(options);
         InitializeAndSetProviderEngine(
-            engine,
+            _engine,
             parameters,
             configureCborOptions,
             loggerFactory,
             null
         );
     }
+
+    /// <summary>
+    /// Backs the database up into a directory, which must not exist or be empty.
+    /// The backup is a consistent copy of the data, which can be restored when connecting
+    /// (see <see cref="SurrealDbEmbeddedOptionsBuilder.WithRestore"/>).
+    /// Writes are not blocked while the backup is in progress.
+    /// </summary>
+    /// <param name="path">The path of the backup directory.</param>
+    /// <param name="cancellationToken">The cancellationToken enables graceful cancellation of asynchronous operations</param>
+    public Task BackupAsync(string path, CancellationToken cancellationToken = default)
+    {
+        return _engine.Backup(path, cancellationToken);
+    }
+
+    /// <summary>
+    /// Serves the database over the <c>/rpc</c> (WebSocket) and <c>/sql</c> (HTTP) endpoints of SurrealDB,
+    /// so that tools such as Surrealist can inspect it while debugging.
+    /// By default, the server listens on <c>127.0.0.1:8000</c> and requires clients to sign in.
+    /// </summary>
+    /// <param name="options">The configuration of the server.</param>
+    /// <param name="cancellationToken">The cancellationToken enables graceful cancellation of asynchronous operations</param>
+    /// <returns>The running server, stopped once disposed.</returns>
+    public Task<SurrealDbEmbeddedServer> ServeAsync(
+        SurrealDbEmbeddedServeOptions? options = null,
+        CancellationToken cancellationToken = default
+    )
+    {
+        return _engine.Serve(options, cancellationToken);
+    }
 }
@@ -23,53 +23,255 @@ namespace SurrealDb.Embedded.Internals
         ///
         ///  Apply connection for the SurrealDB engine (given its id).
         ///  💡 "connect" is a reserved keyword
+        ///  Returns the request id, which can be used to cancel the request.
         /// </summary>
         [DllImport(__DllName, EntryPoint = "apply_connect", CallingConvention = CallingConvention.Cdecl, ExactSpelling = true)]
-        internal static extern void apply_connect(int id, ushort* utf16_str, int utf16_len, byte* bytes, int len, SuccessAction success, FailureAction failure);
+        internal static extern ulong apply_connect(int id, ushort* utf16_str, nuint utf16_len, byte* bytes, nuint len, SuccessAction success, FailureAction failure);
+
+        /// <summary>
+        ///  # Safety
+        ///
+        ///  Validates connection options without opening a datastore.
+        ///  Every invalid entry is reported at once through the failure callback.
+        /// </summary>
+        [DllImport(__DllName, EntryPoint = "validate_options", CallingConvention = CallingConvention.Cdecl, ExactSpelling = true)]
+        internal static extern void validate_options(byte* bytes, nuint len, SuccessAction success, FailureAction failure);
 
         /// <summary>
         ///  # Safety
         ///
         ///  Executes a specific method of a SurrealDB engine (given its id).
         ///  To execute a method, you should pass down the Method, the params and the callback functions (success, failure).
+        ///  Returns the request id, which can be used to cancel the request.
         /// </summary>
         [DllImport(__DllName, EntryPoint = "execute", CallingConvention = CallingConvention.Cdecl, ExactSpelling = true)]
-        internal static extern void execute(int id, Method method, byte* session_bytes, int session_len, byte* transaction_bytes, int transaction_len, byte* params_bytes, int params_len, SuccessAction success, FailureAction failure);
+        internal static extern ulong execute(int id, Method method, byte* session_bytes, nuint session_len, byte* transaction_bytes, nuint transaction_len, byte* params_bytes, nuint params_len, SuccessAction success, FailureAction failure);
+
+        /// <summary>
+        ///  # Safety
+        ///
+        ///  Executes a specific method of a SurrealDB engine (given its id), like "execute",
+        ///  reading the params directly from a pinned C# buffer instead of copying them.
+        ///  The buffer must stay pinned until the release callback is invoked, once the request has completed or been cancelled.
+        ///  Returns the request id, which can be used to cancel the request.
+        /// </summary>
+        [DllImport(__DllName, EntryPoint = "execute_pinned", CallingConvention = CallingConvention.Cdecl, ExactSpelling = true)]
+        internal static extern ulong execute_pinned(int id, Method method, byte* session_bytes, nuint session_len, byte* transaction_bytes, nuint transaction_len, byte* params_bytes, nuint params_len, ReleaseAction release, SuccessAction success, FailureAction failure);
 
         /// <summary>
         ///  # Safety
         ///
-        ///  Executes the "import" method of a SurrealDB engine (given its id).
+        ///  Executes the "import" method of a SurrealDB engine (given its id), using the given session (default session if empty).
+        ///  The import runs within the given transaction, if any.
+        ///  Returns the request id, which can be used to cancel the request.
         /// </summary>
         [DllImport(__DllName, EntryPoint = "import", CallingConvention = CallingConvention.Cdecl, ExactSpelling = true)]
-        internal static extern void import(int id, ushort* utf16_str, int utf16_len, SuccessAction success, FailureAction failure);
+        internal static extern ulong import(int id, byte* session_bytes, nuint session_len, byte* transaction_bytes, nuint transaction_len, ushort* utf16_str, nuint utf16_len, SuccessAction success, FailureAction failure);
+
+        /// <summary>
+        ///  # Safety
+        ///
+        ///  Executes the "import" method of a SurrealDB engine (given its id),
+        ///  reading the SurrealQL script from a file (given its path).
+        ///  The script is streamed, so giving a transaction is rejected.
+        ///  Returns the request id, which can be used to cancel the request.
+        /// </summary>
+        [DllImport(__DllName, EntryPoint = "import_file", CallingConvention = CallingConvention.Cdecl, ExactSpelling = true)]
+        internal static extern ulong import_file(int id, byte* session_bytes, nuint session_len, byte* transaction_bytes, nuint transaction_len, ushort* utf16_str, nuint utf16_len, SuccessAction success, FailureAction failure);
+
+        /// <summary>
+        ///  # Safety
+        ///
+        ///  Starts a chunked import of a SurrealDB engine (given its id).
+        ///  The script is streamed, so giving a transaction is rejected.
+        ///  The import id is sent to the success callback, to be used with "write_import_chunk" and "finish_import".
+        /// </summary>
+        [DllImport(__DllName, EntryPoint = "begin_import", CallingConvention = CallingConvention.Cdecl, ExactSpelling = true)]
+        internal static extern void begin_import(int id, byte* session_bytes, nuint session_len, byte* transaction_bytes, nuint transaction_len, SuccessAction success, FailureAction failure);
+
+        /// <summary>
+        ///  # Safety
+        ///
+        ///  Sends the next chunk of the SurrealQL script of a chunked import (given its id).
+        ///  The success callback is invoked once the chunk is queued for import.
+        ///  Returns the request id, which can be used to cancel the request.
+        /// </summary>
+        [DllImport(__DllName, EntryPoint = "write_import_chunk", CallingConvention = CallingConvention.Cdecl, ExactSpelling = true)]
+        internal static extern ulong write_import_chunk(int id, byte* import_bytes, nuint import_len, byte* bytes, nuint len, SuccessAction success, FailureAction failure);
+
+        /// <summary>
+        ///  # Safety
+        ///
+        ///  Completes a chunked import (given its id), once every chunk has been written.
+        ///  The success callback is invoked once the whole SurrealQL script has been imported.
+        ///  Returns the request id, which can be used to cancel the request.
+        /// </summary>
+        [DllImport(__DllName, EntryPoint = "finish_import", CallingConvention = CallingConvention.Cdecl, ExactSpelling = true)]
+        internal static extern ulong finish_import(int id, byte* import_bytes, nuint import_len, SuccessAction success, FailureAction failure);
+
+        /// <summary>
+        ///  # Safety
+        ///
+        ///  Aborts a chunked import (given its id), discarding the chunks that have not been imported yet.
+        ///  Does nothing if the import has already been finished or aborted.
+        /// </summary>
+        [DllImport(__DllName, EntryPoint = "abort_import", CallingConvention = CallingConvention.Cdecl, ExactSpelling = true)]
+        internal static extern void abort_import(int id, byte* import_bytes, nuint import_len);
 
         /// <summary>
         ///  # Safety
         ///
-        ///  Executes the "export" method of a SurrealDB engine (given its id).
+        ///  Executes the "export" method of a SurrealDB engine (given its id), using the given session (default session if empty).
+        ///  Returns the request id, which can be used to cancel the request.
         /// </summary>
         [DllImport(__DllName, EntryPoint = "export", CallingConvention = CallingConvention.Cdecl, ExactSpelling = true)]
-        internal static extern void export(int id, byte* bytes, int len, SuccessAction success, FailureAction failure);
+        internal static extern ulong export(int id, byte* session_bytes, nuint session_len, byte* bytes, nuint len, SuccessAction success, FailureAction failure);
+
+        /// <summary>
+        ///  # Safety
+        ///
+        ///  Executes the "export" method of a SurrealDB engine (given its id), using the given session (default session if empty),
+        ///  streaming the output.
+        ///  Each chunk produced by the datastore is sent to the chunk callback, in order,
+        ///  then the success callback is invoked once the export is complete.
+        ///  Returns the request id, which can be used to cancel the request.
+        /// </summary>
+        [DllImport(__DllName, EntryPoint = "export_stream", CallingConvention = CallingConvention.Cdecl, ExactSpelling = true)]
+        internal static extern ulong export_stream(int id, byte* session_bytes, nuint session_len, byte* bytes, nuint len, ChunkAction chunk, SuccessAction success, FailureAction failure);
+
+        /// <summary>
+        ///  # Safety
+        ///
+        ///  Backs a RocksDB or SurrealKV engine (given its id) up into a target directory (given its path),
+        ///  which must not exist or be empty.
+        ///  The backup is a consistent copy of the datastore, usable with the "restore" connection option.
+        ///  Writers are not blocked while the backup is in progress.
+        ///  The cluster keys of the engine (nodes, heartbeats, leases) are left out.
+        ///  Returns the request id, which can be used to cancel the request.
+        /// </summary>
+        [DllImport(__DllName, EntryPoint = "backup", CallingConvention = CallingConvention.Cdecl, ExactSpelling = true)]
+        internal static extern ulong backup(int id, ushort* utf16_str, nuint utf16_len, SuccessAction success, FailureAction failure);
 
         /// <summary>
         ///  # Safety
         ///
         ///  This function is used to free Rust memory from a C# binding.
+        ///  The memory is kept for reuse by the buffer pool, when possible.
         /// </summary>
         [DllImport(__DllName, EntryPoint = "free_u8_buffer", CallingConvention = CallingConvention.Cdecl, ExactSpelling = true)]
         internal static extern void free_u8_buffer(ByteBuffer* buffer);
 
+        /// <summary>
+        ///  # Safety
+        ///
+        ///  Configures the pool of result buffers, given its options (`max_buffer_size`, `max_buffers`).
+        ///  An empty buffer stands for the default options, and 0 `max_buffers` disables pooling.
+        /// </summary>
+        [DllImport(__DllName, EntryPoint = "configure_buffer_pool", CallingConvention = CallingConvention.Cdecl, ExactSpelling = true)]
+        internal static extern void configure_buffer_pool(byte* bytes, nuint len, SuccessAction success, FailureAction failure);
+
+        /// <summary>
+        ///  Sends the statistics of the pool of result buffers to the success callback, as a CBOR object.
+        /// </summary>
+        [DllImport(__DllName, EntryPoint = "get_buffer_pool_stats", CallingConvention = CallingConvention.Cdecl, ExactSpelling = true)]
+        internal static extern void get_buffer_pool_stats(SuccessAction success, FailureAction failure);
+
+        /// <summary>
+        ///  Disposes a SurrealDB engine (given its id), once the requests being executed on it have completed.
+        ///  Requests made after this call fail as if the engine was missing, and requests on other engines are not delayed.
+        ///  Open transactions are cancelled and the datastore is shut down before the success callback is invoked,
+        ///  so the same storage can be reopened right away.
+        /// </summary>
         [DllImport(__DllName, EntryPoint = "dispose", CallingConvention = CallingConvention.Cdecl, ExactSpelling = true)]
-        internal static extern void dispose(int id);
+        internal static extern void dispose(int id, SuccessAction success, FailureAction failure);
 
         /// <summary>
         ///  # Safety
         ///
-        ///  This function is called to initialize the async runtime (using tokio).
+        ///  Registers the callback used to forward log events (of the native library and SurrealDB) to C#,
+        ///  keeping the events matching the given filter directives (e.g. "warn" or "surrealdb=debug,shared=trace").
+        ///  Each event is a CBOR object: `{ level, target, message, fields, engine_id }`, the level matching the .NET `LogLevel`
+        ///  and the engine id being omitted for events not emitted on behalf of an engine.
+        ///  The buffer given to the callback is freed once it returns.
+        ///  Registering a new callback drops the previous one.
+        /// </summary>
+        [DllImport(__DllName, EntryPoint = "register_log_action", CallingConvention = CallingConvention.Cdecl, ExactSpelling = true)]
+        internal static extern void register_log_action(LogAction action, ushort* utf16_str, nuint utf16_len, SuccessAction success, FailureAction failure);
+
+        /// <summary>
+        ///  # Safety
+        ///
+        ///  Changes the filter directives (e.g. "warn" or "surrealdb=debug,shared=trace") of the forwarded log events.
+        /// </summary>
+        [DllImport(__DllName, EntryPoint = "set_log_filter", CallingConvention = CallingConvention.Cdecl, ExactSpelling = true)]
+        internal static extern void set_log_filter(ushort* utf16_str, nuint utf16_len, SuccessAction success, FailureAction failure);
+
+        /// <summary>
+        ///  Sends the metrics of the native library to the success callback, as a CBOR object:
+        ///  - `engines`: the sessions, open transactions, live queries and in-flight requests of each engine
+        ///  - `latencies`: a histogram of the execution time of each request (method, "import", "export", "backup", ...), with `latency_bounds_us` as bucket bounds
+        ///  - `bytes_in` and `bytes_out`: the bytes exchanged with .NET since the library was loaded
+        ///  - `runtime`: the workers, alive tasks, queue depth, busy duration and park count of the runtime
+        ///
+        ///  Returns the request id, which can be used to cancel the request.
+        /// </summary>
+        [DllImport(__DllName, EntryPoint = "get_metrics", CallingConvention = CallingConvention.Cdecl, ExactSpelling = true)]
+        internal static extern ulong get_metrics(SuccessAction success, FailureAction failure);
+
+        /// <summary>
+        ///  # Safety
+        ///
+        ///  This function is called to initialize the async runtime (using tokio), given its options.
+        ///  An empty buffer stands for the default options.
+        ///  Does nothing if the runtime has already been created.
         /// </summary>
         [DllImport(__DllName, EntryPoint = "create_global_runtime", CallingConvention = CallingConvention.Cdecl, ExactSpelling = true)]
-        internal static extern void create_global_runtime();
+        internal static extern void create_global_runtime(byte* bytes, nuint len, SuccessAction success, FailureAction failure);
+
+        /// <summary>
+        ///  Shuts the async runtime down, cancelling pending requests and disposing every engine first.
+        ///  The notification and log callbacks are released as well, so the .NET assembly can be unloaded.
+        ///  The runtime can be created again afterwards.
+        /// </summary>
+        [DllImport(__DllName, EntryPoint = "shutdown_global_runtime", CallingConvention = CallingConvention.Cdecl, ExactSpelling = true)]
+        internal static extern void shutdown_global_runtime();
+
+        /// <summary>
+        ///  Registers the callback used to push live query notifications (of every engine) to C#.
+        ///  Registering a new callback drops the previous one, once no notification is being sent with it.
+        /// </summary>
+        [DllImport(__DllName, EntryPoint = "register_notification_action", CallingConvention = CallingConvention.Cdecl, ExactSpelling = true)]
+        internal static extern void register_notification_action(NotificationAction action);
+
+        /// <summary>
+        ///  Cancels a pending request (given its id), dropping its work along with any implicit transaction.
+        ///  The failure callback of the request is invoked with a "Cancelled" error.
+        ///  Does nothing if the request has already completed.
+        /// </summary>
+        [DllImport(__DllName, EntryPoint = "cancel_request", CallingConvention = CallingConvention.Cdecl, ExactSpelling = true)]
+        internal static extern void cancel_request(ulong id);
+
+        /// <summary>
+        ///  # Safety
+        ///
+        ///  Serves a SurrealDB engine (given its id) over the `/rpc` (WebSocket, CBOR or JSON) and `/sql` (HTTP) endpoints
+        ///  of SurrealDB, so that tools such as Surrealist can inspect it while debugging.
+        ///  The options (`address`, `auth`) are CBOR encoded, an empty buffer standing for the default options:
+        ///  listening on "127.0.0.1:8000" and requiring clients to sign in.
+        ///  With `auth` disabled, every client is given owner rights, so no untrusted client should be able to reach the address.
+        ///  Sends `{ id, address }` to the success callback, the server id being used to stop it (see `stop_serving`).
+        ///  Returns the request id, which can be used to cancel the request.
+        /// </summary>
+        [DllImport(__DllName, EntryPoint = "serve", CallingConvention = CallingConvention.Cdecl, ExactSpelling = true)]
+        internal static extern ulong serve(int id, byte* bytes, nuint len, SuccessAction success, FailureAction failure);
+
+        /// <summary>
+        ///  Stops a debug server (given its id), closing its connections.
+        ///  Does nothing if the server has already been stopped, e.g. when its engine was disposed.
+        ///  Returns the request id, which can be used to cancel the request.
+        /// </summary>
+        [DllImport(__DllName, EntryPoint = "stop_serving", CallingConvention = CallingConvention.Cdecl, ExactSpelling = true)]
+        internal static extern ulong stop_serving(ulong server_id, SuccessAction success, FailureAction failure);
 
 
     }
@@ -78,8 +280,8 @@ namespace SurrealDb.Embedded.Internals
     internal unsafe partial struct ByteBuffer
     {
         public byte* ptr;
-        public int length;
-        public int capacity;
+        public nuint length;
+        public nuint capacity;
     }
 
     [StructLayout(LayoutKind.Sequential)]
@@ -103,6 +305,37 @@ namespace SurrealDb.Embedded.Internals
         public delegate* unmanaged[Cdecl]<nint, ByteBuffer*, void> callback;
     }
 
+    [StructLayout(LayoutKind.Sequential)]
+    internal unsafe partial struct ChunkAction
+    {
+        public RustGCHandle handle;
+        public delegate* unmanaged[Cdecl]<nint, ByteBuffer*, void> callback;
+    }
+
+    [StructLayout(LayoutKind.Sequential)]
+    internal unsafe partial struct NotificationAction
+    {
+        public RustGCHandle handle;
+        public delegate* unmanaged[Cdecl]<nint, int, ByteBuffer*, void> callback;
+    }
+
+    [StructLayout(LayoutKind.Sequential)]
+    internal unsafe partial struct LogAction
+    {
+        public RustGCHandle handle;
+        public delegate* unmanaged[Cdecl]<nint, ByteBuffer*, void> callback;
+    }
+
+    /// <summary>
+    ///  Releases a buffer borrowed from .NET (e.g. unpins it), once it is no longer read.
+    /// </summary>
+    [StructLayout(LayoutKind.Sequential)]
+    internal unsafe partial struct ReleaseAction
+    {
+        public nint ptr;
+        public delegate* unmanaged[Cdecl]<nint, void> callback;
+    }
+
 
     internal enum Method : byte
     {
@@ -129,6 +362,13 @@ namespace SurrealDb.Embedded.Internals
         Begin = 21,
         Commit = 22,
         Cancel = 23,
+        Live = 24,
+        Kill = 25,
+        Signin = 26,
+        Signup = 27,
+        Authenticate = 28,
+        Invalidate = 29,
+        Info = 30,
     }
 
 
@@ -56,6 +56,33 @@ public class KillTests
         await func.Should().NotThrowAsync();
     }
 
+    [Test]
+    [Arguments("Endpoint=mem://")]
+    public async Task ShouldKillActiveLiveQueryInEmbeddedMode(string connectionString)
+    {
+        Func<Task> func = async () =>
+        {
+            await using var surrealDbClientGenerator = new SurrealDbClientGenerator();
+            var dbInfo = surrealDbClientGenerator.GenerateDatabaseInfo();
+
+            await using var client = surrealDbClientGenerator.Create(connectionString);
+            await client.Use(dbInfo.Namespace, dbInfo.Database);
+
+            await client.RawQuery("DEFINE TABLE test SCHEMALESS;");
+
+            var response = await client.RawQuery("LIVE SELECT * FROM test;");
+
+            if (response.FirstResult is not SurrealDbOkResult okResult)
+                throw new Exception("Expected a SurrealDbOkResult");
+
+            var liveQueryUuid = okResult.GetValue<Guid>();
+
+            await client.Kill(liveQueryUuid);
+        };
+
+        await func.Should().NotThrowAsync();
+    }
+
     [Test]
     [Arguments("Endpoint=ws://127.0.0.1:8000/rpc;User=root;Pass=root")]
     public async Task ShouldFailToKillInexistantLiveQueryOnWsProtocol(string connectionString)
@@ -106,6 +106,121 @@ public class LiveTableTests : BaseLiveQueryTests
         lastResult.Should().BeOfType<SurrealDbLiveQueryCloseResponse>();
     }
 
+    [Test]
+    [Arguments("Endpoint=mem://")]
+    public async Task ShouldReceiveDataInEmbeddedMode(string connectionString)
+    {
+        var allResults = new List<SurrealDbLiveQueryResponse>();
+
+        Func<Task> func = async () =>
+        {
+            await using var surrealDbClientGenerator = new SurrealDbClientGenerator();
+            var dbInfo = surrealDbClientGenerator.GenerateDatabaseInfo();
+
+            await using var client = surrealDbClientGenerator.Create(connectionString);
+            await client.Use(dbInfo.Namespace, dbInfo.Database);
+
+            await client.RawQuery("DEFINE TABLE test SCHEMALESS;");
+
+            var liveQuery = await client.LiveTable<TestRecord>("test");
+
+            using var cts = new CancellationTokenSource();
+
+            _ = Task.Run(async () =>
+            {
+                await foreach (var result in liveQuery.WithCancellation(cts.Token))
+                {
+                    allResults.Add(result);
+                }
+            });
+
+            _ = Task.Run(async () =>
+            {
+                await WaitLiveQueryCreationAsync();
+
+                var record = await client.Create("test", new TestRecord { Value = 1 });
+                await WaitLiveQueryNotificationAsync();
+
+                await client.Upsert(new TestRecord { Id = record.Id, Value = 2 });
+                await WaitLiveQueryNotificationAsync();
+
+                await client.Delete(record.Id!);
+                await WaitLiveQueryNotificationAsync();
+
+                await liveQuery.KillAsync();
+                await WaitLiveQueryNotificationAsync();
+
+                await cts.CancelAsync();
+            });
+
+            await Task.Delay(Timeout);
+
+            if (!cts.IsCancellationRequested)
+            {
+                await cts.CancelAsync();
+                throw new Exception("Timeout");
+            }
+        };
+
+        await func.Should().NotThrowAsync();
+
+        allResults.Should().HaveCount(5);
+
+        var firstResult = allResults[0];
+        firstResult.Should().BeOfType<SurrealDbLiveQueryOpenResponse>();
+
+        var secondResult = allResults[1];
+        secondResult.Should().BeOfType<SurrealDbLiveQueryCreateResponse<TestRecord>>();
+
+        var thirdResult = allResults[2];
+        thirdResult.Should().BeOfType<SurrealDbLiveQueryUpdateResponse<TestRecord>>();
+
+        var fourthResult = allResults[3];
+        fourthResult.Should().BeOfType<SurrealDbLiveQueryDeleteResponse<TestRecord>>();
+
+        var lastResult = allResults[4];
+        lastResult.Should().BeOfType<SurrealDbLiveQueryCloseResponse>();
+    }
+
+    [Test]
+    [Arguments("Endpoint=mem://")]
+    public async Task ShouldCloseWhenEmbeddedEngineIsDisposed(string connectionString)
+    {
+        var allResults = new List<SurrealDbLiveQueryResponse>();
+
+        await using var surrealDbClientGenerator = new SurrealDbClientGenerator();
+        var dbInfo = surrealDbClientGenerator.GenerateDatabaseInfo();
+
+        var client = surrealDbClientGenerator.Create(connectionString);
+        await client.Use(dbInfo.Namespace, dbInfo.Database);
+
+        await client.RawQuery("DEFINE TABLE test SCHEMALESS;");
+
+        var liveQuery = await client.LiveTable<TestRecord>("test");
+
+        var readTask = Task.Run(async () =>
+        {
+            await foreach (var result in liveQuery)
+            {
+                allResults.Add(result);
+            }
+        });
+
+        await WaitLiveQueryCreationAsync();
+        await client.DisposeAsync();
+
+        // The channel is completed, so the enumeration ends without being cancelled
+        await readTask.WaitAsync(Timeout);
+
+        allResults.Should().HaveCount(2);
+        allResults[0].Should().BeOfType<SurrealDbLiveQueryOpenResponse>();
+        allResults[1]
+            .Should()
+            .BeOfType<SurrealDbLiveQueryCloseResponse>()
+            .Which.Reason.Should()
+            .Be(SurrealDbLiveQueryClosureReason.ConnectionTerminated);
+    }
+
     [Test]
     [Skip("The DELETE event does not send a JsonPatchDocument at the moment")]
     [Arguments("Endpoint=ws://127.0.0.1:8000/rpc;User=root;Pass=root")]
@@ -1,8 +1,17 @@
-﻿using Bogus;
+﻿extern alias RocksDb;
+extern alias SurrealKv;
+
+using Bogus;
 using Microsoft.Extensions.DependencyInjection;
+using Microsoft.Extensions.Options;
+using RocksDb::Microsoft.Extensions.DependencyInjection;
 using Semver;
+using SurrealDb.Embedded.Options;
 using SurrealDb.Net.Extensions;
 using SurrealDb.Net.Models.Auth;
+using SurrealKv::Microsoft.Extensions.DependencyInjection;
+using RocksDbOptions = RocksDb::SurrealDb.Embedded.Options.SurrealDbEmbeddedOptions;
+using SurrealKvOptions = SurrealKv::SurrealDb.Embedded.Options.SurrealDbEmbeddedOptions;
 
 namespace SurrealDb.Net.Tests.Fixtures;
 
@@ -69,9 +78,14 @@ public sealed class SurrealDbClientGenerator : IAsyncDisposable
     }
 
     // TODO : Remove to simplify with Configure()/ctor + GetSingleton()
-    public SurrealDbClient Create(string connectionString, string? ns = null, string? db = null)
+    public SurrealDbClient Create(
+        string connectionString,
+        string? ns = null,
+        string? db = null,
+        RootAuth? embeddedAuth = null
+    )
     {
-        Configure(connectionString, ns, db);
+        Configure(connectionString, ns, db, embeddedAuth: embeddedAuth);
         return _serviceProvider!.GetRequiredService<SurrealDbClient>();
     }
 
@@ -79,7 +93,8 @@ public sealed class SurrealDbClientGenerator : IAsyncDisposable
         string connectionString,
         string? ns = null,
         string? db = null,
-        ServiceLifetime lifetime = ServiceLifetime.Singleton
+        ServiceLifetime lifetime = ServiceLifetime.Singleton,
+        RootAuth? embeddedAuth = null
     )
     {
         var optionsBuilder = SurrealDbOptions.Create().FromConnectionString(connectionString);
@@ -107,7 +122,37 @@ public sealed class SurrealDbClientGenerator : IAsyncDisposable
 
         _options = optionsBuilder.Build();
 
-        _serviceProvider = new ServiceCollection()
+        var services = new ServiceCollection();
+        if (embeddedAuth is not null)
+        {
+            // Each embedded engine reads the options type of its own assembly
+            services.AddSingleton(
+                Options.Create(
+                    SurrealDbEmbeddedOptions
+                        .Create()
+                        .WithAuthentication(embeddedAuth.Username, embeddedAuth.Password)
+                        .Build()
+                )
+            );
+            services.AddSingleton(
+                Options.Create(
+                    RocksDbOptions
+                        .Create()
+                        .WithAuthentication(embeddedAuth.Username, embeddedAuth.Password)
+                        .Build()
+                )
+            );
+            services.AddSingleton(
+                Options.Create(
+                    SurrealKvOptions
+                        .Create()
+                        .WithAuthentication(embeddedAuth.Username, embeddedAuth.Password)
+                        .Build()
+                )
+            );
+        }
+
+        _serviceProvider = services
             .AddSurreal(_options, lifetime: lifetime)
             .AddInMemoryProvider()
             .AddRocksDbProvider()
@@ -1,4 +1,6 @@
-﻿namespace SurrealDb.Net.Tests;
+﻿using SurrealDb.Net.Exceptions.Embedded;
+
+namespace SurrealDb.Net.Tests;
 
 public class AuthenticateTests
 {
@@ -47,14 +49,20 @@ public class AuthenticateTests
 
     [Test]
     [EmbeddedConnectionStringFixtureGenerator]
-    public async Task InvalidateIsNotSupportedInEmbeddedMode(string connectionString)
+    public async Task ShouldAuthenticateInEmbeddedMode(string connectionString)
     {
+        Tokens? tokens = null;
+        IEnumerable<Post>? list = null;
+
         Func<Task> func = async () =>
         {
             await using var surrealDbClientGenerator = new SurrealDbClientGenerator();
             var dbInfo = surrealDbClientGenerator.GenerateDatabaseInfo();
-
-            await using var client = surrealDbClientGenerator.Create(connectionString);
+            await using var client = surrealDbClientGenerator.Create(
+                connectionString,
+                embeddedAuth: new RootAuth { Username = "root", Password = "root" }
+            );
+            await client.SignIn(new RootAuth { Username = "root", Password = "root" });
             await client.Use(dbInfo.Namespace, dbInfo.Database);
 
             await client.ApplySchemaAsync(SurrealSchemaFile.User);
@@ -73,14 +81,39 @@ public class AuthenticateTests
             };
 #pragma warning restore CS0618 // Type or member is obsolete
 
-            var jwt = await client.SignUp(authParams);
+            tokens = await client.SignUp(authParams);
+
+            await client.Authenticate(tokens);
 
-            await client.Authenticate(jwt);
+            list = await client.Select<Post>("post");
         };
 
-        await func.Should()
-            .ThrowAsync<NotSupportedException>()
-            .WithMessage("Authentication is not enabled in embedded mode.");
+        await func.Should().NotThrowAsync();
+
+        list.Should().NotBeNull().And.HaveCount(2);
+    }
+
+    [Test]
+    [EmbeddedConnectionStringFixtureGenerator]
+    public async Task ShouldFailToAuthenticateWithInvalidTokenInEmbeddedMode(
+        string connectionString
+    )
+    {
+        Func<Task> func = async () =>
+        {
+            await using var surrealDbClientGenerator = new SurrealDbClientGenerator();
+            var dbInfo = surrealDbClientGenerator.GenerateDatabaseInfo();
+            await using var client = surrealDbClientGenerator.Create(
+                connectionString,
+                embeddedAuth: new RootAuth { Username = "root", Password = "root" }
+            );
+            await client.SignIn(new RootAuth { Username = "root", Password = "root" });
+            await client.Use(dbInfo.Namespace, dbInfo.Database);
+
+            await client.Authenticate(new Tokens { Access = "invalid" });
+        };
+
+        await func.Should().ThrowAsync<SurrealDbEmbeddedException>();
     }
 
     [Test]
@@ -101,6 +134,52 @@ public class AuthenticateTests
             await client.ApplySchemaAsync(SurrealSchemaFile.User);
             await client.ApplySchemaAsync(SurrealSchemaFile.Post);
 
+#pragma warning disable CS0618 // Type or member is obsolete
+            var authParams = new AuthParams
+            {
+                Namespace = dbInfo.Namespace,
+                Database = dbInfo.Database,
+                Scope = "user_scope",
+                Access = "user_scope",
+                Username = "johndoe",
+                Email = "john.doe@example.com",
+                Password = "password123",
+            };
+#pragma warning restore CS0618 // Type or member is obsolete
+
+            tokens = await client.SignUp(authParams);
+
+            await client.Invalidate();
+
+            list = await client.Select<Post>("post");
+        };
+
+        await func.Should().NotThrowAsync();
+
+        list.Should().NotBeNull().And.HaveCount(0);
+    }
+
+    [Test]
+    [EmbeddedConnectionStringFixtureGenerator]
+    public async Task ShouldFailWhenInvalidateInEmbeddedMode(string connectionString)
+    {
+        Tokens? tokens = null;
+        IEnumerable<Post>? list = null;
+
+        Func<Task> func = async () =>
+        {
+            await using var surrealDbClientGenerator = new SurrealDbClientGenerator();
+            var dbInfo = surrealDbClientGenerator.GenerateDatabaseInfo();
+            await using var client = surrealDbClientGenerator.Create(
+                connectionString,
+                embeddedAuth: new RootAuth { Username = "root", Password = "root" }
+            );
+            await client.SignIn(new RootAuth { Username = "root", Password = "root" });
+            await client.Use(dbInfo.Namespace, dbInfo.Database);
+
+            await client.ApplySchemaAsync(SurrealSchemaFile.User);
+            await client.ApplySchemaAsync(SurrealSchemaFile.Post);
+
 #pragma warning disable CS0618 // Type or member is obsolete
             var authParams = new AuthParams
             {
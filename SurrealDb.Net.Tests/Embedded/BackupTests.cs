@@ -0,0 +1,51 @@
+﻿extern alias RocksDb;
+
+using RocksDb::SurrealDb.Embedded.Options;
+using RocksDb::SurrealDb.Embedded.RocksDb;
+
+namespace SurrealDb.Net.Tests.Embedded;
+
+public class BackupTests
+{
+    [Test]
+    public async Task ShouldRestoreBackup()
+    {
+        string directory = Path.Combine(Path.GetTempPath(), Path.GetRandomFileName());
+        string databasePath = Path.Combine(directory, "db");
+        string backupPath = Path.Combine(directory, "backup");
+
+        try
+        {
+            await using (var client = new SurrealDbRocksDbClient(databasePath))
+            {
+                await client.Use("test", "test");
+                await client.Create("post", new Post { Content = "Before backup" });
+
+                await client.BackupAsync(backupPath);
+
+                await client.Create("post", new Post { Content = "After backup" });
+
+                // An existing backup is never overwritten
+                Func<Task> func = () => client.BackupAsync(backupPath);
+                await func.Should().ThrowAsync<Exception>();
+            }
+
+            var options = SurrealDbEmbeddedOptions.Create().WithRestore(backupPath).Build();
+            await using (var client = new SurrealDbRocksDbClient(databasePath, options))
+            {
+                await client.Use("test", "test");
+
+                var posts = await client.Select<Post>("post");
+                posts.Should().NotBeNull().And.HaveCount(1);
+                posts.First().Content.Should().Be("Before backup");
+            }
+        }
+        finally
+        {
+            if (Directory.Exists(directory))
+            {
+                Directory.Delete(directory, true);
+            }
+        }
+    }
+}
@@ -0,0 +1,45 @@
+﻿using SurrealDb.Embedded.InMemory;
+using SurrealDb.Embedded.Options;
+using SurrealDb.Net.Exceptions.Embedded;
+
+namespace SurrealDb.Net.Tests.Embedded;
+
+public class BufferPoolTests
+{
+    [Test]
+    public async Task ShouldExecuteWithConfiguredBufferPool()
+    {
+        SurrealDbEmbeddedRuntime.ConfigureBufferPool(
+            new SurrealDbEmbeddedBufferPoolOptions { MaxBufferSize = 1024, MaxBuffers = 4 }
+        );
+
+        try
+        {
+            await using var client = new SurrealDbMemoryClient();
+            await client.Use("test", "test");
+
+            // Results both smaller and larger than the pooled buffers
+            await client.Create("post", new Post { Content = "Small post" });
+            await client.Create("post", new Post { Content = new string('x', 4096) });
+
+            var posts = await client.Select<Post>("post");
+            posts.Should().NotBeNull().And.HaveCount(2);
+        }
+        finally
+        {
+            SurrealDbEmbeddedRuntime.ConfigureBufferPool(null);
+        }
+    }
+
+    [Test]
+    public void ShouldThrowInvalidParamsExceptionOnInvalidBufferPoolOptions()
+    {
+        Action action = () =>
+            SurrealDbEmbeddedRuntime.ConfigureBufferPool(
+                new SurrealDbEmbeddedBufferPoolOptions { MaxBuffers = -1 }
+            );
+
+        var exception = action.Should().Throw<SurrealDbEmbeddedInvalidParamsException>();
+        exception.Which.Message.Should().Contain("max_buffers");
+    }
+}
@@ -0,0 +1,44 @@
+﻿using SurrealDb.Embedded.InMemory;
+using SurrealDb.Embedded.Options;
+using SurrealDb.Net.Exceptions.Embedded;
+
+namespace SurrealDb.Net.Tests.Embedded;
+
+public class ErrorTests
+{
+    [Test]
+    public async Task ShouldThrowInvalidParamsExceptionWithoutNamespace()
+    {
+        await using var client = new SurrealDbMemoryClient();
+
+        Func<Task> func = () => client.Run<object>("fn::missing");
+
+        var exception = await func.Should().ThrowAsync<SurrealDbEmbeddedInvalidParamsException>();
+        exception.Which.Kind.Should().Be(SurrealDbEmbeddedErrorKind.InvalidParams);
+        exception.Which.Code.Should().Be(-32600);
+    }
+
+    [Test]
+    public async Task ShouldThrowPermissionExceptionOnInvalidCredentials()
+    {
+        var options = SurrealDbEmbeddedOptions.Create().WithAuthentication("root", "root").Build();
+        await using var client = new SurrealDbMemoryClient(options);
+
+        Func<Task> func = () =>
+            client.SignIn(new RootAuth { Username = "root", Password = "wrong" });
+
+        var exception = await func.Should().ThrowAsync<SurrealDbEmbeddedPermissionException>();
+        exception.Which.Kind.Should().Be(SurrealDbEmbeddedErrorKind.Permission);
+        exception.Which.Code.Should().Be(-32002);
+    }
+
+    [Test]
+    public void ShouldThrowInvalidParamsExceptionOnInvalidOptions()
+    {
+        Action action = () =>
+            SurrealDbEmbeddedOptions.Create().WithoutFunctions(["crypto::md5(", "("]).Build();
+
+        var exception = action.Should().Throw<SurrealDbEmbeddedInvalidParamsException>();
+        exception.Which.Message.Should().Contain("crypto::md5(").And.Contain("'('");
+    }
+}
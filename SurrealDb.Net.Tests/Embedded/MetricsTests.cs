@@ -0,0 +1,63 @@
+﻿using System.Diagnostics.Metrics;
+using SurrealDb.Embedded.InMemory;
+
+namespace SurrealDb.Net.Tests.Embedded;
+
+public class MetricsTests
+{
+    [Test]
+    public async Task ShouldPublishNativeMetrics()
+    {
+        await using var client = new SurrealDbMemoryClient();
+        await client.Use("test", "test");
+
+        await client.Create("post", new Post { Content = "Observed post" });
+
+        var measurements = new List<(string Name, long Value, string? RequestName)>();
+
+        using var listener = new MeterListener();
+        listener.InstrumentPublished = (instrument, meterListener) =>
+        {
+            // The meter is named after the engine assembly
+            if (instrument.Meter.Name == "SurrealDb.Embedded.InMemory")
+            {
+                meterListener.EnableMeasurementEvents(instrument);
+            }
+        };
+        listener.SetMeasurementEventCallback<long>(
+            (instrument, value, tags, _) =>
+            {
+                string? requestName = null;
+                foreach (var tag in tags)
+                {
+                    if (tag.Key == "surrealdb.request.name")
+                    {
+                        requestName = tag.Value as string;
+                    }
+                }
+
+                measurements.Add((instrument.Name, value, requestName));
+            }
+        );
+        listener.Start();
+        listener.RecordObservableInstruments();
+
+        measurements
+            .Should()
+            .Contain(measurement =>
+                measurement.Name == "surrealdb.embedded.sessions" && measurement.Value >= 1
+            );
+        measurements
+            .Should()
+            .Contain(measurement =>
+                measurement.Name == "surrealdb.embedded.requests"
+                && measurement.RequestName == "connect"
+                && measurement.Value >= 1
+            );
+        measurements
+            .Should()
+            .Contain(measurement =>
+                measurement.Name == "surrealdb.embedded.bytes_in" && measurement.Value > 0
+            );
+    }
+}
@@ -0,0 +1,23 @@
+﻿using SurrealDb.Embedded.InMemory;
+
+namespace SurrealDb.Net.Tests.Embedded;
+
+public class PinnedParamsTests
+{
+    [Test]
+    public async Task ShouldCreateRecordFromPinnedParams()
+    {
+        await using var client = new SurrealDbMemoryClient();
+        await client.Use("test", "test");
+
+        // Large enough for the params to be read from pinned memory
+        string content = new('x', 4 * 1024 * 1024);
+
+        var created = await client.Create("post", new Post { Content = content });
+        created.Content.Should().Be(content);
+
+        var posts = await client.Select<Post>("post");
+        posts.Should().NotBeNull().And.HaveCount(1);
+        posts.First().Content.Should().Be(content);
+    }
+}
@@ -0,0 +1,48 @@
+﻿using System.Net.Http.Headers;
+using SurrealDb.Embedded.InMemory;
+using SurrealDb.Embedded.Options;
+
+namespace SurrealDb.Net.Tests.Embedded;
+
+public class ServeTests
+{
+    [Test]
+    public async Task ShouldQueryServedDatabaseOverHttp()
+    {
+        await using var client = new SurrealDbMemoryClient();
+        await client.Use("test", "test");
+        await client.Create("post", new Post { Content = "Served post" });
+
+        using var httpClient = new HttpClient();
+
+        await using (
+            var server = await client.ServeAsync(
+                new SurrealDbEmbeddedServeOptions { Address = "127.0.0.1:0", Auth = false }
+            )
+        )
+        {
+            using var request = new HttpRequestMessage(
+                HttpMethod.Post,
+                $"http://{server.Address}/sql"
+            )
+            {
+                Content = new StringContent("SELECT * FROM post;"),
+            };
+            request.Headers.Accept.Add(new MediaTypeWithQualityHeaderValue("application/json"));
+            request.Headers.Add("surreal-ns", "test");
+            request.Headers.Add("surreal-db", "test");
+
+            using var response = await httpClient.SendAsync(request);
+            response.IsSuccessStatusCode.Should().BeTrue();
+
+            string body = await response.Content.ReadAsStringAsync();
+            body.Should().Contain("Served post");
+
+            await server.StopAsync();
+
+            // The connections are closed along with the server
+            Func<Task> func = () => httpClient.GetAsync($"http://{server.Address}/sql");
+            await func.Should().ThrowAsync<HttpRequestException>();
+        }
+    }
+}
@@ -1,4 +1,5 @@
-﻿using System.Text.RegularExpressions;
+﻿using System.Text;
+using System.Text.RegularExpressions;
 using Semver;
 
 namespace SurrealDb.Net.Tests;
@@ -124,4 +125,46 @@ public class ExportTests
 
         await Verify(result, _verifySettings);
     }
+
+    [Test]
+    [ConnectionStringFixtureGenerator]
+    [SinceSurrealVersion("2.0")]
+    public async Task ShouldExportToStream(string connectionString)
+    {
+        string? expected = null;
+        string? result = null;
+
+        Func<Task> func = async () =>
+        {
+            await using var surrealDbClientGenerator = new SurrealDbClientGenerator();
+            var dbInfo = surrealDbClientGenerator.GenerateDatabaseInfo();
+
+            await using var client = surrealDbClientGenerator.Create(connectionString);
+            await client.Use(dbInfo.Namespace, dbInfo.Database);
+
+            await client.ApplySchemaAsync(SurrealSchemaFile.Post);
+
+            await client.Delete("post");
+
+            var post = new Post
+            {
+                Id = ("post", "dotnet-123456"),
+                Title = "A new article",
+                Content = "This is a new article created using the .NET SDK",
+            };
+
+            await client.Create(post);
+
+            expected = await client.Export();
+
+            await using var stream = new MemoryStream();
+            await client.Export(stream);
+
+            result = Encoding.UTF8.GetString(stream.ToArray());
+        };
+
+        await func.Should().NotThrowAsync();
+
+        result.Should().NotBeNullOrEmpty().And.Be(expected);
+    }
 }
@@ -1,4 +1,5 @@
-﻿using Semver;
+﻿using System.Text;
+using Semver;
 
 namespace SurrealDb.Net.Tests;
 
@@ -57,4 +58,48 @@ public class ImportTests
 
         await client.DisposeAsync();
     }
+
+    [Test]
+    [ConnectionStringFixtureGenerator]
+    public async Task ShouldImportFromStream(string connectionString)
+    {
+        var version = await SurrealDbClientGenerator.GetSurrealTestVersion(connectionString);
+        if (version?.Major < 2)
+        {
+            return;
+        }
+
+        await using var surrealDbClientGenerator = new SurrealDbClientGenerator();
+        var dbInfo = surrealDbClientGenerator.GenerateDatabaseInfo();
+
+        var client = surrealDbClientGenerator.Create(connectionString);
+        await client.Use(dbInfo.Namespace, dbInfo.Database);
+
+        bool shouldPrefixOptionImport =
+            version is not null && version.Satisfies(SemVersionRange.AtLeast(new(3, 0, 4), true));
+
+        string importQuery = shouldPrefixOptionImport
+            ? $"{OPTION_IMPORT}\n{IMPORT_QUERY}"
+            : IMPORT_QUERY;
+
+        using var input = new MemoryStream(Encoding.UTF8.GetBytes(importQuery));
+
+        Func<Task> func = async () =>
+        {
+            await client.Import(input);
+        };
+
+        await func.Should().NotThrowAsync();
+
+        var fooRecords = await client.Select<object>("foo");
+        fooRecords.Should().NotBeNull().And.HaveCount(1);
+
+        var barRecords = await client.Select<object>("bar");
+        barRecords.Should().NotBeNull().And.HaveCount(1);
+
+        var fnResult = await client.Run<string>("fn::foo");
+        fnResult.Should().Be("bar");
+
+        await client.DisposeAsync();
+    }
 }
@@ -21,4 +21,10 @@ public class DbLoggerCategoryTests
     {
         DbLoggerCategory.Query.Name.Should().Be("SurrealDB.Query");
     }
+
+    [Test]
+    public void EmbeddedLoggerCategoryShouldHaveTheCorrectName()
+    {
+        DbLoggerCategory.Embedded.Name.Should().Be("SurrealDB.Embedded");
+    }
 }
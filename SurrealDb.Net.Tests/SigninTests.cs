@@ -1,5 +1,6 @@
 ﻿using System.ComponentModel.DataAnnotations.Schema;
 using SurrealDb.Net.Exceptions;
+using SurrealDb.Net.Exceptions.Embedded;
 using SurrealDb.Net.Exceptions.Rpc;
 
 namespace SurrealDb.Net.Tests;
@@ -79,19 +80,40 @@ public class SignInTests
 
     [Test]
     [EmbeddedConnectionStringFixtureGenerator]
-    public async Task SignInAsRootUserIsNotSupportedInEmbeddedMode(string connectionString)
+    public async Task ShouldSignInAsRootUserInEmbeddedMode(string connectionString)
     {
         Func<Task> func = async () =>
         {
             await using var surrealDbClientGenerator = new SurrealDbClientGenerator();
-
-            await using var client = surrealDbClientGenerator.Create(connectionString);
+            await using var client = surrealDbClientGenerator.Create(
+                connectionString,
+                embeddedAuth: new RootAuth { Username = "root", Password = "root" }
+            );
             await client.SignIn(new RootAuth { Username = "root", Password = "root" });
         };
 
+        await func.Should().NotThrowAsync();
+    }
+
+    [Test]
+    [EmbeddedConnectionStringFixtureGenerator]
+    public async Task ShouldFailedToSignInAsRootUserWithInvalidCredentialsInEmbeddedMode(
+        string connectionString
+    )
+    {
+        Func<Task> func = async () =>
+        {
+            await using var surrealDbClientGenerator = new SurrealDbClientGenerator();
+            await using var client = surrealDbClientGenerator.Create(
+                connectionString,
+                embeddedAuth: new RootAuth { Username = "root", Password = "root" }
+            );
+            await client.SignIn(new RootAuth { Username = "root", Password = "password" });
+        };
+
         await func.Should()
-            .ThrowAsync<NotSupportedException>()
-            .WithMessage("Authentication is not enabled in embedded mode.");
+            .ThrowAsync<SurrealDbEmbeddedPermissionException>()
+            .WithMessage("There was a problem with authentication");
     }
 
     [Test]
@@ -129,20 +151,25 @@ public class SignInTests
 
     [Test]
     [EmbeddedConnectionStringFixtureGenerator]
-    public async Task SignInUsingNamespaceAuthIsNotSupportedInEmbeddedMode(string connectionString)
+    public async Task ShouldSignInUsingNamespaceAuthInEmbeddedMode(string connectionString)
     {
+        Tokens? tokens = null;
+
         Func<Task> func = async () =>
         {
             await using var surrealDbClientGenerator = new SurrealDbClientGenerator();
             var dbInfo = surrealDbClientGenerator.GenerateDatabaseInfo();
-
-            await using var client = surrealDbClientGenerator.Create(connectionString);
+            await using var client = surrealDbClientGenerator.Create(
+                connectionString,
+                embeddedAuth: new RootAuth { Username = "root", Password = "root" }
+            );
+            await client.SignIn(new RootAuth { Username = "root", Password = "root" });
             await client.Use(dbInfo.Namespace, dbInfo.Database);
 
             string query = "DEFINE USER johndoe ON NAMESPACE PASSWORD 'password123'";
             (await client.RawQuery(query)).EnsureAllOks();
 
-            await client.SignIn(
+            tokens = await client.SignIn(
                 new NamespaceAuth
                 {
                     Namespace = dbInfo.Namespace,
@@ -152,9 +179,10 @@ public class SignInTests
             );
         };
 
-        await func.Should()
-            .ThrowAsync<NotSupportedException>()
-            .WithMessage("Authentication is not enabled in embedded mode.");
+        await func.Should().NotThrowAsync();
+
+        tokens.Should().NotBeNull();
+        tokens!.Access.Should().BeValidJwt();
     }
 
     [Test]
@@ -193,20 +221,25 @@ public class SignInTests
 
     [Test]
     [EmbeddedConnectionStringFixtureGenerator]
-    public async Task SignInUsingDatabaseAuthIsNotSupportedInEmbeddedMode(string connectionString)
+    public async Task ShouldSignInUsingDatabaseAuthInEmbeddedMode(string connectionString)
     {
+        Tokens? tokens = null;
+
         Func<Task> func = async () =>
         {
             await using var surrealDbClientGenerator = new SurrealDbClientGenerator();
             var dbInfo = surrealDbClientGenerator.GenerateDatabaseInfo();
-
-            await using var client = surrealDbClientGenerator.Create(connectionString);
+            await using var client = surrealDbClientGenerator.Create(
+                connectionString,
+                embeddedAuth: new RootAuth { Username = "root", Password = "root" }
+            );
+            await client.SignIn(new RootAuth { Username = "root", Password = "root" });
             await client.Use(dbInfo.Namespace, dbInfo.Database);
 
             string query = "DEFINE USER johndoe ON DATABASE PASSWORD 'password123'";
             (await client.RawQuery(query)).EnsureAllOks();
 
-            await client.SignIn(
+            tokens = await client.SignIn(
                 new DatabaseAuth
                 {
                     Namespace = dbInfo.Namespace,
@@ -217,9 +250,10 @@ public class SignInTests
             );
         };
 
-        await func.Should()
-            .ThrowAsync<NotSupportedException>()
-            .WithMessage("Authentication is not enabled in embedded mode.");
+        await func.Should().NotThrowAsync();
+
+        tokens.Should().NotBeNull();
+        tokens!.Access.Should().BeValidJwt();
     }
 
     [Test]
@@ -264,14 +298,62 @@ public class SignInTests
 
     [Test]
     [EmbeddedConnectionStringFixtureGenerator]
-    public async Task SignInUsingScopeAuthIsNotSupportedInEmbeddedMode(string connectionString)
+    public async Task ShouldSignInUsingScopeAuthInEmbeddedMode(string connectionString)
     {
+        Tokens? tokens = null;
+
         Func<Task> func = async () =>
         {
             await using var surrealDbClientGenerator = new SurrealDbClientGenerator();
             var dbInfo = surrealDbClientGenerator.GenerateDatabaseInfo();
+            await using var client = surrealDbClientGenerator.Create(
+                connectionString,
+                embeddedAuth: new RootAuth { Username = "root", Password = "root" }
+            );
+            await client.SignIn(new RootAuth { Username = "root", Password = "root" });
+            await client.Use(dbInfo.Namespace, dbInfo.Database);
 
-            await using var client = surrealDbClientGenerator.Create(connectionString);
+            await client.ApplySchemaAsync(SurrealSchemaFile.User);
+
+#pragma warning disable CS0618 // Type or member is obsolete
+            var authParams = new AuthParams
+            {
+                Namespace = dbInfo.Namespace,
+                Database = dbInfo.Database,
+                Scope = "user_scope",
+                Access = "user_scope",
+                Username = "johndoe",
+                Email = "john.doe@example.com",
+                Password = "password123",
+            };
+#pragma warning restore CS0618 // Type or member is obsolete
+
+            await client.SignUp(authParams);
+
+            tokens = await client.SignIn(authParams);
+        };
+
+        await func.Should().NotThrowAsync();
+
+        tokens.Should().NotBeNull();
+        tokens!.Access.Should().BeValidJwt();
+    }
+
+    [Test]
+    [EmbeddedConnectionStringFixtureGenerator]
+    public async Task ShouldFailedToSignInUsingScopeAuthWithInvalidCredentialsInEmbeddedMode(
+        string connectionString
+    )
+    {
+        Func<Task> func = async () =>
+        {
+            await using var surrealDbClientGenerator = new SurrealDbClientGenerator();
+            var dbInfo = surrealDbClientGenerator.GenerateDatabaseInfo();
+            await using var client = surrealDbClientGenerator.Create(
+                connectionString,
+                embeddedAuth: new RootAuth { Username = "root", Password = "root" }
+            );
+            await client.SignIn(new RootAuth { Username = "root", Password = "root" });
             await client.Use(dbInfo.Namespace, dbInfo.Database);
 
             await client.ApplySchemaAsync(SurrealSchemaFile.User);
@@ -291,12 +373,11 @@ public class SignInTests
 
             await client.SignUp(authParams);
 
+            authParams.Password = "password";
             await client.SignIn(authParams);
         };
 
-        await func.Should()
-            .ThrowAsync<NotSupportedException>()
-            .WithMessage("Authentication is not enabled in embedded mode.");
+        await func.Should().ThrowAsync<SurrealDbEmbeddedPermissionException>();
     }
 
     [Test]
@@ -1,4 +1,6 @@
-﻿namespace SurrealDb.Net.Tests;
+﻿using SurrealDb.Net.Exceptions.Embedded;
+
+namespace SurrealDb.Net.Tests;
 
 public class SignUpTests
 {
@@ -42,14 +44,19 @@ public class SignUpTests
 
     [Test]
     [EmbeddedConnectionStringFixtureGenerator]
-    public async Task SignUpIsNotSupportedInEmbeddedMode(string connectionString)
+    public async Task ShouldSignUpUsingScopeAuthInEmbeddedMode(string connectionString)
     {
+        Tokens? tokens = null;
+
         Func<Task> func = async () =>
         {
             await using var surrealDbClientGenerator = new SurrealDbClientGenerator();
             var dbInfo = surrealDbClientGenerator.GenerateDatabaseInfo();
-
-            await using var client = surrealDbClientGenerator.Create(connectionString);
+            await using var client = surrealDbClientGenerator.Create(
+                connectionString,
+                embeddedAuth: new RootAuth { Username = "root", Password = "root" }
+            );
+            await client.SignIn(new RootAuth { Username = "root", Password = "root" });
             await client.Use(dbInfo.Namespace, dbInfo.Database);
 
             await client.ApplySchemaAsync(SurrealSchemaFile.User);
@@ -67,11 +74,48 @@ public class SignUpTests
             };
 #pragma warning restore CS0618 // Type or member is obsolete
 
+            tokens = await client.SignUp(authParams);
+        };
+
+        await func.Should().NotThrowAsync();
+
+        tokens.Should().NotBeNull();
+        tokens!.Access.Should().BeValidJwt();
+    }
+
+    [Test]
+    [EmbeddedConnectionStringFixtureGenerator]
+    public async Task ShouldFailedToSignUpUsingUnknownScopeInEmbeddedMode(string connectionString)
+    {
+        Func<Task> func = async () =>
+        {
+            await using var surrealDbClientGenerator = new SurrealDbClientGenerator();
+            var dbInfo = surrealDbClientGenerator.GenerateDatabaseInfo();
+            await using var client = surrealDbClientGenerator.Create(
+                connectionString,
+                embeddedAuth: new RootAuth { Username = "root", Password = "root" }
+            );
+            await client.SignIn(new RootAuth { Username = "root", Password = "root" });
+            await client.Use(dbInfo.Namespace, dbInfo.Database);
+
+            await client.ApplySchemaAsync(SurrealSchemaFile.User);
+
+#pragma warning disable CS0618 // Type or member is obsolete
+            var authParams = new AuthParams
+            {
+                Namespace = dbInfo.Namespace,
+                Database = dbInfo.Database,
+                Scope = "unknown_scope",
+                Access = "unknown_scope",
+                Username = "johndoe",
+                Email = "john.doe@example.com",
+                Password = "password123",
+            };
+#pragma warning restore CS0618 // Type or member is obsolete
+
             await client.SignUp(authParams);
         };
 
-        await func.Should()
-            .ThrowAsync<NotSupportedException>()
-            .WithMessage("Authentication is not enabled in embedded mode.");
+        await func.Should().ThrowAsync<SurrealDbEmbeddedException>();
     }
 }
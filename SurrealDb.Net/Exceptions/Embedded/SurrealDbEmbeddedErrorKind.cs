@@ -0,0 +1,18 @@
+﻿namespace SurrealDb.Net.Exceptions.Embedded;
+
+/// <summary>
+/// The category of an error returned by the SurrealDB embedded engine.
+/// </summary>
+public enum SurrealDbEmbeddedErrorKind : byte
+{
+    Internal = 0,
+    NotFound = 1,
+    InvalidParams = 2,
+    TransactionConflict = 3,
+    ParseError = 4,
+    Permission = 5,
+    EngineMissing = 6,
+    Serialization = 7,
+    Cancelled = 8,
+    TimedOut = 9,
+}
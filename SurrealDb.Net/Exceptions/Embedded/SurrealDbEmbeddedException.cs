@@ -1,7 +1,28 @@
 ﻿namespace SurrealDb.Net.Exceptions.Embedded;
 
-public sealed class SurrealDbEmbeddedException : SurrealDbException
+/// <summary>
+/// Error returned by the SurrealDB embedded engine.
+/// Dedicated subclasses are thrown for the most common kinds of errors.
+/// </summary>
+public class SurrealDbEmbeddedException : SurrealDbException
 {
+    /// <summary>
+    /// The category of the error.
+    /// </summary>
+    public SurrealDbEmbeddedErrorKind Kind { get; }
+
+    /// <summary>
+    /// The RPC error code, if any.
+    /// </summary>
+    public long? Code { get; }
+
     public SurrealDbEmbeddedException(string message)
         : base(message) { }
+
+    public SurrealDbEmbeddedException(SurrealDbEmbeddedErrorKind kind, long? code, string message)
+        : base(message)
+    {
+        Kind = kind;
+        Code = code;
+    }
 }
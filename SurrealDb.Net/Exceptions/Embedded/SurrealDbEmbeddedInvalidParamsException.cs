@@ -0,0 +1,10 @@
+﻿namespace SurrealDb.Net.Exceptions.Embedded;
+
+/// <summary>
+/// Embedded engine error: the parameters of a method are invalid.
+/// </summary>
+public sealed class SurrealDbEmbeddedInvalidParamsException : SurrealDbEmbeddedException
+{
+    public SurrealDbEmbeddedInvalidParamsException(long? code, string message)
+        : base(SurrealDbEmbeddedErrorKind.InvalidParams, code, message) { }
+}
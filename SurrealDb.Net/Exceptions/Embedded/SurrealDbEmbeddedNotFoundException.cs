@@ -0,0 +1,10 @@
+﻿namespace SurrealDb.Net.Exceptions.Embedded;
+
+/// <summary>
+/// Embedded engine error: a resource (session, transaction, record, etc.) was not found.
+/// </summary>
+public sealed class SurrealDbEmbeddedNotFoundException : SurrealDbEmbeddedException
+{
+    public SurrealDbEmbeddedNotFoundException(long? code, string message)
+        : base(SurrealDbEmbeddedErrorKind.NotFound, code, message) { }
+}
@@ -0,0 +1,10 @@
+﻿namespace SurrealDb.Net.Exceptions.Embedded;
+
+/// <summary>
+/// Embedded engine error: the operation is not allowed, e.g. invalid credentials or missing permissions.
+/// </summary>
+public sealed class SurrealDbEmbeddedPermissionException : SurrealDbEmbeddedException
+{
+    public SurrealDbEmbeddedPermissionException(long? code, string message)
+        : base(SurrealDbEmbeddedErrorKind.Permission, code, message) { }
+}
@@ -0,0 +1,10 @@
+﻿namespace SurrealDb.Net.Exceptions.Embedded;
+
+/// <summary>
+/// Embedded engine error: the transaction conflicts with another one and can be retried.
+/// </summary>
+public sealed class SurrealDbEmbeddedTransactionConflictException : SurrealDbEmbeddedException
+{
+    public SurrealDbEmbeddedTransactionConflictException(long? code, string message)
+        : base(SurrealDbEmbeddedErrorKind.TransactionConflict, code, message) { }
+}
@@ -9,6 +9,7 @@ public interface ISurrealDbLoggerFactory
     ILogger? Method { get; }
     ILogger? Query { get; }
     ILogger? Serialization { get; }
+    ILogger? Embedded { get; }
 }
 
 internal sealed class SurrealDbLoggerFactory : ISurrealDbLoggerFactory
@@ -17,6 +18,7 @@ internal sealed class SurrealDbLoggerFactory : ISurrealDbLoggerFactory
     public ILogger? Method { get; }
     public ILogger? Query { get; }
     public ILogger? Serialization { get; }
+    public ILogger? Embedded { get; }
 
     public SurrealDbLoggerFactory(ILoggerFactory loggerFactory)
     {
@@ -24,5 +26,6 @@ internal sealed class SurrealDbLoggerFactory : ISurrealDbLoggerFactory
         Method = loggerFactory.CreateLogger(DbLoggerCategory.Method.Name);
         Query = loggerFactory.CreateLogger(DbLoggerCategory.Query.Name);
         Serialization = loggerFactory.CreateLogger(DbLoggerCategory.Serialization.Name);
+        Embedded = loggerFactory.CreateLogger(DbLoggerCategory.Embedded.Name);
     }
 }
@@ -41,4 +41,10 @@ internal static class DbLoggerCategory
     /// e.g. hexa CBOR format exchanged between the client and a SurrealDB instance.
     /// </summary>
     public sealed class Serialization : LoggerCategory<Serialization>;
+
+    /// <summary>
+    /// Logger category for messages emitted by the native library of an embedded engine,
+    /// including the ones of the SurrealDB engine it runs.
+    /// </summary>
+    public sealed class Embedded : LoggerCategory<Embedded>;
 }
@@ -0,0 +1,139 @@
+﻿using System.Collections.Concurrent;
+using Dahomey.Cbor;
+using SurrealDb.Net.Exceptions.LiveQuery;
+using SurrealDb.Net.Internals.Ws;
+using SurrealDb.Net.Models.LiveQuery;
+
+namespace SurrealDb.Net.Internals.Models.LiveQuery;
+
+/// <summary>
+/// Tracks the live queries of an engine and dispatches their notifications to the subscribed channels.
+/// Used by engines which receive notifications outside of this assembly (e.g. embedded engines).
+/// </summary>
+public sealed class SurrealDbLiveQueryDispatcher
+{
+    private readonly ConcurrentDictionary<
+        Guid,
+        SurrealDbLiveQueryChannelSubscriptions
+    > _liveQueryChannelSubscriptionsPerQuery = new();
+    private readonly string _engineId;
+
+    public SurrealDbLiveQueryDispatcher(string engineId)
+    {
+        _engineId = engineId;
+    }
+
+    /// <summary>
+    /// Starts tracking a live query, returning the object used to consume its notifications.
+    /// </summary>
+    public SurrealDbLiveQuery<T> Listen<T>(
+        ISurrealDbEngine engine,
+        Guid queryUuid,
+        Guid? sessionId,
+        Guid? transactionId
+    )
+    {
+        _liveQueryChannelSubscriptionsPerQuery.TryAdd(queryUuid, new(_engineId));
+        return new SurrealDbLiveQuery<T>(queryUuid, engine, sessionId, transactionId);
+    }
+
+    /// <summary>
+    /// Creates a new channel receiving the notifications of a tracked live query.
+    /// </summary>
+    /// <exception cref="LiveQuerySurrealDbException">The live query is not tracked.</exception>
+    public SurrealDbLiveQueryChannel Subscribe(Guid queryUuid)
+    {
+        if (
+            !_liveQueryChannelSubscriptionsPerQuery.TryGetValue(
+                queryUuid,
+                out var liveQueryChannelSubscriptions
+            )
+        )
+        {
+            throw new LiveQuerySurrealDbException("Live Query not found");
+        }
+
+        var liveQueryChannel = new SurrealDbLiveQueryChannel();
+        liveQueryChannelSubscriptions.Add(liveQueryChannel);
+
+        return liveQueryChannel;
+    }
+
+    /// <summary>
+    /// Writes a notification, encoded in CBOR, to every channel of its live query.
+    /// Notifications of untracked live queries are ignored.
+    /// </summary>
+    /// <param name="notification">
+    /// The encoded notification, which must not be reused as the notification content is read lazily.
+    /// </param>
+    /// <param name="cborOptions">The options used to decode the notification.</param>
+    public async Task NotifyAsync(ReadOnlyMemory<byte> notification, CborOptions cborOptions)
+    {
+        var content = CborSerializer.Deserialize<SurrealDbWsLiveResponseContent>(
+            notification.Span,
+            cborOptions
+        );
+
+        if (
+            _liveQueryChannelSubscriptionsPerQuery.TryGetValue(
+                content.Id,
+                out var liveQueryChannelSubscriptions
+            )
+        )
+        {
+            var response = new SurrealDbWsLiveResponse(content);
+            var tasks = liveQueryChannelSubscriptions.Select(liveQueryChannel =>
+            {
+                return liveQueryChannel.WriteAsync(response);
+            });
+
+            await Task.WhenAll(tasks).ConfigureAwait(false);
+        }
+    }
+
+    /// <summary>
+    /// Stops tracking a live query, closing its channels.
+    /// </summary>
+    public async Task CloseAsync(Guid queryUuid, SurrealDbLiveQueryClosureReason reason)
+    {
+        if (
+            _liveQueryChannelSubscriptionsPerQuery.TryRemove(
+                queryUuid,
+                out var liveQueryChannelSubscriptions
+            )
+        )
+        {
+            var tasks = liveQueryChannelSubscriptions.Select(liveQueryChannel =>
+            {
+                return CloseLiveQueryAsync(liveQueryChannel, reason);
+            });
+
+            await Task.WhenAll(tasks).ConfigureAwait(false);
+        }
+    }
+
+    /// <summary>
+    /// Stops tracking every live query, closing their channels.
+    /// </summary>
+    public async Task CloseAllAsync(SurrealDbLiveQueryClosureReason reason)
+    {
+        var tasks = _liveQueryChannelSubscriptionsPerQuery.Keys.Select(queryUuid =>
+        {
+            return CloseAsync(queryUuid, reason);
+        });
+
+        await Task.WhenAll(tasks).ConfigureAwait(false);
+    }
+
+    private static async Task CloseLiveQueryAsync(
+        SurrealDbLiveQueryChannel liveQueryChannel,
+        SurrealDbLiveQueryClosureReason reason
+    )
+    {
+        await liveQueryChannel
+            .WriteAsync(new SurrealDbWsClosedLiveResponse { Reason = reason })
+            .ConfigureAwait(false);
+
+        liveQueryChannel.Complete();
+    }
+}
@@ -101,8 +101,19 @@ internal class SurrealDbHttpEngine : ISurrealDbEngine
         SessionInfos.Get(sessionId)?.SetBearerAuth(tokens.Access);
     }
 
-    public async Task<Guid> Begin(Guid? sessionId, CancellationToken cancellationToken)
+    public async Task<Guid> Begin(
+        Guid? sessionId,
+        TransactionOptions? options,
+        CancellationToken cancellationToken
+    )
     {
+        if (options is not null)
+        {
+            throw new NotSupportedException(
+                "Transaction options are only supported by embedded engines."
+            );
+        }
+
         await RequireMajorVersion(3, cancellationToken).ConfigureAwait(false);
 
         var request = new SurrealDbHttpRequest { Method = "begin", SessionId = sessionId };
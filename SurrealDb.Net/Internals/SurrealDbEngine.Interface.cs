@@ -24,7 +24,11 @@ public interface ISurrealDbEngineWithSessions
 
 public interface ISurrealDbEngineWithTransactions
 {
-    Task<Guid> Begin(Guid? sessionId, CancellationToken cancellationToken);
+    Task<Guid> Begin(
+        Guid? sessionId,
+        TransactionOptions? options,
+        CancellationToken cancellationToken
+    );
     Task Commit(Guid? sessionId, Guid transactionId, CancellationToken cancellationToken);
     Task Cancel(Guid? sessionId, Guid transactionId, CancellationToken cancellationToken);
 }
@@ -406,9 +410,28 @@ public interface ISurrealDbProviderEngine : ISurrealDbEngine
     /// Export the database as a SurrealQL script.
     /// </summary>
     /// <param name="options">Export configuration options.</param>
+    /// <param name="sessionId">The session to export from (namespace and database), or the default session.</param>
     /// <param name="cancellationToken">The cancellationToken enables graceful cancellation of asynchronous operations</param>
     /// <returns>SurrealQL script as <see cref="String"/></returns>
-    Task<string> Export(ExportOptions? options, CancellationToken cancellationToken);
+    Task<string> Export(
+        ExportOptions? options,
+        Guid? sessionId,
+        CancellationToken cancellationToken
+    );
+
+    /// <summary>
+    /// Export the database as a SurrealQL script, written to a stream as it is produced.
+    /// </summary>
+    /// <param name="output">The stream the SurrealQL script is written to.</param>
+    /// <param name="options">Export configuration options.</param>
+    /// <param name="sessionId">The session to export from (namespace and database), or the default session.</param>
+    /// <param name="cancellationToken">The cancellationToken enables graceful cancellation of asynchronous operations</param>
+    Task Export(
+        System.IO.Stream output,
+        ExportOptions? options,
+        Guid? sessionId,
+        CancellationToken cancellationToken
+    );
 
     /// <summary>
     /// This method imports data into a SurrealDB database.
@@ -417,8 +440,43 @@ public interface ISurrealDbProviderEngine : ISurrealDbEngine
     /// This method is only supported by SurrealDB v2.0.0 or higher.
     /// </remarks>
     /// <param name="input"></param>
+    /// <param name="sessionId">The session to import into (namespace and database), or the default session.</param>
+    /// <param name="transactionId">The transaction to import within, if any.</param>
+    /// <param name="cancellationToken">The cancellationToken enables graceful cancellation of asynchronous operations</param>
+    Task Import(
+        string input,
+        Guid? sessionId,
+        Guid? transactionId,
+        CancellationToken cancellationToken
+    );
+
+    /// <summary>
+    /// This method imports data into a SurrealDB database, reading the SurrealQL script from a stream.
+    /// </summary>
+    /// <param name="input">The stream the SurrealQL script is read from.</param>
+    /// <param name="sessionId">The session to import into (namespace and database), or the default session.</param>
+    /// <param name="transactionId">The transaction to import within, if any.</param>
     /// <param name="cancellationToken">The cancellationToken enables graceful cancellation of asynchronous operations</param>
-    Task Import(string input, CancellationToken cancellationToken);
+    Task Import(
+        System.IO.Stream input,
+        Guid? sessionId,
+        Guid? transactionId,
+        CancellationToken cancellationToken
+    );
+
+    /// <summary>
+    /// This method imports data into a SurrealDB database, reading the SurrealQL script from a file.
+    /// </summary>
+    /// <param name="path">The path of the file containing the SurrealQL script.</param>
+    /// <param name="sessionId">The session to import into (namespace and database), or the default session.</param>
+    /// <param name="transactionId">The transaction to import within, if any.</param>
+    /// <param name="cancellationToken">The cancellationToken enables graceful cancellation of asynchronous operations</param>
+    Task ImportFile(
+        string path,
+        Guid? sessionId,
+        Guid? transactionId,
+        CancellationToken cancellationToken
+    );
 }
 
 public interface ISurrealDbInMemoryEngine : ISurrealDbProviderEngine { }
@@ -377,8 +377,19 @@ internal sealed class SurrealDbWsEngine : ISurrealDbEngine
             .ConfigureAwait(false);
     }
 
-    public async Task<Guid> Begin(Guid? sessionId, CancellationToken cancellationToken)
+    public async Task<Guid> Begin(
+        Guid? sessionId,
+        TransactionOptions? options,
+        CancellationToken cancellationToken
+    )
     {
+        if (options is not null)
+        {
+            throw new NotSupportedException(
+                "Transaction options are only supported by embedded engines."
+            );
+        }
+
         await RequireMajorVersion(3, cancellationToken).ConfigureAwait(false);
 
         var response = await SendRequestAsync(
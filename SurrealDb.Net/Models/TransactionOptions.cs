@@ -0,0 +1,26 @@
+﻿using Dahomey.Cbor.Attributes;
+
+namespace SurrealDb.Net.Models;
+
+/// <summary>
+/// Settings used to configure a transaction created by <see cref="ISurrealDbSession.BeginTransaction(TransactionOptions, CancellationToken)"/>.
+/// </summary>
+/// <remarks>
+/// Only supported by embedded engines.
+/// </remarks>
+public class TransactionOptions
+{
+    /// <summary>
+    /// Creates a read-only transaction, reading from a snapshot, which never conflicts with other transactions.
+    /// </summary>
+    [CborProperty("read_only")]
+    [CborIgnoreIfDefault]
+    public bool? ReadOnly { get; set; }
+
+    /// <summary>
+    /// Locks the records as soon as they are read or written, instead of detecting conflicts on commit.
+    /// </summary>
+    [CborProperty("pessimistic")]
+    [CborIgnoreIfDefault]
+    public bool? Pessimistic { get; set; }
+}
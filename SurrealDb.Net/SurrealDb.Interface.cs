@@ -211,6 +211,25 @@ public interface ISurrealDbSharedMethods : ISurrealDbInterface, ISurrealDbFeatur
         CancellationToken cancellationToken = default
     );
 
+    /// <summary>
+    /// Export the database as a SurrealQL script, written to a stream as it is produced.<br />
+    /// <see href="https://surrealdb.com/docs/sdk/dotnet/methods/export">
+    /// `Export` on surrealdb.com/docs
+    /// </see>
+    /// </summary>
+    /// <param name="output">The stream the SurrealQL script is written to, e.g. a <see cref="FileStream"/>.</param>
+    /// <param name="options">Export configuration options.</param>
+    /// <param name="cancellationToken">The cancellationToken enables graceful cancellation of asynchronous operations</param>
+    /// <exception cref="OperationCanceledException"></exception>
+    /// <exception cref="HttpRequestException"></exception>
+    /// <exception cref="NotImplementedException"></exception>
+    /// <exception cref="SurrealDbException"></exception>
+    Task Export(
+        Stream output,
+        ExportOptions? options = default,
+        CancellationToken cancellationToken = default
+    );
+
     /// <summary>
     /// Checks the status of the database server and storage engine.<br />
     /// <see href="https://surrealdb.com/docs/sdk/dotnet/methods/health">
@@ -238,6 +257,32 @@ public interface ISurrealDbSharedMethods : ISurrealDbInterface, ISurrealDbFeatur
     /// <param name="cancellationToken">The cancellationToken enables graceful cancellation of asynchronous operations</param>
     Task Import(string input, CancellationToken cancellationToken = default);
 
+    /// <summary>
+    /// This method imports data into a SurrealDB database, reading the SurrealQL script from a stream.<br />
+    /// <see href="https://surrealdb.com/docs/sdk/dotnet/methods/import">
+    /// `Import` on surrealdb.com/docs
+    /// </see>
+    /// </summary>
+    /// <remarks>
+    /// This method is only supported by SurrealDB v2.0.0 or higher.
+    /// </remarks>
+    /// <param name="input">The stream the SurrealQL script is read from.</param>
+    /// <param name="cancellationToken">The cancellationToken enables graceful cancellation of asynchronous operations</param>
+    Task Import(Stream input, CancellationToken cancellationToken = default);
+
+    /// <summary>
+    /// This method imports data into a SurrealDB database, reading the SurrealQL script from a file.<br />
+    /// <see href="https://surrealdb.com/docs/sdk/dotnet/methods/import">
+    /// `Import` on surrealdb.com/docs
+    /// </see>
+    /// </summary>
+    /// <remarks>
+    /// This method is only supported by SurrealDB v2.0.0 or higher.
+    /// </remarks>
+    /// <param name="path">The path of the file containing the SurrealQL script.</param>
+    /// <param name="cancellationToken">The cancellationToken enables graceful cancellation of asynchronous operations</param>
+    Task ImportFile(string path, CancellationToken cancellationToken = default);
+
     /// <summary>
     /// Retrieves information about the authenticated scope user.<br />
     /// <see href="https://surrealdb.com/docs/sdk/dotnet/methods/info">
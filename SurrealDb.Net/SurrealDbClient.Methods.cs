@@ -98,9 +98,59 @@ public abstract partial class BaseSurrealDbClient
     {
         if (Engine is ISurrealDbProviderEngine providerEngine)
         {
-            return await providerEngine.Export(options, cancellationToken).ConfigureAwait(false);
+            return await providerEngine
+                .Export(options, SessionId, cancellationToken)
+                .ConfigureAwait(false);
         }
 
+        return await ExportOverHttpAsync(
+                options,
+#if NET6_0_OR_GREATER
+                content => content.ReadAsStringAsync(cancellationToken),
+#else
+                content => content.ReadAsStringAsync(),
+#endif
+                cancellationToken
+            )
+            .ConfigureAwait(false);
+    }
+
+    public async Task Export(
+        Stream output,
+        ExportOptions? options = default,
+        CancellationToken cancellationToken = default
+    )
+    {
+        if (Engine is ISurrealDbProviderEngine providerEngine)
+        {
+            await providerEngine
+                .Export(output, options, SessionId, cancellationToken)
+                .ConfigureAwait(false);
+            return;
+        }
+
+        await ExportOverHttpAsync(
+                options,
+                async content =>
+                {
+#if NET6_0_OR_GREATER
+                    await content.CopyToAsync(output, cancellationToken).ConfigureAwait(false);
+#else
+                    await content.CopyToAsync(output).ConfigureAwait(false);
+#endif
+                    return true;
+                },
+                cancellationToken
+            )
+            .ConfigureAwait(false);
+    }
+
+    private async Task<T> ExportOverHttpAsync<T>(
+        ExportOptions? options,
+        Func<HttpContent, Task<T>> read,
+        CancellationToken cancellationToken
+    )
+    {
         const string path = "/export";
 
         var exportUri = Uri.Scheme switch
@@ -124,16 +174,21 @@ public abstract partial class BaseSurrealDbClient
             wrapper.Version is not null
             && wrapper.Version.Satisfies(SemVersionRange.AtLeast(new(2, 1), true));
 
-        using var response = shouldUsePostRequest
-            ? await wrapper.HttpClient.PostAsync(exportUri, httpContent, cancellationToken)
-            : await wrapper.HttpClient.GetAsync(exportUri, cancellationToken);
+        using var request = shouldUsePostRequest
+            ? new HttpRequestMessage(HttpMethod.Post, exportUri) { Content = httpContent }
+            : new HttpRequestMessage(HttpMethod.Get, exportUri);
+
+        // 💡 The response is read as it is received, rather than buffered
+        using var response = await wrapper
+            .HttpClient.SendAsync(
+                request,
+                HttpCompletionOption.ResponseHeadersRead,
+                cancellationToken
+            )
+            .ConfigureAwait(false);
         response.EnsureSuccessStatusCode();
 
-#if NET6_0_OR_GREATER
-        return await response.Content.ReadAsStringAsync(cancellationToken).ConfigureAwait(false);
-#else
-        return await response.Content.ReadAsStringAsync().ConfigureAwait(false);
-#endif
+        return await read(response.Content).ConfigureAwait(false);
     }
 
     public Task<bool> Health(CancellationToken cancellationToken = default)
@@ -145,10 +200,57 @@ public abstract partial class BaseSurrealDbClient
     {
         if (Engine is ISurrealDbProviderEngine providerEngine)
         {
-            await providerEngine.Import(input, cancellationToken).ConfigureAwait(false);
+            await providerEngine
+                .Import(input, SessionId, TransactionId, cancellationToken)
+                .ConfigureAwait(false);
+            return;
+        }
+
+        using var httpContent = new StringContent(input, Encoding.UTF8, "text/plain");
+
+        await ImportOverHttpAsync(httpContent, cancellationToken).ConfigureAwait(false);
+    }
+
+    public async Task Import(Stream input, CancellationToken cancellationToken = default)
+    {
+        if (Engine is ISurrealDbProviderEngine providerEngine)
+        {
+            await providerEngine
+                .Import(input, SessionId, TransactionId, cancellationToken)
+                .ConfigureAwait(false);
+            return;
+        }
+
+        using var httpContent = new StreamContent(input);
+        httpContent.Headers.ContentType = new("text/plain") { CharSet = Encoding.UTF8.WebName };
+
+        await ImportOverHttpAsync(httpContent, cancellationToken).ConfigureAwait(false);
+    }
+
+    public async Task ImportFile(string path, CancellationToken cancellationToken = default)
+    {
+        if (Engine is ISurrealDbProviderEngine providerEngine)
+        {
+            await providerEngine
+                .ImportFile(path, SessionId, TransactionId, cancellationToken)
+                .ConfigureAwait(false);
             return;
         }
 
+#if NET6_0_OR_GREATER
+        await using var input = File.OpenRead(path);
+#else
+        using var input = File.OpenRead(path);
+#endif
+
+        await Import(input, cancellationToken).ConfigureAwait(false);
+    }
+
+    private async Task ImportOverHttpAsync(
+        HttpContent httpContent,
+        CancellationToken cancellationToken
+    )
+    {
         const string path = "/import";
 
         var importUri = Uri.Scheme switch
@@ -162,8 +264,6 @@ public abstract partial class BaseSurrealDbClient
         using var wrapper = await CreateCommonHttpWrapperAsync(cancellationToken)
             .ConfigureAwait(false);
 
-        using var httpContent = new StringContent(input, Encoding.UTF8, "text/plain");
-
         using var response = await wrapper
             .HttpClient.PostAsync(importUri, httpContent, cancellationToken)
             .ConfigureAwait(false);
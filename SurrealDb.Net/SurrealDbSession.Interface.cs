@@ -1,4 +1,5 @@
-﻿using SurrealDb.Net.Models.Sessions;
+﻿using SurrealDb.Net.Models;
+using SurrealDb.Net.Models.Sessions;
 
 namespace SurrealDb.Net;
 
@@ -53,6 +54,21 @@ public interface ISurrealDbSession
     /// <returns>A new <see cref="SurrealDbTransaction"/>.</returns>
     Task<SurrealDbTransaction> BeginTransaction(CancellationToken cancellationToken = default);
 
+    /// <summary>
+    /// Create a new transaction scoped to the current session, with specific options (e.g. read-only, pessimistic locking).
+    /// The transaction is cancelled when the session is closed.
+    /// </summary>
+    /// <remarks>
+    /// Transaction options are only supported by embedded engines.
+    /// </remarks>
+    /// <param name="options">The options of the transaction.</param>
+    /// <param name="cancellationToken">The cancellationToken enables graceful cancellation of asynchronous operations</param>
+    /// <returns>A new <see cref="SurrealDbTransaction"/>.</returns>
+    Task<SurrealDbTransaction> BeginTransaction(
+        TransactionOptions options,
+        CancellationToken cancellationToken = default
+    );
+
     /// <summary>
     /// Create a new session by cloning the current session.
     /// The new session inherits all properties from the parent session including namespace, database, variables, and authentication state.
@@ -1,5 +1,6 @@
 ﻿using SurrealDb.Net.Exceptions;
 using SurrealDb.Net.Internals;
+using SurrealDb.Net.Models;
 
 namespace SurrealDb.Net;
 
@@ -16,9 +17,25 @@ public class SurrealDbSession : BaseSurrealDbClient, ISurrealDbSession
         TransactionId = transactionId;
     }
 
-    public async Task<SurrealDbTransaction> BeginTransaction(
+    public Task<SurrealDbTransaction> BeginTransaction(
         CancellationToken cancellationToken = default
     )
+    {
+        return CreateTransaction(null, cancellationToken);
+    }
+
+    public Task<SurrealDbTransaction> BeginTransaction(
+        TransactionOptions options,
+        CancellationToken cancellationToken = default
+    )
+    {
+        return CreateTransaction(options, cancellationToken);
+    }
+
+    private async Task<SurrealDbTransaction> CreateTransaction(
+        TransactionOptions? options,
+        CancellationToken cancellationToken
+    )
     {
         if (!SessionId.HasValue)
         {
@@ -29,7 +46,9 @@ public class SurrealDbSession : BaseSurrealDbClient, ISurrealDbSession
             throw new NotSupportedException("Transactions are not supported.");
         }
 
-        var transactionId = await Engine.Begin(SessionId, cancellationToken).ConfigureAwait(false);
+        var transactionId = await Engine
+            .Begin(SessionId, options, cancellationToken)
+            .ConfigureAwait(false);
         return new SurrealDbTransaction(this, SessionId!.Value, transactionId);
     }
 
@@ -0,0 +1,191 @@
+use std::fs::File;
+use std::io::{BufWriter, Write};
+use std::path::{Path, PathBuf};
+use std::process::ExitCode;
+
+use anyhow::{Context, anyhow};
+use shared::app::SurrealEmbeddedEngine;
+use surrealdb::rpc::Method;
+use surrealdb::rpc::format::cbor::{decode, encode};
+use surrealdb_types::{Array, Object, ToSql, Value};
+
+use crate::read_value_file;
+
+/// Executes a method with the default session, returning its decoded result.
+pub async fn call(
+    engine: &SurrealEmbeddedEngine,
+    method: Method,
+    params: Vec<Value>,
+) -> anyhow::Result<Value> {
+    let params = encode(Value::Array(Array::from(params)))?;
+    let output = engine.execute(method, None, None, params).await?;
+    decode(&output)
+}
+
+/// Executes SurrealQL statements, returning the result (or error message) of each statement.
+pub async fn query(
+    engine: &SurrealEmbeddedEngine,
+    sql: &str,
+    vars: Object,
+) -> anyhow::Result<Vec<Result<Value, String>>> {
+    let params = vec![Value::String(sql.to_string()), Value::Object(vars)];
+    let Value::Array(results) = call(engine, Method::Query, params).await? else {
+        return Err(anyhow!("Expected the results of the query"));
+    };
+
+    Ok(results
+        .into_iter()
+        .map(|result| {
+            let Value::Object(mut result) = result else {
+                return Err("Unexpected query result".to_string());
+            };
+            let output = result.remove("result").unwrap_or_default();
+            match result.get("status") {
+                Some(Value::String(status)) if status == "OK" => Ok(output),
+                _ => Err(match output {
+                    Value::String(message) => message,
+                    output => output.to_sql(),
+                }),
+            }
+        })
+        .collect())
+}
+
+/// Executes a single SurrealQL statement, failing if the statement failed.
+async fn query_one(
+    engine: &SurrealEmbeddedEngine,
+    sql: &str,
+    vars: Object,
+) -> anyhow::Result<Value> {
+    query(engine, sql, vars)
+        .await?
+        .pop()
+        .unwrap_or(Ok(Value::None))
+        .map_err(|message| anyhow!(message))
+}
+
+/// Names of the entities of a given kind, listed by an `INFO FOR` statement.
+fn defined(info: &Value, kind: &str) -> Vec<String> {
+    match info {
+        Value::Object(info) => match info.get(kind) {
+            Some(Value::Object(entities)) => entities.keys().cloned().collect(),
+            _ => vec![],
+        },
+        _ => vec![],
+    }
+}
+
+pub async fn export(
+    engine: &SurrealEmbeddedEngine,
+    config: Option<&Path>,
+    output: Option<&Path>,
+) -> anyhow::Result<()> {
+    let config = match config {
+        Some(path) => read_value_file(path)?,
+        None => encode(Value::Object(Object::new()))?,
+    };
+
+    let mut writer: Box<dyn Write> = match output {
+        Some(path) => {
+            Box::new(BufWriter::new(File::create(path).with_context(|| {
+                format!("Cannot create file {}", path.display())
+            })?))
+        }
+        None => Box::new(std::io::stdout().lock()),
+    };
+
+    // Chunks cannot be rejected, so the first write error is reported once the export is complete
+    let mut write_result = Ok(());
+    engine
+        .export_stream(None, config, |chunk| {
+            if write_result.is_ok() {
+                write_result = writer.write_all(&chunk);
+            }
+        })
+        .await?;
+    write_result?;
+    writer.flush()?;
+
+    Ok(())
+}
+
+pub async fn import(engine: &SurrealEmbeddedEngine, input: PathBuf) -> anyhow::Result<()> {
+    engine.import_file(None, None, input).await
+}
+
+pub async fn info(
+    engine: &SurrealEmbeddedEngine,
+    endpoint: &str,
+    ns: Option<&str>,
+    db: Option<&str>,
+) -> anyhow::Result<()> {
+    let version = call(engine, Method::Version, vec![]).await?;
+    println!("Endpoint: {endpoint}");
+    println!("Version: {}", version.to_sql());
+
+    let root = query_one(engine, "INFO FOR ROOT", Object::new()).await?;
+    println!("\n-- Root\n{}", root.to_sql_pretty());
+
+    if let Some(ns) = ns {
+        let info = query_one(engine, "INFO FOR NS", Object::new()).await?;
+        println!("\n-- Namespace {ns}\n{}", info.to_sql_pretty());
+    }
+    if let Some(db) = db {
+        let info = query_one(engine, "INFO FOR DB", Object::new()).await?;
+        println!("\n-- Database {db}\n{}", info.to_sql_pretty());
+    }
+
+    Ok(())
+}
+
+/// Counts the records of every table, then exports every database (discarding the output),
+/// so that every definition and record is read at least once.
+pub async fn check(engine: &SurrealEmbeddedEngine) -> anyhow::Result<ExitCode> {
+    let mut errors = 0;
+
+    let root = query_one(engine, "INFO FOR ROOT", Object::new()).await?;
+    for ns in defined(&root, "namespaces") {
+        call(
+            engine,
+            Method::Use,
+            vec![Value::String(ns.clone()), Value::None],
+        )
+        .await?;
+        let info = query_one(engine, "INFO FOR NS", Object::new()).await?;
+
+        for db in defined(&info, "databases") {
+            let params = vec![Value::String(ns.clone()), Value::String(db.clone())];
+            call(engine, Method::Use, params).await?;
+            let info = query_one(engine, "INFO FOR DB", Object::new()).await?;
+
+            for table in defined(&info, "tables") {
+                let mut vars = Object::new();
+                vars.insert("table".to_string(), Value::String(table.clone()));
+                let sql = "RETURN count(SELECT VALUE id FROM type::table($table))";
+                match query_one(engine, sql, vars).await {
+                    Ok(count) => println!("{ns}/{db}/{table}: {} records", count.to_sql()),
+                    Err(error) => {
+                        errors += 1;
+                        println!("{ns}/{db}/{table}: {error}");
+                    }
+                }
+            }
+
+            let config = encode(Value::Object(Object::new()))?;
+            match engine.export_stream(None, config, |_| ()).await {
+                Ok(()) => println!("{ns}/{db}: exported"),
+                Err(error) => {
+                    errors += 1;
+                    println!("{ns}/{db}: {error:#}");
+                }
+            }
+        }
+    }
+
+    if errors > 0 {
+        println!("{errors} errors found");
+        return Ok(ExitCode::FAILURE);
+    }
+    println!("No errors found");
+    Ok(ExitCode::SUCCESS)
+}
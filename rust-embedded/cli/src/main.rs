@@ -0,0 +1,148 @@
+#![recursion_limit = "256"]
+
+use std::path::{Path, PathBuf};
+use std::process::ExitCode;
+
+use anyhow::Context;
+use clap::{Parser, Subcommand};
+use shared::app::SurrealEmbeddedEngine;
+use shared::models::runtime_options::RuntimeOptions;
+use shared::runtime::{get_global_runtime, init_global_runtime, shutdown_global_runtime};
+use surrealdb::rpc::Method;
+use surrealdb::rpc::format::{cbor, json};
+use surrealdb_types::{Object, Value};
+
+mod commands;
+mod repl;
+
+/// Opens a database produced by the embedded SurrealDB engine,
+/// using the very same version of surrealdb-core as the .NET embedded providers.
+#[derive(Parser)]
+#[command(name = "surreal-embedded", version)]
+struct Cli {
+    /// Endpoint of the database, e.g. "rocksdb://path/to/db", "surrealkv://path/to/db" or "mem://"
+    endpoint: String,
+    /// Connection options used by the embedded app, as a CBOR or JSON file
+    #[arg(long, global = true)]
+    options: Option<PathBuf>,
+    /// Namespace to use
+    #[arg(long, global = true)]
+    ns: Option<String>,
+    /// Database to use (requires a namespace)
+    #[arg(long, global = true, requires = "ns")]
+    db: Option<String>,
+    /// Root username, when authentication is enabled
+    #[arg(long, global = true, requires = "pass")]
+    user: Option<String>,
+    /// Root password, when authentication is enabled
+    #[arg(long, global = true, requires = "user")]
+    pass: Option<String>,
+    #[command(subcommand)]
+    command: Option<Command>,
+}
+
+#[derive(Subcommand)]
+enum Command {
+    /// Starts an interactive SurrealQL shell (default)
+    Sql,
+    /// Exports the selected database as a SurrealQL script
+    Export {
+        /// Export configuration (`tables`, `records`...), as a CBOR or JSON file
+        #[arg(long)]
+        config: Option<PathBuf>,
+        /// File to write the script to, standard output if omitted
+        output: Option<PathBuf>,
+    },
+    /// Imports a SurrealQL script into the selected database
+    Import {
+        /// File to read the script from
+        input: PathBuf,
+    },
+    /// Displays the version of the engine and the definitions of the selected namespace/database
+    Info,
+    /// Reads every record of every database, reporting the ones that cannot be read
+    Check,
+}
+
+fn main() -> ExitCode {
+    let cli = Cli::parse();
+
+    if let Err(error) = init_global_runtime(RuntimeOptions::default()) {
+        eprintln!("Error: {error}");
+        return ExitCode::FAILURE;
+    }
+    let result = get_global_runtime()
+        .map_err(anyhow::Error::from)
+        .and_then(|runtime| runtime.block_on(run(cli)));
+    shutdown_global_runtime();
+
+    match result {
+        Ok(code) => code,
+        Err(error) => {
+            eprintln!("Error: {error:#}");
+            ExitCode::FAILURE
+        }
+    }
+}
+
+async fn run(cli: Cli) -> anyhow::Result<ExitCode> {
+    let options = match &cli.options {
+        Some(path) => read_value_file(path)?,
+        None => cbor::encode(Value::Object(Object::new()))?,
+    };
+    let engine = SurrealEmbeddedEngine::connect(0, cli.endpoint.clone(), options)
+        .await
+        .context("Cannot connect to db")?;
+
+    let result = execute(&engine, cli).await;
+
+    // The storage is only released (and its lock file removed) once the engine is closed
+    engine.close().await?;
+    result
+}
+
+async fn execute(engine: &SurrealEmbeddedEngine, cli: Cli) -> anyhow::Result<ExitCode> {
+    // Signs in and selects the namespace/database of the default session
+    if let (Some(user), Some(pass)) = (&cli.user, &cli.pass) {
+        let mut credentials = Object::new();
+        credentials.insert("user".to_string(), Value::String(user.clone()));
+        credentials.insert("pass".to_string(), Value::String(pass.clone()));
+        commands::call(engine, Method::Signin, vec![Value::Object(credentials)])
+            .await
+            .context("Cannot sign in")?;
+    }
+    if let Some(ns) = &cli.ns {
+        let db = cli.db.clone().map(Value::String).unwrap_or(Value::None);
+        commands::call(engine, Method::Use, vec![Value::String(ns.clone()), db]).await?;
+    }
+
+    match cli.command.unwrap_or(Command::Sql) {
+        Command::Sql => repl::run(engine).await?,
+        Command::Export { config, output } => {
+            commands::export(engine, config.as_deref(), output.as_deref()).await?
+        }
+        Command::Import { input } => commands::import(engine, input).await?,
+        Command::Info => {
+            commands::info(engine, &cli.endpoint, cli.ns.as_deref(), cli.db.as_deref()).await?
+        }
+        Command::Check => return commands::check(engine).await,
+    }
+
+    Ok(ExitCode::SUCCESS)
+}
+
+/// Reads a CBOR or JSON file, returning its content as CBOR.
+/// The formats cannot be mistaken, as a CBOR object never starts with a curly bracket.
+fn read_value_file(path: &Path) -> anyhow::Result<Vec<u8>> {
+    let bytes =
+        std::fs::read(path).with_context(|| format!("Cannot read file {}", path.display()))?;
+
+    match bytes.iter().find(|byte| !byte.is_ascii_whitespace()) {
+        Some(b'{') => {
+            let value = json::decode(&bytes)
+                .with_context(|| format!("Invalid JSON file {}", path.display()))?;
+            cbor::encode(value)
+        }
+        _ => Ok(bytes),
+    }
+}
@@ -0,0 +1,63 @@
+use std::io::{BufRead, IsTerminal, Write};
+
+use shared::app::SurrealEmbeddedEngine;
+use surrealdb_types::{Object, ToSql};
+
+use crate::commands::query;
+
+/// Reads SurrealQL statements from the standard input, until "exit" or the end of the input.
+/// Statements are executed once terminated by a semicolon (or an empty line),
+/// so that they can span several lines.
+pub async fn run(engine: &SurrealEmbeddedEngine) -> anyhow::Result<()> {
+    let interactive = std::io::stdin().is_terminal();
+    if interactive {
+        println!("Type SurrealQL statements, terminated by a semicolon, or \"exit\" to quit.");
+    }
+
+    let mut lines = std::io::stdin().lock().lines();
+    let mut buffer = String::new();
+    loop {
+        if interactive {
+            print!("{}", if buffer.is_empty() { "> " } else { "... " });
+            std::io::stdout().flush()?;
+        }
+
+        let Some(line) = lines.next().transpose()? else {
+            break;
+        };
+        let line = line.trim();
+
+        if buffer.is_empty() && matches!(line.trim_end_matches(';'), "exit" | "quit") {
+            return Ok(());
+        }
+        if !line.is_empty() {
+            buffer.push_str(line);
+            buffer.push('\n');
+            if !line.ends_with(';') {
+                continue;
+            }
+        }
+        if !buffer.is_empty() {
+            execute(engine, &std::mem::take(&mut buffer)).await;
+        }
+    }
+
+    if !buffer.is_empty() {
+        execute(engine, &buffer).await;
+    }
+    Ok(())
+}
+
+async fn execute(engine: &SurrealEmbeddedEngine, sql: &str) {
+    match query(engine, sql, Object::new()).await {
+        Ok(results) => {
+            for (index, result) in results.into_iter().enumerate() {
+                match result {
+                    Ok(value) => println!("-- Query {}\n{}\n", index + 1, value.to_sql_pretty()),
+                    Err(error) => println!("-- Query {} failed\n{}\n", index + 1, error),
+                }
+            }
+        }
+        Err(error) => println!("Error: {error:#}\n"),
+    }
+}
@@ -0,0 +1,89 @@
+use std::io::Write;
+use std::process::{Command, Output, Stdio};
+
+fn surreal_embedded(endpoint: &str, args: &[&str], input: Option<&str>) -> Output {
+    let mut child = Command::new(env!("CARGO_BIN_EXE_surreal-embedded"))
+        .arg(endpoint)
+        .args(args)
+        .stdin(Stdio::piped())
+        .stdout(Stdio::piped())
+        .stderr(Stdio::piped())
+        .spawn()
+        .unwrap();
+
+    let mut stdin = child.stdin.take().unwrap();
+    if let Some(input) = input {
+        stdin.write_all(input.as_bytes()).unwrap();
+    }
+    drop(stdin);
+
+    child.wait_with_output().unwrap()
+}
+
+fn stdout(output: &Output) -> String {
+    assert!(
+        output.status.success(),
+        "{}",
+        String::from_utf8_lossy(&output.stderr)
+    );
+    String::from_utf8(output.stdout.clone()).unwrap()
+}
+
+#[cfg(feature = "memory")]
+#[test]
+fn repl_executes_statements_spanning_several_lines() {
+    let options = tempfile::NamedTempFile::new().unwrap();
+    std::fs::write(options.path(), r#"{ "strict": false }"#).unwrap();
+
+    let output = surreal_embedded(
+        "mem://",
+        &[
+            "--options",
+            options.path().to_str().unwrap(),
+            "--ns",
+            "test",
+            "--db",
+            "test",
+        ],
+        Some(
+            "CREATE person:one\n  SET name = 'Tobie';\nSELECT VALUE name FROM person;\nexit\nRETURN 1;\n",
+        ),
+    );
+    let output = stdout(&output);
+    assert!(output.contains("'Tobie'"), "{output}");
+    assert!(!output.contains("-- Query 1\n1"), "{output}");
+}
+
+#[cfg(feature = "surrealkv")]
+#[test]
+fn commands_inspect_a_surrealkv_database() {
+    let dir = tempfile::tempdir().unwrap();
+    let endpoint = format!("surrealkv://{}", dir.path().join("db").display());
+    let args = ["--ns", "test", "--db", "test"];
+
+    let script = dir.path().join("import.surql");
+    std::fs::write(
+        &script,
+        "OPTION IMPORT; DEFINE TABLE person; CREATE person:one; CREATE person:two;",
+    )
+    .unwrap();
+    let import = [&args[..], &["import", script.to_str().unwrap()]].concat();
+    stdout(&surreal_embedded(&endpoint, &import, None));
+
+    let info = stdout(&surreal_embedded(
+        &endpoint,
+        &[&args[..], &["info"]].concat(),
+        None,
+    ));
+    assert!(info.contains("DEFINE TABLE person"), "{info}");
+
+    let check = stdout(&surreal_embedded(&endpoint, &["check"], None));
+    assert!(check.contains("test/test/person: 2 records"), "{check}");
+    assert!(check.contains("No errors found"), "{check}");
+
+    let export = dir.path().join("export.surql");
+    let args = [&args[..], &["export", export.to_str().unwrap()]].concat();
+    stdout(&surreal_embedded(&endpoint, &args, None));
+    let export = std::fs::read_to_string(export).unwrap();
+    assert!(export.contains("person:two"), "{export}");
+}
@@ -0,0 +1,255 @@
+//! Compares the allocations of "execute" (copying the params) and "execute_pinned" (borrowing them),
+//! for multi-megabyte inserts.
+//!
+//! Run with `cargo bench -p surreal_memory --bench execute_pinned`.
+
+use std::alloc::{GlobalAlloc, Layout, System};
+use std::sync::atomic::{AtomicUsize, Ordering};
+use std::sync::{Condvar, Mutex};
+use std::time::{Duration, Instant};
+
+use shared::bindgen::byte_buffer::ByteBuffer;
+use shared::bindgen::callback::{FailureAction, ReleaseAction, RustGCHandle, SuccessAction};
+use shared::models::method::Method;
+use shared::models::runtime_options::RuntimeOptions;
+use shared::runtime::init_global_runtime;
+use shared::{apply_connect, execute, execute_pinned};
+use surrealdb::rpc::format::cbor::encode;
+use surrealdb_types::{Array, Object, Value};
+
+const ENGINE_ID: i32 = 1;
+const RECORDS: usize = 4_000;
+const RECORD_SIZE: usize = 1_024;
+const ITERATIONS: usize = 10;
+
+static ALLOCATIONS: AtomicUsize = AtomicUsize::new(0);
+static ALLOCATED_BYTES: AtomicUsize = AtomicUsize::new(0);
+
+struct CountingAllocator;
+
+unsafe impl GlobalAlloc for CountingAllocator {
+    unsafe fn alloc(&self, layout: Layout) -> *mut u8 {
+        ALLOCATIONS.fetch_add(1, Ordering::Relaxed);
+        ALLOCATED_BYTES.fetch_add(layout.size(), Ordering::Relaxed);
+        unsafe { System.alloc(layout) }
+    }
+
+    unsafe fn dealloc(&self, ptr: *mut u8, layout: Layout) {
+        unsafe { System.dealloc(ptr, layout) }
+    }
+
+    unsafe fn realloc(&self, ptr: *mut u8, layout: Layout, new_size: usize) -> *mut u8 {
+        ALLOCATIONS.fetch_add(1, Ordering::Relaxed);
+        ALLOCATED_BYTES.fetch_add(new_size, Ordering::Relaxed);
+        unsafe { System.realloc(ptr, layout, new_size) }
+    }
+}
+
+#[global_allocator]
+static GLOBAL: CountingAllocator = CountingAllocator;
+
+/// Same layout as `ReleaseAction`, as built by the host.
+#[repr(C)]
+struct HostReleaseAction {
+    ptr: isize,
+    callback: extern "C" fn(isize),
+}
+
+extern "C" fn release(_: isize) {}
+
+fn release_action() -> ReleaseAction {
+    let action = HostReleaseAction {
+        ptr: 0,
+        callback: release,
+    };
+    unsafe { std::mem::transmute::<HostReleaseAction, ReleaseAction>(action) }
+}
+
+/// Result of the last request, as `(succeeded, payload)`.
+static RESULT: Mutex<Option<(bool, Vec<u8>)>> = Mutex::new(None);
+static RESULT_RECORDED: Condvar = Condvar::new();
+
+fn record_result(succeeded: bool, buffer: *mut ByteBuffer) {
+    let bytes = unsafe { Box::from_raw(buffer) }.destroy_into_vec();
+    *RESULT.lock().unwrap() = Some((succeeded, bytes));
+    RESULT_RECORDED.notify_all();
+}
+
+unsafe extern "C" fn record_success(_: isize, buffer: *mut ByteBuffer) {
+    record_result(true, buffer);
+}
+
+unsafe extern "C" fn record_failure(_: isize, buffer: *mut ByteBuffer) {
+    record_result(false, buffer);
+}
+
+/// Actions recording the result of a request, to be awaited with `wait_result`.
+fn recorded_actions() -> (SuccessAction, FailureAction) {
+    (
+        SuccessAction::new(RustGCHandle::new(0, release), record_success),
+        FailureAction::new(RustGCHandle::new(0, release), record_failure),
+    )
+}
+
+fn wait_result() {
+    let result = RESULT_RECORDED
+        .wait_while(RESULT.lock().unwrap(), |result| result.is_none())
+        .unwrap()
+        .take();
+    let Some((succeeded, bytes)) = result else {
+        unreachable!();
+    };
+    assert!(succeeded, "Request failed: {bytes:?}");
+}
+
+fn connect() {
+    let endpoint = "mem://".encode_utf16().collect::<Vec<_>>();
+    let options = encode(Value::Object(Object::new())).unwrap();
+    let (success, failure) = recorded_actions();
+    unsafe {
+        apply_connect(
+            ENGINE_ID,
+            endpoint.as_ptr(),
+            endpoint.len(),
+            options.as_ptr(),
+            options.len(),
+            success,
+            failure,
+        );
+    }
+    wait_result();
+
+    let params = encode(Value::Array(Array::from(vec![
+        Value::String("bench".to_string()),
+        Value::String("bench".to_string()),
+    ])))
+    .unwrap();
+    let (success, failure) = recorded_actions();
+    unsafe {
+        execute(
+            ENGINE_ID,
+            Method::Use,
+            std::ptr::null(),
+            0,
+            std::ptr::null(),
+            0,
+            params.as_ptr(),
+            params.len(),
+            success,
+            failure,
+        );
+    }
+    wait_result();
+}
+
+fn insert_params() -> Vec<u8> {
+    let records = (0..RECORDS)
+        .map(|index| {
+            let mut record = Object::new();
+            record.insert("index".to_string(), Value::from_t(index as i64));
+            record.insert(
+                "payload".to_string(),
+                Value::String("x".repeat(RECORD_SIZE)),
+            );
+            Value::Object(record)
+        })
+        .collect::<Vec<_>>();
+
+    encode(Value::Array(Array::from(vec![
+        Value::String("person".to_string()),
+        Value::Array(Array::from(records)),
+    ])))
+    .unwrap()
+}
+
+struct Measure {
+    allocations: usize,
+    allocated_bytes: usize,
+    elapsed: Duration,
+}
+
+fn measure(params: &[u8], submit: impl Fn(&[u8])) -> Measure {
+    let mut total = Measure {
+        allocations: 0,
+        allocated_bytes: 0,
+        elapsed: Duration::ZERO,
+    };
+
+    for _ in 0..ITERATIONS {
+        let allocations = ALLOCATIONS.load(Ordering::Relaxed);
+        let allocated_bytes = ALLOCATED_BYTES.load(Ordering::Relaxed);
+        let started_at = Instant::now();
+
+        submit(params);
+        wait_result();
+
+        total.elapsed += started_at.elapsed();
+        total.allocations += ALLOCATIONS.load(Ordering::Relaxed) - allocations;
+        total.allocated_bytes += ALLOCATED_BYTES.load(Ordering::Relaxed) - allocated_bytes;
+    }
+
+    Measure {
+        allocations: total.allocations / ITERATIONS,
+        allocated_bytes: total.allocated_bytes / ITERATIONS,
+        elapsed: total.elapsed / ITERATIONS as u32,
+    }
+}
+
+fn report(name: &str, measure: &Measure) {
+    println!(
+        "{name:<16} {:>12} allocations {:>12.2} MiB allocated {:>10.2?} per insert",
+        measure.allocations,
+        measure.allocated_bytes as f64 / (1024.0 * 1024.0),
+        measure.elapsed,
+    );
+}
+
+fn main() {
+    init_global_runtime(RuntimeOptions::default()).unwrap();
+    connect();
+
+    let params = insert_params();
+    println!(
+        "Inserting {RECORDS} records ({:.2} MiB of params), {ITERATIONS} times",
+        params.len() as f64 / (1024.0 * 1024.0)
+    );
+
+    let copied = measure(&params, |params| {
+        let (success, failure) = recorded_actions();
+        unsafe {
+            execute(
+                ENGINE_ID,
+                Method::Insert,
+                std::ptr::null(),
+                0,
+                std::ptr::null(),
+                0,
+                params.as_ptr(),
+                params.len(),
+                success,
+                failure,
+            );
+        }
+    });
+    report("execute", &copied);
+
+    let pinned = measure(&params, |params| {
+        let (success, failure) = recorded_actions();
+        unsafe {
+            execute_pinned(
+                ENGINE_ID,
+                Method::Insert,
+                std::ptr::null(),
+                0,
+                std::ptr::null(),
+                0,
+                params.as_ptr(),
+                params.len(),
+                release_action(),
+                success,
+                failure,
+            );
+        }
+    });
+    report("execute_pinned", &pinned);
+}
@@ -0,0 +1,39 @@
+use shared::bindgen::alloc::alloc_u8_buffer;
+use shared::bindgen::free::free_u8_buffer;
+use shared::bindgen::pool::{configure_pool, pool_stats, take_buffer};
+use shared::models::buffer_pool_options::BufferPoolOptions;
+
+fn buffer_with_capacity(capacity: usize) -> Vec<u8> {
+    let mut buffer = Vec::with_capacity(capacity);
+    buffer.extend_from_slice(&[1, 2, 3]);
+    buffer
+}
+
+// The pool is global, so every scenario runs within a single test.
+#[test]
+fn result_buffers_are_pooled() {
+    configure_pool(BufferPoolOptions {
+        max_buffer_size: 1024,
+        max_buffers: 4,
+    });
+    let initial = pool_stats();
+
+    // A freed result is kept for reuse
+    unsafe { free_u8_buffer(alloc_u8_buffer(buffer_with_capacity(512))) };
+    let stats = pool_stats();
+    assert_eq!(stats.released, initial.released + 1);
+    assert_eq!(stats.pooled_buffers, initial.pooled_buffers + 1);
+
+    let buffer = take_buffer(256);
+    assert!(buffer.is_empty());
+    assert!(buffer.capacity() >= 512);
+    assert_eq!(pool_stats().hits, initial.hits + 1);
+
+    // Larger buffers are freed rather than reused
+    unsafe { free_u8_buffer(alloc_u8_buffer(buffer_with_capacity(4096))) };
+    assert_eq!(pool_stats().discarded, initial.discarded + 1);
+
+    let buffer = take_buffer(2048);
+    assert!(buffer.capacity() >= 2048);
+    assert_eq!(pool_stats().misses, initial.misses + 1);
+}
@@ -0,0 +1,33 @@
+use shared::bindgen::byte_buffer::ByteBuffer;
+
+#[test]
+#[ignore = "allocates more than 2 GiB, run with `cargo test -- --ignored`"]
+fn lengths_are_kept_beyond_i32() {
+    let length = i32::MAX as usize + 16;
+    let buffer = ByteBuffer::from_vec(vec![0u8; length]);
+    assert_eq!(buffer.len(), length);
+    assert_eq!(buffer.as_slice().len(), length);
+
+    let bytes = buffer.destroy_into_vec();
+    assert_eq!(bytes.len(), length);
+    assert!(bytes.capacity() >= length);
+}
+
+#[test]
+fn struct_lengths_are_counted_in_bytes() {
+    let values: Vec<u64> = vec![1, 2, 3];
+    let buffer = ByteBuffer::from_vec_struct(values);
+    assert_eq!(buffer.len(), 3 * size_of::<u64>());
+
+    assert_eq!(buffer.destroy_into_vec_struct::<u64>(), vec![1, 2, 3]);
+}
+
+#[test]
+fn empty_buffers_are_read_as_empty() {
+    let mut buffer = ByteBuffer::from_vec(vec![1, 2, 3]);
+    assert_eq!(buffer.take_vec(), vec![1, 2, 3]);
+
+    assert!(buffer.is_empty());
+    assert!(buffer.as_slice().is_empty());
+    assert!(buffer.destroy_into_vec().is_empty());
+}
@@ -0,0 +1,118 @@
+#![recursion_limit = "256"]
+
+mod common;
+
+use common::{block_on, connect, execute};
+use shared::app::SurrealEmbeddedEngine;
+use surrealdb::rpc::Method;
+use surrealdb::rpc::format::cbor::encode;
+use surrealdb_types::{Array, Object, Value};
+
+/// Targets configuration denying the given targets
+fn deny(targets: &[&str]) -> Value {
+    let mut config = Object::new();
+    config.insert(
+        "array",
+        Value::Array(Array::from(
+            targets
+                .iter()
+                .map(|target| Value::String(target.to_string()))
+                .collect::<Vec<_>>(),
+        )),
+    );
+    let mut targets = Object::new();
+    targets.insert("deny", Value::Object(config));
+    Value::Object(targets)
+}
+
+fn options(capabilities: Object) -> Object {
+    let mut options = Object::new();
+    options.insert("capabilities", Value::Object(capabilities));
+    options
+}
+
+/// Runs a single statement, returning its result or error message.
+async fn query(engine: &SurrealEmbeddedEngine, sql: &str) -> Result<Value, String> {
+    let params = vec![Value::String(sql.to_string())];
+    let Value::Array(mut results) = execute(engine, Method::Query, None, params).await? else {
+        panic!("Expected the query results");
+    };
+    let Value::Object(mut result) = results.remove(0) else {
+        panic!("Expected a query result");
+    };
+    match (result.remove("status"), result.remove("result")) {
+        (Some(Value::String(status)), Some(result)) if status == "OK" => Ok(result),
+        (_, Some(Value::String(error))) => Err(error),
+        result => panic!("Unexpected result: {result:?}"),
+    }
+}
+
+#[test]
+fn denied_capabilities_are_rejected() {
+    block_on(async {
+        let mut capabilities = Object::new();
+        capabilities.insert("functions", deny(&["crypto::md5"]));
+        capabilities.insert("rpc_methods", deny(&["version"]));
+        let engine = connect(options(capabilities)).await;
+        execute(
+            &engine,
+            Method::Use,
+            None,
+            vec![
+                Value::String("test".to_string()),
+                Value::String("test".to_string()),
+            ],
+        )
+        .await
+        .unwrap();
+
+        let error = query(&engine, "RETURN crypto::md5('value')")
+            .await
+            .unwrap_err();
+        assert!(error.contains("crypto::md5"), "{error}");
+        assert!(query(&engine, "RETURN crypto::sha1('value')").await.is_ok());
+
+        let error = execute(&engine, Method::Version, None, vec![])
+            .await
+            .unwrap_err();
+        assert!(error.contains("not allowed"), "{error}");
+        assert!(execute(&engine, Method::Ping, None, vec![]).await.is_ok());
+
+        engine.close().await.unwrap();
+    });
+}
+
+#[test]
+fn invalid_capabilities_are_rejected_on_connect() {
+    let mut capabilities = Object::new();
+    capabilities.insert("functions", deny(&["crypto::md5(", "fn::ok"]));
+    let options = encode(Value::Object(options(capabilities))).unwrap();
+
+    let error = block_on(SurrealEmbeddedEngine::connect(
+        0,
+        "mem://".to_string(),
+        options,
+    ))
+    .err()
+    .unwrap();
+    assert!(error.to_string().contains("Invalid function"), "{error}");
+}
+
+#[test]
+fn invalid_targets_are_reported_with_invalid_fields() {
+    let mut capabilities = Object::new();
+    capabilities.insert("scripting", Value::String("yes".to_string()));
+    capabilities.insert("functions", deny(&["crypto::md5("]));
+    let options = encode(Value::Object(options(capabilities))).unwrap();
+
+    let error = block_on(SurrealEmbeddedEngine::connect(
+        0,
+        "mem://".to_string(),
+        options,
+    ))
+    .err()
+    .unwrap()
+    .to_string();
+    assert!(error.contains("capabilities.scripting"), "{error}");
+    assert!(error.contains("Invalid function"), "{error}");
+}
@@ -0,0 +1,96 @@
+//! Helpers shared by the integration tests, each test crate using a subset of them.
+//! The tests of the other engines include this module as well, by path.
+//!
+//! Test crates awaiting engine futures raise `recursion_limit` to 256, as the `shared` crate does:
+//! once scoped to their engine id (for log events), those futures are nested too deeply for the default limit.
+#![allow(dead_code)]
+
+use std::collections::VecDeque;
+use std::sync::{Condvar, Mutex};
+use std::time::Duration;
+
+use shared::app::SurrealEmbeddedEngine;
+use shared::bindgen::byte_buffer::ByteBuffer;
+use shared::bindgen::callback::{FailureAction, RustGCHandle, SuccessAction};
+use shared::models::runtime_options::RuntimeOptions;
+use shared::runtime::{get_global_runtime, init_global_runtime};
+use surrealdb::rpc::Method;
+use surrealdb::rpc::format::cbor::{decode, encode};
+use surrealdb_types::{Array, Object, Value};
+use uuid::Uuid;
+
+/// Runs a future on the global runtime, created with the default options if needed.
+pub fn block_on<F: Future>(future: F) -> F::Output {
+    init_global_runtime(RuntimeOptions::default()).unwrap();
+    get_global_runtime().unwrap().block_on(future)
+}
+
+/// Connects an in-memory engine with the given connection options.
+pub async fn connect(options: Object) -> SurrealEmbeddedEngine {
+    connect_endpoint("mem://", options).await.unwrap()
+}
+
+/// Connects an engine to the given endpoint (e.g. "surrealkv://path"), returning it or its error message.
+pub async fn connect_endpoint(
+    endpoint: &str,
+    options: Object,
+) -> Result<SurrealEmbeddedEngine, String> {
+    let options = encode(Value::Object(options)).unwrap();
+
+    SurrealEmbeddedEngine::connect(0, endpoint.to_string(), options)
+        .await
+        .map_err(|e| e.to_string())
+}
+
+/// Executes a method on the given session, returning its decoded result or error message.
+pub async fn execute(
+    engine: &SurrealEmbeddedEngine,
+    method: Method,
+    session_id: Option<Uuid>,
+    params: Vec<Value>,
+) -> Result<Value, String> {
+    let params = encode(Value::Array(Array::from(params))).unwrap();
+    engine
+        .execute(method, session_id, None, params)
+        .await
+        .map(|output| decode(&output).unwrap())
+        .map_err(|e| e.to_string())
+}
+
+static RESULTS: Mutex<VecDeque<(bool, Vec<u8>)>> = Mutex::new(VecDeque::new());
+static RESULT_RECORDED: Condvar = Condvar::new();
+
+extern "C" fn release_handle(_: isize) {}
+
+fn record_result(succeeded: bool, buffer: *mut ByteBuffer) {
+    let bytes = unsafe { Box::from_raw(buffer) }.destroy_into_vec();
+    RESULTS.lock().unwrap().push_back((succeeded, bytes));
+    RESULT_RECORDED.notify_all();
+}
+
+unsafe extern "C" fn record_success(_: isize, buffer: *mut ByteBuffer) {
+    record_result(true, buffer);
+}
+
+unsafe extern "C" fn record_failure(_: isize, buffer: *mut ByteBuffer) {
+    record_result(false, buffer);
+}
+
+/// Actions recording the result of a request, to be read with `wait_result`.
+pub fn recorded_actions() -> (SuccessAction, FailureAction) {
+    (
+        SuccessAction::new(RustGCHandle::new(0, release_handle), record_success),
+        FailureAction::new(RustGCHandle::new(0, release_handle), record_failure),
+    )
+}
+
+/// Waits for the next recorded result, returning whether it succeeded and its payload.
+pub fn wait_result(timeout_ms: u64) -> Option<(bool, Vec<u8>)> {
+    let results = RESULTS.lock().unwrap();
+    let (mut results, _) = RESULT_RECORDED
+        .wait_timeout_while(results, Duration::from_millis(timeout_ms), |results| {
+            results.is_empty()
+        })
+        .unwrap();
+    results.pop_front()
+}
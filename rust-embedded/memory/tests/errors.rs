@@ -0,0 +1,84 @@
+#![recursion_limit = "256"]
+
+mod common;
+
+use common::{block_on, connect};
+use shared::models::error::{EmbeddedError, ErrorKind};
+use shared::runtime::engines::ENGINES;
+use surrealdb::rpc::Method;
+use surrealdb::rpc::format::cbor::encode;
+use surrealdb_types::{Array, Error as TypesError, Number, Object, QueryError, Value};
+use uuid::Uuid;
+
+const ENGINE_ID: i32 = 2;
+
+async fn execute(method: Method, session_id: Option<Uuid>, params: Vec<Value>) -> Value {
+    let params = encode(Value::Array(Array::from(params))).unwrap();
+    let error = ENGINES
+        .execute(ENGINE_ID, method, session_id, None, &params)
+        .await
+        .unwrap_err();
+    EmbeddedError::from(error).into_value()
+}
+
+/// Asserts the kind and RPC code of an encoded error, returning its message.
+fn assert_error(error: Value, kind: ErrorKind, code: i64) -> String {
+    let Value::Object(error) = error else {
+        panic!("Expected an error object, got {error:?}");
+    };
+    assert_eq!(
+        error.get("kind"),
+        Some(&Value::Number(Number::Int(kind as i64))),
+        "{error:?}"
+    );
+    assert_eq!(
+        error.get("code"),
+        Some(&Value::Number(Number::Int(code))),
+        "{error:?}"
+    );
+    match error.get("message") {
+        Some(Value::String(message)) => message.clone(),
+        message => panic!("Unexpected message: {message:?}"),
+    }
+}
+
+#[test]
+fn errors_are_mapped_to_kind_and_code() {
+    block_on(async {
+        let error = execute(Method::Ping, None, vec![]).await;
+        assert_error(error, ErrorKind::EngineMissing, -32000);
+
+        let engine = connect(Object::new()).await;
+        ENGINES.insert(ENGINE_ID, engine).await;
+
+        let error = execute(Method::Use, None, vec![Value::Bool(true)]).await;
+        assert_error(error, ErrorKind::InvalidParams, -32603);
+
+        let error = execute(Method::Run, None, vec![Value::String("fn::f".to_string())]).await;
+        assert_error(error, ErrorKind::InvalidParams, -32600);
+
+        let session_id = Uuid::new_v4();
+        let sql = Value::String("RETURN 1".to_string());
+        let error = execute(Method::Query, Some(session_id), vec![sql]).await;
+        let message = assert_error(error, ErrorKind::NotFound, -32000);
+        assert!(message.contains(&session_id.to_string()));
+
+        let mut credentials = Object::new();
+        credentials.insert("user", Value::String("root".to_string()));
+        credentials.insert("pass", Value::String("wrong".to_string()));
+        let error = execute(Method::Signin, None, vec![Value::Object(credentials)]).await;
+        assert_error(error, ErrorKind::Permission, -32002);
+    });
+
+    let error = EmbeddedError::from(TypesError::query(
+        "Conflict".to_string(),
+        QueryError::TransactionConflict,
+    ));
+    assert_error(error.into_value(), ErrorKind::TransactionConflict, -32009);
+
+    let error = EmbeddedError::cancelled("Cancelled");
+    assert_error(error.into_value(), ErrorKind::Cancelled, -32005);
+
+    let error = EmbeddedError::deserialization("Invalid CBOR");
+    assert_error(error.into_value(), ErrorKind::Serialization, -32008);
+}
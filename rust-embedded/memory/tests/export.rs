@@ -0,0 +1,46 @@
+#![recursion_limit = "256"]
+
+mod common;
+
+use common::{block_on, connect, execute};
+use surrealdb::rpc::Method;
+use surrealdb::rpc::format::cbor::{decode, encode};
+use surrealdb_types::{Object, Value};
+
+#[test]
+fn chunked_export_matches_plain_export() {
+    block_on(async {
+        let engine = connect(Object::new()).await;
+        let params = vec![
+            Value::String("test".to_string()),
+            Value::String("test".to_string()),
+        ];
+        execute(&engine, Method::Use, None, params).await.unwrap();
+        let sql = "DEFINE TABLE person; FOR $i IN 1..500 { CREATE person SET index = $i; };";
+        execute(
+            &engine,
+            Method::Query,
+            None,
+            vec![Value::String(sql.to_string())],
+        )
+        .await
+        .unwrap();
+
+        let config = encode(Value::Object(Object::new())).unwrap();
+        let Value::String(plain) =
+            decode(&engine.export(None, config.clone()).await.unwrap()).unwrap()
+        else {
+            panic!("Expected the export output");
+        };
+
+        let mut chunks = Vec::new();
+        engine
+            .export_stream(None, config, |chunk| chunks.push(chunk))
+            .await
+            .unwrap();
+
+        assert!(chunks.len() > 1);
+        assert_eq!(String::from_utf8(chunks.concat()).unwrap(), plain);
+        assert!(plain.contains("index: 499"), "{plain}");
+    });
+}
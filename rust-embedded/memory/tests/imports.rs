@@ -0,0 +1,153 @@
+#![recursion_limit = "256"]
+
+mod common;
+
+use std::time::Duration;
+
+use common::{block_on, connect, execute, recorded_actions, wait_result};
+use shared::app::ImportSession;
+use shared::models::error::ErrorKind;
+use shared::runtime::engines::ENGINES;
+use shared::{begin_import, export, import};
+use surrealdb::rpc::Method;
+use surrealdb::rpc::format::cbor::decode;
+use surrealdb_types::{Number, Object, Value};
+use uuid::Uuid;
+
+const ENGINE_ID: i32 = 3;
+const OTHER_ENGINE_ID: i32 = 4;
+
+#[test]
+fn chunked_import_does_not_hold_the_engines() {
+    block_on(async {
+        let engine = connect(Object::new()).await;
+        let params = vec![
+            Value::String("test".to_string()),
+            Value::String("test".to_string()),
+        ];
+        execute(&engine, Method::Use, None, params).await.unwrap();
+        ENGINES.insert(ENGINE_ID, engine).await;
+
+        let import = ImportSession::begin(ENGINE_ID, None, None).unwrap();
+        let writer = import.writer();
+        writer
+            .write(b"OPTION IMPORT; CREATE person:one;".to_vec())
+            .await
+            .unwrap();
+
+        // Opening and disposing another engine does not wait for the import to finish
+        let other = connect(Object::new()).await;
+        tokio::time::timeout(Duration::from_secs(5), async {
+            ENGINES.insert(OTHER_ENGINE_ID, other).await;
+            ENGINES.remove(OTHER_ENGINE_ID).await.unwrap().close().await
+        })
+        .await
+        .expect("The engines are held by the import")
+        .unwrap();
+
+        writer.write(b"CREATE person:two;".to_vec()).await.unwrap();
+        import.finish().await.unwrap();
+
+        let sql = Value::String("RETURN count(SELECT * FROM person)".to_string());
+        let engine = ENGINES.get(ENGINE_ID).await.unwrap();
+        let result = execute(&engine, Method::Query, None, vec![sql])
+            .await
+            .unwrap();
+        let Value::Array(results) = result else {
+            panic!("Expected the query results");
+        };
+        let Value::Object(result) = &results[0] else {
+            panic!("Expected a query result");
+        };
+        assert_eq!(result.get("result"), Some(&Value::Number(Number::Int(2))));
+    });
+}
+
+#[test]
+fn streamed_import_within_a_transaction_is_rejected() {
+    let Some(error) = ImportSession::begin(ENGINE_ID, None, Some(Uuid::now_v7())).err() else {
+        panic!("Expected the import to be rejected");
+    };
+
+    let Value::Object(error) = error.into_value() else {
+        panic!("Expected an error object");
+    };
+    assert_eq!(
+        error.get("kind"),
+        Some(&Value::Number(Number::Int(ErrorKind::InvalidParams as i64)))
+    );
+    assert_eq!(
+        error.get("message"),
+        Some(&Value::String(
+            "A streamed import cannot run within a transaction".to_string()
+        ))
+    );
+}
+
+fn assert_invalid_params(message: &str) {
+    let (succeeded, bytes) = wait_result(5_000).unwrap();
+    assert!(!succeeded);
+
+    let Value::Object(error) = decode(&bytes).unwrap() else {
+        panic!("Expected an error object");
+    };
+    assert_eq!(
+        error.get("kind"),
+        Some(&Value::Number(Number::Int(ErrorKind::InvalidParams as i64)))
+    );
+    assert_eq!(
+        error.get("message"),
+        Some(&Value::String(message.to_string()))
+    );
+}
+
+#[test]
+fn malformed_ids_are_rejected() {
+    let malformed = [0u8; 5];
+    let valid = [0u8; 16];
+    let script = "CREATE person".encode_utf16().collect::<Vec<_>>();
+
+    let (success, failure) = recorded_actions();
+    unsafe {
+        export(
+            ENGINE_ID,
+            malformed.as_ptr(),
+            malformed.len(),
+            std::ptr::null(),
+            0,
+            success,
+            failure,
+        )
+    };
+    assert_invalid_params("Failed to deserialize session id");
+
+    let (success, failure) = recorded_actions();
+    unsafe {
+        import(
+            ENGINE_ID,
+            valid.as_ptr(),
+            valid.len(),
+            malformed.as_ptr(),
+            malformed.len(),
+            script.as_ptr(),
+            script.len(),
+            success,
+            failure,
+        )
+    };
+    assert_invalid_params("Failed to deserialize transaction id");
+
+    let (success, failure) = recorded_actions();
+    unsafe {
+        begin_import(
+            ENGINE_ID,
+            malformed.as_ptr(),
+            malformed.len(),
+            std::ptr::null(),
+            0,
+            success,
+            failure,
+        )
+    };
+    assert_invalid_params("Failed to deserialize session id");
+}
@@ -0,0 +1,160 @@
+#![recursion_limit = "256"]
+
+mod common;
+
+use std::sync::Mutex;
+use std::time::Duration;
+
+use common::{block_on, connect, execute};
+use shared::app::SurrealEmbeddedEngine;
+use shared::bindgen::byte_buffer::ByteBuffer;
+use shared::bindgen::callback::{NotificationAction, RustGCHandle};
+use shared::runtime::notifications::{
+    register_notification_action, send_notification, unregister_notification_action,
+};
+use surrealdb::rpc::Method;
+use surrealdb::rpc::format::cbor::{decode, encode};
+use surrealdb_types::{Object, Value};
+use uuid::Uuid;
+
+static NOTIFICATIONS: Mutex<Vec<(i32, Object)>> = Mutex::new(Vec::new());
+
+unsafe extern "C" fn collect_notification(_: isize, id: i32, buffer: *mut ByteBuffer) {
+    let bytes = unsafe { &*buffer }.as_slice();
+    if let Ok(Value::Object(notification)) = decode(bytes) {
+        NOTIFICATIONS.lock().unwrap().push((id, notification));
+    }
+}
+
+extern "C" fn release_handle(_: isize) {}
+
+fn notification_action() -> NotificationAction {
+    NotificationAction::new(RustGCHandle::new(0, release_handle), collect_notification)
+}
+
+/// Waits for the notifications of a live query, for at most a second.
+async fn take_notifications(lqid: Uuid, count: usize) -> Vec<Object> {
+    let lqid = Value::Uuid(lqid.into());
+    let mut taken = Vec::new();
+    for _ in 0..100 {
+        NOTIFICATIONS.lock().unwrap().retain(|(_, notification)| {
+            if notification.get("id") == Some(&lqid) {
+                taken.push(notification.clone());
+                false
+            } else {
+                true
+            }
+        });
+        if taken.len() >= count {
+            break;
+        }
+        tokio::time::sleep(Duration::from_millis(10)).await;
+    }
+    taken
+}
+
+async fn live(engine: &SurrealEmbeddedEngine, session_id: Option<Uuid>) -> Uuid {
+    let params = vec![Value::String("person".to_string())];
+    match execute(engine, Method::Live, session_id, params).await {
+        Ok(Value::Uuid(lqid)) => lqid.into(),
+        result => panic!("Expected a live query id, found {result:?}"),
+    }
+}
+
+async fn create(engine: &SurrealEmbeddedEngine, session_id: Option<Uuid>, id: &str) {
+    let sql = format!("CREATE person:{id}");
+    execute(engine, Method::Query, session_id, vec![Value::String(sql)])
+        .await
+        .unwrap();
+}
+
+async fn live_queries(engine: &SurrealEmbeddedEngine) -> usize {
+    engine.metrics().await.unwrap().live_queries
+}
+
+// The notification callback is global, so every scenario runs within a single test.
+#[test]
+fn notifications_are_forwarded_until_killed() {
+    register_notification_action(notification_action());
+
+    block_on(async {
+        let engine = connect(Object::new()).await;
+        let session_id = Uuid::now_v7();
+        execute(&engine, Method::Attach, Some(session_id), vec![])
+            .await
+            .unwrap();
+        for session_id in [None, Some(session_id)] {
+            let params = vec![
+                Value::String("test".to_string()),
+                Value::String("test".to_string()),
+            ];
+            execute(&engine, Method::Use, session_id, params)
+                .await
+                .unwrap();
+        }
+
+        let sql = Value::String("DEFINE TABLE person".to_string());
+        execute(&engine, Method::Query, None, vec![sql])
+            .await
+            .unwrap();
+
+        // Notifications are sent along with the session that started the live query
+        let lqid = live(&engine, Some(session_id)).await;
+        assert_eq!(live_queries(&engine).await, 1);
+        create(&engine, None, "one").await;
+        let notifications = take_notifications(lqid, 1).await;
+        assert_eq!(notifications.len(), 1);
+        let notification = &notifications[0];
+        assert_eq!(
+            notification.get("action"),
+            Some(&Value::String("CREATE".to_string()))
+        );
+        assert_eq!(
+            notification.get("session"),
+            Some(&Value::Uuid(session_id.into()))
+        );
+
+        // Killed live queries are no longer tracked nor forwarded
+        let params = vec![Value::Uuid(lqid.into())];
+        execute(&engine, Method::Kill, Some(session_id), params)
+            .await
+            .unwrap();
+        assert_eq!(live_queries(&engine).await, 0);
+        take_notifications(lqid, usize::MAX).await;
+        create(&engine, None, "two").await;
+        assert!(take_notifications(lqid, 1).await.is_empty());
+
+        // Detaching a session kills its live queries only
+        live(&engine, Some(session_id)).await;
+        let default_lqid = live(&engine, None).await;
+        assert_eq!(live_queries(&engine).await, 2);
+        execute(&engine, Method::Detach, Some(session_id), vec![])
+            .await
+            .unwrap();
+        assert_eq!(live_queries(&engine).await, 1);
+        create(&engine, None, "three").await;
+        assert_eq!(take_notifications(default_lqid, 1).await.len(), 1);
+
+        // Live queries killed by a statement are untracked along with their last notification
+        let sql = Value::String(format!("KILL u'{default_lqid}'"));
+        execute(&engine, Method::Query, None, vec![sql])
+            .await
+            .unwrap();
+        let notifications = take_notifications(default_lqid, 1).await;
+        assert_eq!(
+            notifications[0].get("action"),
+            Some(&Value::String("KILLED".to_string()))
+        );
+        assert_eq!(live_queries(&engine).await, 0);
+
+        engine.close().await.unwrap();
+    });
+
+    // Notifications are dropped once the callback is unregistered
+    unregister_notification_action();
+    let lqid = Uuid::now_v7();
+    let mut notification = Object::new();
+    notification.insert("id".to_string(), Value::Uuid(lqid.into()));
+    send_notification(0, encode(Value::Object(notification)).unwrap());
+    assert!(block_on(take_notifications(lqid, 1)).is_empty());
+}
@@ -0,0 +1,90 @@
+use std::sync::Mutex;
+
+use shared::bindgen::byte_buffer::ByteBuffer;
+use shared::bindgen::callback::LogAction;
+use shared::models::runtime_options::RuntimeOptions;
+use shared::runtime::logs::{
+    register_log_forwarding, set_log_filter_directives, unregister_log_action, with_engine_id,
+};
+use shared::runtime::{get_global_runtime, init_global_runtime};
+use surrealdb::rpc::format::cbor::decode;
+use surrealdb_types::{Number, Object, Value};
+
+static EVENTS: Mutex<Vec<Object>> = Mutex::new(Vec::new());
+
+unsafe extern "C" fn collect_event(_: isize, buffer: *mut ByteBuffer) {
+    let bytes = unsafe { &*buffer }.as_slice();
+    if let Ok(Value::Object(event)) = decode(bytes) {
+        EVENTS.lock().unwrap().push(event);
+    }
+}
+
+extern "C" fn release_handle(_: isize) {}
+
+/// Same layout as `LogAction`, as built by the host.
+#[repr(C)]
+struct HostLogAction {
+    handle: isize,
+    drop_callback: Option<extern "C" fn(isize)>,
+    callback: unsafe extern "C" fn(isize, *mut ByteBuffer),
+}
+
+fn log_action() -> LogAction {
+    let action = HostLogAction {
+        handle: 0,
+        drop_callback: Some(release_handle),
+        callback: collect_event,
+    };
+    unsafe { std::mem::transmute::<HostLogAction, LogAction>(action) }
+}
+
+fn take_events(target: &str) -> Vec<Object> {
+    let mut events = EVENTS.lock().unwrap();
+    let (matching, others) = events
+        .drain(..)
+        .partition(|event| event.get("target") == Some(&Value::String(target.to_string())));
+    *events = others;
+    matching
+}
+
+// The subscriber is global, so every scenario runs within a single test.
+#[test]
+fn events_are_forwarded_with_engine_id() {
+    init_global_runtime(RuntimeOptions::default()).unwrap();
+    register_log_forwarding(log_action(), "logs=info").unwrap();
+
+    get_global_runtime()
+        .unwrap()
+        .block_on(with_engine_id(7, async {
+            tracing::info!(count = 3, "Within an engine");
+        }));
+    tracing::debug!("Filtered out");
+
+    let events = take_events("logs");
+    assert_eq!(events.len(), 1);
+    let event = &events[0];
+    assert_eq!(
+        event.get("message"),
+        Some(&Value::String("Within an engine".to_string()))
+    );
+    assert_eq!(event.get("level"), Some(&Value::Number(Number::Int(2))));
+    assert_eq!(event.get("engine_id"), Some(&Value::Number(Number::Int(7))));
+    let Some(Value::Object(fields)) = event.get("fields") else {
+        panic!("Expected the event fields");
+    };
+    assert_eq!(fields.get("count"), Some(&Value::Number(Number::Int(3))));
+
+    // The filter can be changed at runtime
+    set_log_filter_directives("logs=error").unwrap();
+    tracing::warn!("Filtered out");
+    tracing::error!("Kept");
+    let events = take_events("logs");
+    assert_eq!(events.len(), 1);
+    assert_eq!(events[0].get("engine_id"), None);
+
+    assert!(set_log_filter_directives("logs=loud").is_err());
+
+    unregister_log_action();
+    tracing::error!("Not forwarded");
+    assert!(take_events("logs").is_empty());
+}
@@ -0,0 +1,114 @@
+#![recursion_limit = "256"]
+
+mod common;
+
+use shared::app::SurrealEmbeddedEngine;
+use shared::bindgen::alloc::alloc_u8_buffer;
+use shared::bindgen::csharp_to_rust::convert_csharp_to_rust_bytes;
+use shared::bindgen::free::free_u8_buffer;
+use shared::models::runtime_options::RuntimeOptions;
+use shared::runtime::engines::ENGINES;
+use shared::runtime::metrics::collect_metrics;
+use shared::runtime::requests::{cancel_request, spawn_engine_request};
+use shared::runtime::{get_global_runtime, init_global_runtime};
+use surrealdb::rpc::Method;
+use surrealdb::rpc::format::cbor::encode;
+use surrealdb_types::{Array, Number, Object, Value};
+
+use common::{recorded_actions, wait_result};
+
+fn get<'a>(value: &'a Value, key: &str) -> &'a Value {
+    match value {
+        Value::Object(object) => object.get(key).unwrap_or(&Value::None),
+        _ => &Value::None,
+    }
+}
+
+fn int(value: &Value) -> i64 {
+    match value {
+        Value::Number(Number::Int(value)) => *value,
+        value => panic!("Expected a number, found {value:?}"),
+    }
+}
+
+async fn engine_metrics(id: i32) -> Value {
+    let metrics = collect_metrics(&get_global_runtime().unwrap()).await;
+    let Value::Array(engines) = get(&metrics, "engines") else {
+        panic!("Expected the metrics of every engine");
+    };
+    engines
+        .iter()
+        .find(|engine| int(get(engine, "id")) == id as i64)
+        .cloned()
+        .unwrap()
+}
+
+// Metrics are global, so every scenario runs within a single test.
+#[test]
+fn metrics_report_engines_requests_and_runtime() {
+    init_global_runtime(RuntimeOptions::default()).unwrap();
+    let runtime = get_global_runtime().unwrap();
+
+    runtime.block_on(async {
+        let options = encode(Value::Object(Object::new())).unwrap();
+        let engine = SurrealEmbeddedEngine::connect(1, "mem://".to_string(), options)
+            .await
+            .unwrap();
+        ENGINES.insert(1, engine).await;
+
+        let params = encode(Value::Array(Array::new())).unwrap();
+        ENGINES
+            .execute(1, Method::Begin, None, None, &params)
+            .await
+            .unwrap();
+
+        let engine = engine_metrics(1).await;
+        assert_eq!(int(get(&engine, "transactions")), 1);
+        assert_eq!(int(get(&engine, "requests")), 0);
+
+        // A request counts as in flight until cancelled
+        let (success, failure) = recorded_actions();
+        let ticket = spawn_engine_request(1, "pending", success, failure, std::future::pending());
+        while int(get(&engine_metrics(1).await, "requests")) == 0 {
+            tokio::task::yield_now().await;
+        }
+        cancel_request(ticket);
+        assert_eq!(int(get(&engine_metrics(1).await, "requests")), 0);
+        assert!(matches!(wait_result(0), Some((false, _))));
+
+        // The latency of a completed request is recorded under its name
+        let (success, failure) = recorded_actions();
+        spawn_engine_request(1, Method::Ping.to_str(), success, failure, async move {
+            ENGINES.execute(1, Method::Ping, None, None, &params).await
+        });
+        let result = tokio::task::spawn_blocking(|| wait_result(5_000))
+            .await
+            .unwrap();
+        assert!(matches!(result, Some((true, _))));
+
+        let metrics = collect_metrics(&runtime).await;
+        let ping = get(get(&metrics, "latencies"), "ping");
+        assert_eq!(int(get(ping, "count")), 1);
+        let Value::Array(buckets) = get(ping, "buckets") else {
+            panic!("Expected the latency buckets");
+        };
+        assert_eq!(buckets.iter().map(int).sum::<i64>(), 1);
+        // Unlike a cancelled one
+        assert_eq!(get(get(&metrics, "latencies"), "pending"), &Value::None);
+        assert!(int(get(get(&metrics, "runtime"), "workers")) > 0);
+
+        // Buffers exchanged with .NET are counted
+        let bytes_in = int(get(&metrics, "bytes_in"));
+        let bytes_out = int(get(&metrics, "bytes_out"));
+        let bytes = [1u8, 2, 3];
+        drop(unsafe { convert_csharp_to_rust_bytes(bytes.as_ptr(), bytes.len()) });
+        unsafe { free_u8_buffer(alloc_u8_buffer(vec![1, 2, 3, 4])) };
+
+        let metrics = collect_metrics(&runtime).await;
+        assert_eq!(int(get(&metrics, "bytes_in")), bytes_in + 3);
+        assert_eq!(int(get(&metrics, "bytes_out")), bytes_out + 4);
+
+        let engine = ENGINES.remove(1).await.unwrap();
+        engine.close().await.unwrap();
+    });
+}
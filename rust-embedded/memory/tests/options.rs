@@ -0,0 +1,156 @@
+#![recursion_limit = "256"]
+
+use std::time::Duration as StdDuration;
+
+use shared::bindgen::csharp_to_rust::convert_csharp_to_rust_string_utf16;
+use shared::models::connection_options::ConnectionOptions;
+use surrealdb::rpc::format::cbor::encode;
+use surrealdb_types::{Array, Duration, Object, Value};
+
+fn object(fields: Vec<(&str, Value)>) -> Value {
+    let mut object = Object::new();
+    for (key, value) in fields {
+        object.insert(key.to_string(), value);
+    }
+    Value::Object(object)
+}
+
+fn parse(options: Value) -> Result<ConnectionOptions, String> {
+    let bytes = encode(options).unwrap();
+    ConnectionOptions::from_bytes(&bytes).map_err(|error| error.to_string())
+}
+
+#[test]
+fn valid_options_are_parsed() {
+    let options = parse(object(vec![
+        ("strict", Value::Bool(true)),
+        (
+            "auth",
+            object(vec![
+                ("enabled", Value::Bool(true)),
+                ("username", Value::String("root".to_string())),
+                ("password", Value::String("root".to_string())),
+            ]),
+        ),
+        (
+            "transactions",
+            object(vec![(
+                "idle_timeout",
+                Value::Duration(Duration::from_secs(30)),
+            )]),
+        ),
+        (
+            "capabilities",
+            object(vec![(
+                "functions",
+                object(vec![(
+                    "deny",
+                    object(vec![(
+                        "array",
+                        Value::Array(Array::from(vec![Value::String("http".to_string())])),
+                    )]),
+                )]),
+            )]),
+        ),
+    ]))
+    .unwrap();
+
+    assert_eq!(options.strict, Some(true));
+    let auth = options.auth.unwrap();
+    assert_eq!(auth.username.as_deref(), Some("root"));
+    assert_eq!(
+        options.transactions.unwrap().idle_timeout,
+        Some(StdDuration::from_secs(30))
+    );
+    let deny = options
+        .capabilities
+        .unwrap()
+        .functions
+        .unwrap()
+        .deny
+        .unwrap();
+    assert!(deny.array.unwrap().contains("http"));
+
+    assert!(parse(Value::None).unwrap().strict.is_none());
+}
+
+#[test]
+fn every_invalid_field_is_reported_by_name() {
+    let error = parse(object(vec![
+        ("strict", Value::String("yes".to_string())),
+        ("restore", Value::Bool(true)),
+        (
+            "auth",
+            object(vec![
+                ("enabled", Value::Bool(true)),
+                ("username", Value::Bool(false)),
+            ]),
+        ),
+        (
+            "transactions",
+            object(vec![("max_lifetime", Value::Duration(Duration::ZERO))]),
+        ),
+        (
+            "capabilities",
+            object(vec![
+                ("scripting", Value::None),
+                ("guest_access", Value::String("no".to_string())),
+                (
+                    "rpc_methods",
+                    object(vec![(
+                        "allow",
+                        object(vec![(
+                            "array",
+                            Value::Array(Array::from(vec![Value::Bool(true)])),
+                        )]),
+                    )]),
+                ),
+                ("functions", Value::Bool(true)),
+            ]),
+        ),
+    ]))
+    .unwrap_err();
+
+    for field in [
+        "strict to boolean",
+        "restore to string",
+        "auth.username to string",
+        "transactions.max_lifetime must be a positive duration",
+        "capabilities.guest_access to boolean",
+        "capabilities.rpc_methods.allow.array",
+        "capabilities.functions to object",
+    ] {
+        assert!(error.contains(field), "{field} is missing from: {error}");
+    }
+    // The pair is only checked once the fields themselves are valid
+    assert!(!error.contains("are required"), "{error}");
+}
+
+#[test]
+fn root_user_requires_both_username_and_password() {
+    let error = parse(object(vec![(
+        "auth",
+        object(vec![("username", Value::String("root".to_string()))]),
+    )]))
+    .unwrap_err();
+
+    assert!(
+        error.contains("Both auth.username and auth.password are required"),
+        "{error}"
+    );
+}
+
+#[test]
+fn invalid_utf16_strings_are_rejected() {
+    let valid: Vec<u16> = "mem://".encode_utf16().collect();
+    let converted = unsafe { convert_csharp_to_rust_string_utf16(valid.as_ptr(), valid.len()) };
+    assert_eq!(converted.unwrap(), "mem://");
+
+    // An unpaired surrogate, which a C# string can hold
+    let invalid = [0x006d, 0xd800, 0x006d];
+    let converted = unsafe { convert_csharp_to_rust_string_utf16(invalid.as_ptr(), invalid.len()) };
+    assert!(converted.is_err());
+
+    let empty = unsafe { convert_csharp_to_rust_string_utf16(std::ptr::null(), 0) };
+    assert_eq!(empty.unwrap(), "");
+}
@@ -0,0 +1,110 @@
+#![recursion_limit = "256"]
+
+mod common;
+
+use std::time::{Duration, Instant};
+
+use common::{block_on, connect, recorded_actions, wait_result};
+use shared::execute;
+use shared::models::error::ErrorKind;
+use shared::models::method::Method;
+use shared::runtime::engines::ENGINES;
+use shared::runtime::requests::{REQUEST_CANCELLED, cancel_request};
+use surrealdb::rpc::format::cbor::{decode, encode};
+use surrealdb_types::{Array, Number, Object, Value};
+
+const ENGINE_ID: i32 = 1;
+const SLOW_ENGINE_ID: i32 = 2;
+const OTHER_ENGINE_ID: i32 = 3;
+
+fn execute_query(sql: &str) -> u64 {
+    let params = encode(Value::Array(Array::from(vec![Value::String(
+        sql.to_string(),
+    )])))
+    .unwrap();
+    let (success, failure) = recorded_actions();
+
+    unsafe {
+        execute(
+            ENGINE_ID,
+            Method::Query,
+            std::ptr::null(),
+            0,
+            std::ptr::null(),
+            0,
+            params.as_ptr(),
+            params.len(),
+            success,
+            failure,
+        )
+    }
+}
+
+#[test]
+fn cancelled_request_fails_with_cancelled_kind() {
+    block_on(async {
+        let engine = connect(Object::new()).await;
+        ENGINES.insert(ENGINE_ID, engine).await;
+    });
+
+    let started = Instant::now();
+    let ticket = execute_query("SLEEP 10s");
+    std::thread::sleep(Duration::from_millis(100));
+    cancel_request(ticket);
+
+    let (succeeded, bytes) = wait_result(5_000).unwrap();
+    assert!(!succeeded);
+    assert!(started.elapsed() < Duration::from_secs(5));
+
+    let Value::Object(error) = decode(&bytes).unwrap() else {
+        panic!("Expected an error object");
+    };
+    assert_eq!(
+        error.get("kind"),
+        Some(&Value::Number(Number::Int(ErrorKind::Cancelled as i64)))
+    );
+    assert_eq!(
+        error.get("message"),
+        Some(&Value::String(REQUEST_CANCELLED.to_string()))
+    );
+
+    // Cancelling a completed request does nothing
+    cancel_request(ticket);
+    assert_eq!(wait_result(10), None);
+
+    // The engine keeps serving requests
+    execute_query("RETURN 1");
+    let (succeeded, _) = wait_result(5_000).unwrap();
+    assert!(succeeded);
+}
+
+#[test]
+fn running_request_does_not_hold_the_engines() {
+    block_on(async {
+        let engine = connect(Object::new()).await;
+        ENGINES.insert(SLOW_ENGINE_ID, engine).await;
+
+        let params = encode(Value::Array(Array::from(vec![Value::String(
+            "SLEEP 2s".to_string(),
+        )])))
+        .unwrap();
+        let request = tokio::spawn(async move {
+            ENGINES
+                .execute(SLOW_ENGINE_ID, Method::Query.into(), None, None, params)
+                .await
+        });
+        tokio::time::sleep(Duration::from_millis(100)).await;
+
+        // Opening and disposing another engine does not wait for the request to complete
+        let other = connect(Object::new()).await;
+        tokio::time::timeout(Duration::from_secs(1), async {
+            ENGINES.insert(OTHER_ENGINE_ID, other).await;
+            ENGINES.remove(OTHER_ENGINE_ID).await.unwrap().close().await
+        })
+        .await
+        .expect("The engines are held by the request")
+        .unwrap();
+
+        request.await.unwrap().unwrap();
+    });
+}
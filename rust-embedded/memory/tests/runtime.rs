@@ -0,0 +1,29 @@
+#![recursion_limit = "256"]
+
+mod common;
+
+use common::{block_on, connect};
+use shared::runtime::engines::ENGINES;
+use shared::runtime::{get_global_runtime, shutdown_global_runtime};
+use surrealdb_types::Object;
+
+const ENGINE_ID: i32 = 1;
+
+#[test]
+fn runtime_shuts_down_within_an_async_context() {
+    block_on(async {
+        let engine = connect(Object::new()).await;
+        ENGINES.insert(ENGINE_ID, engine).await;
+    });
+
+    let host = tokio::runtime::Builder::new_current_thread()
+        .enable_all()
+        .build()
+        .unwrap();
+    host.block_on(async { shutdown_global_runtime() });
+
+    assert!(get_global_runtime().is_err());
+
+    // The runtime is created again, without the disposed engine
+    assert!(!block_on(ENGINES.contains(ENGINE_ID)));
+}
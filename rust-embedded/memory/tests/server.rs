@@ -0,0 +1,163 @@
+#![recursion_limit = "256"]
+
+mod common;
+
+use std::net::SocketAddr;
+
+use common::{block_on, connect};
+use futures::{SinkExt, StreamExt};
+use shared::app::server::DebugServer;
+use shared::models::serve_options::ServeOptions;
+use shared::runtime::engines::ENGINES;
+use surrealdb::rpc::Method;
+use surrealdb::rpc::format::cbor::{decode, encode};
+use surrealdb::rpc::format::json;
+use surrealdb_types::{Array, Object, Value};
+use tokio::io::{AsyncReadExt, AsyncWriteExt};
+use tokio::net::TcpStream;
+use tokio_tungstenite::tungstenite::Message;
+use tokio_tungstenite::tungstenite::client::IntoClientRequest;
+use tokio_tungstenite::{MaybeTlsStream, WebSocketStream};
+
+type Socket = WebSocketStream<MaybeTlsStream<TcpStream>>;
+
+async fn start(id: i32, auth: bool) -> DebugServer {
+    DebugServer::start(
+        id,
+        ServeOptions {
+            address: "127.0.0.1:0".parse().unwrap(),
+            auth,
+        },
+    )
+    .await
+    .unwrap()
+}
+
+async fn connect_socket(address: SocketAddr) -> Socket {
+    let mut request = format!("ws://{address}/rpc").into_client_request().unwrap();
+    request
+        .headers_mut()
+        .insert("Sec-WebSocket-Protocol", "json".parse().unwrap());
+    let (socket, _) = tokio_tungstenite::connect_async(request).await.unwrap();
+    socket
+}
+
+/// Sends a JSON request, and returns its result (or error).
+async fn call(socket: &mut Socket, method: &str, params: &str) -> Result<Value, Value> {
+    send_request(
+        socket,
+        format!(r#"{{ "id": 1, "method": "{method}", "params": {params} }}"#),
+    )
+    .await
+}
+
+/// Sends a raw JSON request, and returns its result (or error).
+async fn send_request(socket: &mut Socket, request: String) -> Result<Value, Value> {
+    socket.send(Message::text(request)).await.unwrap();
+
+    let Some(Ok(Message::Text(response))) = socket.next().await else {
+        panic!("Expected a response");
+    };
+    let Value::Object(mut response) = json::decode(response.as_bytes()).unwrap() else {
+        panic!("Expected a response object");
+    };
+    match response.remove("error") {
+        Some(error) => Err(error),
+        None => Ok(response.remove("result").unwrap_or_default()),
+    }
+}
+
+/// Posts statements to the `/sql` endpoint, and returns the status line along with the body.
+async fn post_sql(address: SocketAddr, sql: &str, headers: &str) -> (String, String) {
+    let mut stream = TcpStream::connect(address).await.unwrap();
+    let request = format!(
+        "POST /sql HTTP/1.1\r\nHost: {address}\r\n{headers}Content-Length: {}\r\nConnection: close\r\n\r\n{sql}",
+        sql.len()
+    );
+    stream.write_all(request.as_bytes()).await.unwrap();
+
+    let mut response = String::new();
+    stream.read_to_string(&mut response).await.unwrap();
+    let (head, body) = response.split_once("\r\n\r\n").unwrap();
+    (head.lines().next().unwrap().to_string(), body.to_string())
+}
+
+#[test]
+fn debug_server_answers_rpc_and_sql_requests() {
+    block_on(async {
+        let engine = connect(Object::new()).await;
+        ENGINES.insert(1, engine).await;
+
+        let server = start(1, false).await;
+        let mut socket = connect_socket(server.address()).await;
+
+        call(&mut socket, "use", r#"["test", "test"]"#)
+            .await
+            .unwrap();
+        call(
+            &mut socket,
+            "query",
+            r#"["CREATE person:one SET name = 'debug'"]"#,
+        )
+        .await
+        .unwrap();
+        let Ok(Value::Array(persons)) = call(&mut socket, "select", r#"["person"]"#).await else {
+            panic!("Expected the selected records");
+        };
+        assert_eq!(persons.len(), 1);
+
+        // Notifications cannot be sent to debug clients
+        assert!(call(&mut socket, "live", r#"["person"]"#).await.is_err());
+
+        // A connection cannot switch to another session
+        assert!(call(&mut socket, "sessions", "[]").await.is_err());
+        assert!(call(&mut socket, "attach", "[]").await.is_err());
+        let request = r#"{ "id": 1, "session": "0190a1b2-c3d4-7e5f-8a9b-0c1d2e3f4a5b", "method": "select", "params": ["person"] }"#;
+        let Ok(Value::Array(persons)) = send_request(&mut socket, request.to_string()).await else {
+            panic!("Expected the records selected within the session of the connection");
+        };
+        assert_eq!(persons.len(), 1);
+
+        // The connection has a session of its own, leaving the default session untouched
+        let params = encode(Value::Array(Array::from(vec![Value::String(
+            "SELECT * FROM person".to_string(),
+        )])))
+        .unwrap();
+        let output = ENGINES
+            .execute(1, Method::Query, None, None, &params)
+            .await
+            .unwrap();
+        let result = format!("{:?}", decode(&output).unwrap());
+        assert!(!result.contains("debug"), "{result}");
+
+        let (status, body) = post_sql(
+            server.address(),
+            "SELECT * FROM person",
+            "surreal-ns: test\r\nsurreal-db: test\r\n",
+        )
+        .await;
+        assert!(status.contains("200"), "{status}");
+        assert!(body.contains("debug"), "{body}");
+
+        // Connections are closed once the server is stopped
+        server.stop().await;
+        assert!(matches!(
+            socket.next().await,
+            None | Some(Ok(Message::Close(_)) | Err(_))
+        ));
+
+        // Clients must sign in when authentication is required
+        let server = start(1, true).await;
+        let mut socket = connect_socket(server.address()).await;
+        assert!(call(&mut socket, "ping", "[]").await.is_ok());
+        assert!(call(&mut socket, "select", r#"["person"]"#).await.is_err());
+
+        let (status, _) = post_sql(server.address(), "SELECT * FROM person", "").await;
+        assert!(status.contains("401"), "{status}");
+
+        server.stop().await;
+
+        let engine = ENGINES.remove(1).await.unwrap();
+        engine.close().await.unwrap();
+    });
+}
@@ -0,0 +1,105 @@
+#![recursion_limit = "256"]
+
+mod common;
+
+use common::{block_on, connect, execute};
+use shared::app::SurrealEmbeddedEngine;
+use surrealdb::rpc::Method;
+use surrealdb::rpc::format::cbor::{decode, encode};
+use surrealdb_types::{Object, Value};
+use uuid::Uuid;
+
+async fn attach(engine: &SurrealEmbeddedEngine, ns: &str, db: &str) -> Uuid {
+    let session_id = Uuid::now_v7();
+    execute(engine, Method::Attach, Some(session_id), vec![])
+        .await
+        .unwrap();
+    execute(
+        engine,
+        Method::Use,
+        Some(session_id),
+        vec![Value::String(ns.to_string()), Value::String(db.to_string())],
+    )
+    .await
+    .unwrap();
+    session_id
+}
+
+async fn export(engine: &SurrealEmbeddedEngine, session_id: Option<Uuid>) -> String {
+    let config = encode(Value::Object(Object::new())).unwrap();
+    let output = engine.export(session_id, config).await.unwrap();
+    match decode(&output).unwrap() {
+        Value::String(output) => output,
+        value => panic!("Unexpected export output: {value:?}"),
+    }
+}
+
+#[test]
+fn export_uses_the_given_session() {
+    block_on(async {
+        let engine = connect(Object::new()).await;
+
+        let session_id = attach(&engine, "session", "session").await;
+        execute(
+            &engine,
+            Method::Use,
+            None,
+            vec![
+                Value::String("default".to_string()),
+                Value::String("default".to_string()),
+            ],
+        )
+        .await
+        .unwrap();
+
+        engine
+            .import(Some(session_id), None, "CREATE person:one;".to_string())
+            .await
+            .unwrap();
+
+        assert!(export(&engine, Some(session_id)).await.contains("person"));
+        assert!(!export(&engine, None).await.contains("person"));
+
+        engine.close().await.unwrap();
+    });
+}
+
+#[test]
+fn import_runs_within_the_given_transaction() {
+    block_on(async {
+        let engine = connect(Object::new()).await;
+
+        let session_id = attach(&engine, "test", "test").await;
+        let Value::Uuid(transaction_id) = execute(&engine, Method::Begin, Some(session_id), vec![])
+            .await
+            .unwrap()
+        else {
+            panic!("Expected a transaction id");
+        };
+
+        engine
+            .import(
+                Some(session_id),
+                Some(transaction_id.into_inner()),
+                "CREATE person:one;".to_string(),
+            )
+            .await
+            .unwrap();
+
+        // Not visible until the transaction is committed
+        assert!(!export(&engine, Some(session_id)).await.contains("person"));
+
+        execute(
+            &engine,
+            Method::Commit,
+            Some(session_id),
+            vec![Value::Uuid(transaction_id)],
+        )
+        .await
+        .unwrap();
+
+        assert!(export(&engine, Some(session_id)).await.contains("person"));
+
+        engine.close().await.unwrap();
+    });
+}
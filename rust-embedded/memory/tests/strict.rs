@@ -0,0 +1,300 @@
+#![recursion_limit = "256"]
+
+mod common;
+
+use common::{block_on, connect, execute};
+use shared::app::SurrealEmbeddedEngine;
+use surrealdb::rpc::Method;
+use surrealdb_types::{Object, Value};
+
+fn options(strict: bool) -> Object {
+    let mut options = Object::new();
+    options.insert("strict".to_string(), Value::Bool(strict));
+    options
+}
+
+fn use_params(ns: &str, db: &str) -> Vec<Value> {
+    vec![Value::String(ns.to_string()), Value::String(db.to_string())]
+}
+
+fn query_params(query: &str) -> Vec<Value> {
+    vec![Value::String(query.to_string())]
+}
+
+/// Whether the definitions listed by an INFO statement, run on the default session, contain a name
+async fn is_defined(engine: &SurrealEmbeddedEngine, info: &str, kind: &str, name: &str) -> bool {
+    let Value::Array(results) = execute(engine, Method::Query, None, query_params(info))
+        .await
+        .unwrap()
+    else {
+        panic!("Expected the query results");
+    };
+    match &results[0] {
+        Value::Object(result) => match result.get("result") {
+            Some(Value::Object(info)) => {
+                matches!(info.get(kind), Some(Value::Object(definitions)) if definitions.get(name).is_some())
+            }
+            result => panic!("Unexpected info: {result:?}"),
+        },
+        result => panic!("Unexpected result: {result:?}"),
+    }
+}
+
+#[test]
+fn strict_mode_rejects_undefined_namespace() {
+    block_on(async {
+        let engine = connect(options(true)).await;
+
+        let error = execute(&engine, Method::Use, None, use_params("test", "test"))
+            .await
+            .unwrap_err();
+        assert_eq!(error, "The namespace 'test' does not exist");
+        assert!(!is_defined(&engine, "INFO FOR ROOT", "namespaces", "test").await);
+
+        engine.close().await.unwrap();
+    });
+}
+
+#[test]
+fn strict_mode_rejects_undefined_database() {
+    block_on(async {
+        let engine = connect(options(true)).await;
+
+        execute(
+            &engine,
+            Method::Query,
+            None,
+            query_params("DEFINE NAMESPACE test;"),
+        )
+        .await
+        .unwrap();
+
+        let error = execute(&engine, Method::Use, None, use_params("test", "test"))
+            .await
+            .unwrap_err();
+        assert_eq!(error, "The database 'test' does not exist");
+
+        engine.close().await.unwrap();
+    });
+}
+
+#[test]
+fn strict_mode_accepts_defined_namespace_and_database() {
+    block_on(async {
+        let engine = connect(options(true)).await;
+
+        execute(
+            &engine,
+            Method::Query,
+            None,
+            query_params("DEFINE NAMESPACE test; USE NS test; DEFINE DATABASE test STRICT;"),
+        )
+        .await
+        .unwrap();
+
+        execute(&engine, Method::Use, None, use_params("test", "test"))
+            .await
+            .unwrap();
+
+        engine.close().await.unwrap();
+    });
+}
+
+#[test]
+fn non_strict_mode_creates_namespace_and_database() {
+    block_on(async {
+        let engine = connect(options(false)).await;
+
+        execute(&engine, Method::Use, None, use_params("test", "test"))
+            .await
+            .unwrap();
+
+        assert!(is_defined(&engine, "INFO FOR ROOT", "namespaces", "test").await);
+        assert!(is_defined(&engine, "INFO FOR NS", "databases", "test").await);
+
+        engine.close().await.unwrap();
+    });
+}
+
+#[test]
+fn strict_mode_rejects_undefined_namespace_in_query() {
+    block_on(async {
+        let engine = connect(options(true)).await;
+
+        let error = execute(
+            &engine,
+            Method::Query,
+            None,
+            query_params("USE NS test DB test; CREATE foo;"),
+        )
+        .await
+        .unwrap_err();
+        assert_eq!(error, "The namespace 'test' does not exist");
+        assert!(!is_defined(&engine, "INFO FOR ROOT", "namespaces", "test").await);
+
+        engine.close().await.unwrap();
+    });
+}
+
+#[test]
+fn strict_mode_rejects_undefined_database_in_import() {
+    block_on(async {
+        let engine = connect(options(true)).await;
+
+        execute(
+            &engine,
+            Method::Query,
+            None,
+            query_params("DEFINE NAMESPACE test;"),
+        )
+        .await
+        .unwrap();
+
+        let error = engine
+            .import(
+                None,
+                None,
+                "USE NS test; USE DB test; CREATE foo;".to_string(),
+            )
+            .await
+            .err()
+            .unwrap();
+        assert_eq!(error.to_string(), "The database 'test' does not exist");
+
+        engine.close().await.unwrap();
+    });
+}
+
+#[test]
+fn strict_mode_ignores_use_in_strings_and_comments() {
+    block_on(async {
+        let engine = connect(options(true)).await;
+
+        execute(
+            &engine,
+            Method::Query,
+            None,
+            query_params(
+                "-- USE NS commented;\nDEFINE NAMESPACE `test`; USE NS ⟨test⟩; DEFINE DATABASE IF NOT EXISTS test STRICT; USE DB test; RETURN 'USE NS quoted';",
+            ),
+        )
+        .await
+        .unwrap();
+
+        assert!(!is_defined(&engine, "INFO FOR ROOT", "namespaces", "commented").await);
+
+        engine.close().await.unwrap();
+    });
+}
+
+#[test]
+fn strict_mode_rejects_undefined_table() {
+    block_on(async {
+        let engine = connect(options(true)).await;
+
+        execute(
+            &engine,
+            Method::Query,
+            None,
+            query_params("DEFINE NAMESPACE test; USE NS test; DEFINE DATABASE test STRICT;"),
+        )
+        .await
+        .unwrap();
+        execute(&engine, Method::Use, None, use_params("test", "test"))
+            .await
+            .unwrap();
+
+        let Value::Array(results) =
+            execute(&engine, Method::Query, None, query_params("CREATE foo;"))
+                .await
+                .unwrap()
+        else {
+            panic!("Expected the query results");
+        };
+        let Value::Object(result) = &results[0] else {
+            panic!("Expected a query result");
+        };
+        assert_eq!(
+            result.get("result"),
+            Some(&Value::String("The table 'foo' does not exist".to_string()))
+        );
+
+        engine.close().await.unwrap();
+    });
+}
+
+#[test]
+fn strict_mode_rejects_database_not_defined_as_strict() {
+    block_on(async {
+        let engine = connect(options(true)).await;
+
+        let error = execute(
+            &engine,
+            Method::Query,
+            None,
+            query_params("DEFINE NAMESPACE test; USE NS test; IF true { DEFINE DATABASE test };"),
+        )
+        .await
+        .unwrap_err();
+        assert_eq!(error, "Databases must be defined as STRICT in strict mode");
+        assert!(!is_defined(&engine, "INFO FOR ROOT", "namespaces", "test").await);
+
+        engine.close().await.unwrap();
+    });
+}
+
+#[test]
+fn strict_mode_rejects_use_of_unnamed_namespace() {
+    block_on(async {
+        let engine = connect(options(true)).await;
+
+        for query in [
+            "DEFINE NAMESPACE test; LET $ns = 'other'; USE NS $ns;",
+            "DEFINE NAMESPACE test; USE NS string::concat('te', 'st');",
+            "DEFINE NAMESPACE test; USE DEFAULT;",
+        ] {
+            let error = execute(&engine, Method::Query, None, query_params(query))
+                .await
+                .unwrap_err();
+            assert_eq!(
+                error,
+                "A USE statement must name its namespace and database in strict mode"
+            );
+        }
+        assert!(!is_defined(&engine, "INFO FOR ROOT", "namespaces", "test").await);
+
+        engine.close().await.unwrap();
+    });
+}
+
+#[test]
+fn strict_mode_rejects_removal_of_namespace() {
+    block_on(async {
+        let engine = connect(options(true)).await;
+
+        execute(
+            &engine,
+            Method::Query,
+            None,
+            query_params("DEFINE NAMESPACE test;"),
+        )
+        .await
+        .unwrap();
+
+        let error = execute(
+            &engine,
+            Method::Query,
+            None,
+            query_params("REMOVE NAMESPACE test;"),
+        )
+        .await
+        .unwrap_err();
+        assert_eq!(
+            error,
+            "Namespaces and databases cannot be removed in strict mode"
+        );
+        assert!(is_defined(&engine, "INFO FOR ROOT", "namespaces", "test").await);
+
+        engine.close().await.unwrap();
+    });
+}
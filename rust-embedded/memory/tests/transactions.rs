@@ -0,0 +1,256 @@
+#![recursion_limit = "256"]
+
+use std::time::Duration;
+
+mod common;
+
+use common::{block_on, connect, execute};
+use shared::app::SurrealEmbeddedEngine;
+use surrealdb::rpc::Method;
+use surrealdb::rpc::format::cbor::{decode, encode};
+use surrealdb_types::{Array, Object, Value};
+use uuid::Uuid;
+
+/// Connects an engine limiting transactions with the given option
+async fn connect_with_limit(limit: &str, duration: Duration) -> SurrealEmbeddedEngine {
+    let mut transactions = Object::new();
+    transactions.insert(
+        limit.to_string(),
+        Value::Duration(surrealdb_types::Duration::from_std(duration)),
+    );
+    let mut options = Object::new();
+    options.insert("transactions".to_string(), Value::Object(transactions));
+
+    connect(options).await
+}
+
+#[test]
+fn idle_transaction_expires() {
+    block_on(async {
+        let engine = connect_with_limit("idle_timeout", Duration::from_millis(50)).await;
+
+        let transaction_id = execute(&engine, Method::Begin, None, vec![]).await.unwrap();
+        tokio::time::sleep(Duration::from_millis(300)).await;
+
+        let error = execute(&engine, Method::Commit, None, vec![transaction_id])
+            .await
+            .unwrap_err();
+        assert_eq!(error, "Transaction expired after being idle for 50ms");
+
+        engine.close().await.unwrap();
+    });
+}
+
+#[test]
+fn transaction_expires_after_max_lifetime() {
+    block_on(async {
+        let engine = connect_with_limit("max_lifetime", Duration::from_millis(50)).await;
+
+        let transaction_id = execute(&engine, Method::Begin, None, vec![]).await.unwrap();
+        tokio::time::sleep(Duration::from_millis(300)).await;
+
+        let error = execute(&engine, Method::Cancel, None, vec![transaction_id])
+            .await
+            .unwrap_err();
+        assert_eq!(
+            error,
+            "Transaction expired after reaching its maximum lifetime of 50ms"
+        );
+
+        engine.close().await.unwrap();
+    });
+}
+
+#[test]
+fn active_transaction_does_not_expire() {
+    block_on(async {
+        let engine = connect_with_limit("idle_timeout", Duration::from_secs(60)).await;
+
+        let transaction_id = execute(&engine, Method::Begin, None, vec![]).await.unwrap();
+
+        execute(&engine, Method::Commit, None, vec![transaction_id])
+            .await
+            .unwrap();
+
+        engine.close().await.unwrap();
+    });
+}
+
+#[test]
+fn transaction_does_not_expire_while_in_use() {
+    block_on(async {
+        let engine = connect_with_limit("idle_timeout", Duration::from_millis(100)).await;
+
+        let Value::Uuid(transaction_id) =
+            execute(&engine, Method::Begin, None, vec![]).await.unwrap()
+        else {
+            panic!("Expected a transaction id");
+        };
+
+        // Runs for longer than the idle timeout
+        let params = encode(Value::Array(Array::from(vec![Value::String(
+            "SLEEP 500ms;".to_string(),
+        )])))
+        .unwrap();
+        engine
+            .execute(
+                Method::Query,
+                None,
+                Some(transaction_id.into_inner()),
+                params,
+            )
+            .await
+            .unwrap();
+
+        execute(
+            &engine,
+            Method::Commit,
+            None,
+            vec![Value::Uuid(transaction_id)],
+        )
+        .await
+        .unwrap();
+
+        engine.close().await.unwrap();
+    });
+}
+
+#[test]
+fn transaction_expires_after_max_lifetime_once_no_longer_in_use() {
+    block_on(async {
+        let engine = connect_with_limit("max_lifetime", Duration::from_millis(50)).await;
+
+        let Value::Uuid(transaction_id) =
+            execute(&engine, Method::Begin, None, vec![]).await.unwrap()
+        else {
+            panic!("Expected a transaction id");
+        };
+
+        // Runs for longer than the maximum lifetime
+        let params = encode(Value::Array(Array::from(vec![Value::String(
+            "SLEEP 300ms; RETURN 1;".to_string(),
+        )])))
+        .unwrap();
+        let output = engine
+            .execute(
+                Method::Query,
+                None,
+                Some(transaction_id.into_inner()),
+                params,
+            )
+            .await
+            .unwrap();
+        let Value::Array(results) = decode(&output).unwrap() else {
+            panic!("Expected query results");
+        };
+        assert!(results.iter().all(|result| matches!(
+            result,
+            Value::Object(result) if result.get("status") == Some(&Value::String("OK".to_string()))
+        )));
+
+        tokio::time::sleep(Duration::from_millis(300)).await;
+
+        let error = execute(
+            &engine,
+            Method::Commit,
+            None,
+            vec![Value::Uuid(transaction_id)],
+        )
+        .await
+        .unwrap_err();
+        assert_eq!(
+            error,
+            "Transaction expired after reaching its maximum lifetime of 50ms"
+        );
+
+        engine.close().await.unwrap();
+    });
+}
+
+#[test]
+fn read_only_transaction_rejects_writes() {
+    block_on(async {
+        let engine = connect_with_limit("idle_timeout", Duration::from_secs(60)).await;
+        execute(
+            &engine,
+            Method::Use,
+            None,
+            vec![
+                Value::String("test".to_string()),
+                Value::String("test".to_string()),
+            ],
+        )
+        .await
+        .unwrap();
+
+        let mut options = Object::new();
+        options.insert("read_only".to_string(), Value::Bool(true));
+        let Value::Uuid(transaction_id) =
+            execute(&engine, Method::Begin, None, vec![Value::Object(options)])
+                .await
+                .unwrap()
+        else {
+            panic!("Expected a transaction id");
+        };
+
+        let params = encode(Value::Array(Array::from(vec![Value::String(
+            "CREATE person:one;".to_string(),
+        )])))
+        .unwrap();
+        let output = engine
+            .execute(
+                Method::Query,
+                None,
+                Some(transaction_id.into_inner()),
+                params,
+            )
+            .await
+            .unwrap();
+        let Value::Array(results) = decode(&output).unwrap() else {
+            panic!("Expected query results");
+        };
+        let Some(Value::Object(result)) = results.first() else {
+            panic!("Expected a query result");
+        };
+        assert_eq!(
+            result.get("status"),
+            Some(&Value::String("ERR".to_string()))
+        );
+
+        engine.close().await.unwrap();
+    });
+}
+
+#[test]
+fn detach_cancels_session_transactions() {
+    block_on(async {
+        let engine = connect_with_limit("idle_timeout", Duration::from_secs(60)).await;
+
+        let session_id = Uuid::now_v7();
+        execute(&engine, Method::Attach, Some(session_id), vec![])
+            .await
+            .unwrap();
+
+        let mut options = Object::new();
+        options.insert("pessimistic".to_string(), Value::Bool(true));
+        let transaction_id = execute(
+            &engine,
+            Method::Begin,
+            Some(session_id),
+            vec![Value::Object(options)],
+        )
+        .await
+        .unwrap();
+
+        execute(&engine, Method::Detach, Some(session_id), vec![])
+            .await
+            .unwrap();
+
+        let error = execute(&engine, Method::Commit, None, vec![transaction_id])
+            .await
+            .unwrap_err();
+        assert_eq!(error, "Transaction not found");
+
+        engine.close().await.unwrap();
+    });
+}
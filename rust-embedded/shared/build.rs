@@ -1,9 +1,20 @@
 use std::{error::Error, time::Duration};
 
+const ENGINE_CRATES: [(&str, &str, &str); 3] = [
+    ("memory", "surreal_memory", "SurrealDb.Embedded.InMemory"),
+    ("rocksdb", "surreal_rocksdb", "SurrealDb.Embedded.RocksDb"),
+    (
+        "surrealkv",
+        "surreal_surrealkv",
+        "SurrealDb.Embedded.SurrealKv",
+    ),
+];
+
 fn main() -> Result<(), Box<dyn Error>> {
-    generate_csharp_file("surreal_memory", "SurrealDb.Embedded.InMemory")?;
-    generate_csharp_file("surreal_rocksdb", "SurrealDb.Embedded.RocksDb")?;
-    generate_csharp_file("surreal_surrealkv", "SurrealDb.Embedded.SurrealKv")?;
+    for (crate_dir, dll_name, csharp_project_name) in ENGINE_CRATES {
+        generate_csharp_file(dll_name, csharp_project_name)?;
+        check_exported_symbols(crate_dir, csharp_project_name)?;
+    }
 
     write_surreal_version()?;
 
@@ -18,9 +29,15 @@ fn generate_csharp_file(dll_name: &str, csharp_project_name: &str) -> Result<(),
         .input_extern_file("src/bindgen/byte_buffer.rs")
         .input_extern_file("src/bindgen/callback.rs")
         .input_extern_file("src/bindgen/free.rs")
+        .input_extern_file("src/bindgen/pool.rs")
         .input_extern_file("src/models/method.rs")
         .input_extern_file("src/runtime/engines.rs")
+        .input_extern_file("src/runtime/logs.rs")
+        .input_extern_file("src/runtime/metrics.rs")
         .input_extern_file("src/runtime/mod.rs")
+        .input_extern_file("src/runtime/notifications.rs")
+        .input_extern_file("src/runtime/requests.rs")
+        .input_extern_file("src/runtime/servers.rs")
         .csharp_dll_name(dll_name)
         .csharp_namespace("SurrealDb.Embedded.Internals")
         .generate_csharp_file(format!("../../{}/NativeMethods.g.cs", csharp_project_name))?;
@@ -28,6 +45,55 @@ fn generate_csharp_file(dll_name: &str, csharp_project_name: &str) -> Result<(),
     Ok(())
 }
 
+/// Ensures every entry point generated by csbindgen is exported by the engine crate,
+/// i.e. listed in `EXPORTED_SYMBOLS` and exposed via the `export_ffi!` macro.
+fn check_exported_symbols(
+    crate_dir: &str,
+    csharp_project_name: &str,
+) -> Result<(), Box<dyn Error>> {
+    let exports = std::fs::read_to_string("src/exports.rs")?;
+    let exported_symbols = extract_quoted_after(&exports, "ExportedSymbol(");
+
+    let generated =
+        std::fs::read_to_string(format!("../../{}/NativeMethods.g.cs", csharp_project_name))?;
+    let missing_symbols = extract_quoted_after(&generated, "EntryPoint =")
+        .into_iter()
+        .filter(|entry_point| !exported_symbols.contains(entry_point))
+        .collect::<Vec<_>>();
+
+    if !missing_symbols.is_empty() {
+        return Err(format!(
+            "Symbols generated by csbindgen are missing from EXPORTED_SYMBOLS: {}",
+            missing_symbols.join(", ")
+        )
+        .into());
+    }
+
+    let lib = std::fs::read_to_string(format!("../{}/src/lib.rs", crate_dir))?;
+    if !lib.contains("shared::export_ffi!();") {
+        return Err(format!(
+            "Engine crate \"{}\" must call `shared::export_ffi!();` in its lib.rs",
+            crate_dir
+        )
+        .into());
+    }
+
+    Ok(())
+}
+
+/// Extracts every string literal directly following the given prefix.
+fn extract_quoted_after<'a>(input: &'a str, prefix: &str) -> Vec<&'a str> {
+    input
+        .match_indices(prefix)
+        .filter_map(|(index, _)| {
+            let rest = input[index + prefix.len()..]
+                .trim_start()
+                .strip_prefix('"')?;
+            rest.find('"').map(|end| &rest[..end])
+        })
+        .collect()
+}
+
 fn write_surreal_version() -> Result<(), Box<dyn Error>> {
     let lock_file = include_str!("../Cargo.lock");
     let lock: cargo_lock::Lockfile = lock_file.parse().expect("Failed to parse Cargo.lock");
@@ -0,0 +1,156 @@
+use anyhow::anyhow;
+use std::path::{Path, PathBuf};
+use surrealdb::kvs::{Datastore, Key, LockType, TransactionType};
+
+use crate::models::error::EmbeddedError;
+
+/// Number of key-value pairs copied by each transaction of a backup
+const BACKUP_BATCH_SIZE: u32 = 1000;
+
+/// Prefixes of the keys describing the cluster rather than the data: nodes (`/!nd`), their heartbeats (`/!hb`),
+/// task leases (`/!tl`) and the keys scoped to a node (`/$`), such as its live queries.
+/// They belong to the source datastore, the backup registering a node of its own.
+const CLUSTER_KEY_PREFIXES: [&[u8]; 4] = [b"/!nd", b"/!hb", b"/!tl", b"/$"];
+
+fn is_cluster_key(key: &[u8]) -> bool {
+    CLUSTER_KEY_PREFIXES
+        .iter()
+        .any(|prefix| key.starts_with(prefix))
+}
+
+/// Storage engine and directory of an endpoint, if stored on disk.
+/// Paths are resolved the same way as by the datastore, ignoring query parameters.
+fn storage_endpoint(endpoint: &str) -> Option<(&str, PathBuf)> {
+    let endpoint = endpoint.split_once('?').map_or(endpoint, |(path, _)| path);
+    let (flavour, path) = endpoint
+        .split_once("://")
+        .or_else(|| endpoint.split_once(':'))?;
+
+    match flavour {
+        "rocksdb" | "surrealkv" if path.starts_with('/') => Some((
+            flavour,
+            PathBuf::from(format!("/{}", path.trim_start_matches('/'))),
+        )),
+        "rocksdb" | "surrealkv" if !path.is_empty() => Some((flavour, PathBuf::from(path))),
+        _ => None,
+    }
+}
+
+fn unsupported() -> EmbeddedError {
+    EmbeddedError::invalid_params("Backups are only supported by RocksDB and SurrealKV engines")
+}
+
+/// Copies every key of a datastore (given its endpoint) into a new datastore of the same kind,
+/// created in the target directory, which must not exist or be empty.
+/// Cluster keys (nodes, heartbeats, leases) are left out, so the backup only knows about its own node.
+/// Keys are read from a single snapshot, so that the backup is consistent without blocking writers.
+pub(super) async fn backup(kvs: &Datastore, endpoint: &str, target: &Path) -> anyhow::Result<()> {
+    let (flavour, _) = storage_endpoint(endpoint).ok_or_else(unsupported)?;
+
+    if let Ok(mut entries) = tokio::fs::read_dir(target).await
+        && entries.next_entry().await?.is_some()
+    {
+        return Err(EmbeddedError::invalid_params(format!(
+            "Backup directory {} is not empty",
+            target.display()
+        ))
+        .into());
+    }
+
+    let destination = Datastore::new(&format!("{}://{}", flavour, target.display())).await?;
+    // Registers a node, which the datastore removes again on shutdown
+    destination.bootstrap().await?;
+    let result = copy_keys(kvs, &destination).await;
+    destination.shutdown().await?;
+
+    result
+}
+
+async fn copy_keys(source: &Datastore, destination: &Datastore) -> anyhow::Result<()> {
+    let snapshot = source
+        .transaction(TransactionType::Read, LockType::Optimistic)
+        .await?;
+
+    let result = async {
+        let mut next = Some(Key::new()..vec![0xff]);
+        while let Some(range) = next {
+            let batch = snapshot
+                .batch_keys_vals(range, BACKUP_BATCH_SIZE, None)
+                .await?;
+            next = batch.next;
+
+            let tx = destination
+                .transaction(TransactionType::Write, LockType::Optimistic)
+                .await?;
+            for (key, val) in batch.result {
+                if is_cluster_key(&key) {
+                    continue;
+                }
+                if let Err(error) = tx.set(&key, &val, None).await {
+                    let _ = tx.cancel().await;
+                    return Err(error);
+                }
+            }
+            tx.commit().await?;
+        }
+        Ok(())
+    }
+    .await;
+
+    snapshot.cancel().await?;
+    result
+}
+
+/// Replaces the datastore of an endpoint with a backup directory, before the datastore is opened.
+/// The backup is copied next to the datastore first, so that a failed copy leaves the datastore untouched.
+pub(super) async fn restore(endpoint: &str, source: &Path) -> anyhow::Result<()> {
+    let (_, path) = storage_endpoint(endpoint).ok_or_else(unsupported)?;
+
+    if !tokio::fs::metadata(source)
+        .await
+        .is_ok_and(|metadata| metadata.is_dir())
+    {
+        return Err(EmbeddedError::not_found(format!(
+            "Backup directory {} not found",
+            source.display()
+        ))
+        .into());
+    }
+
+    let file_name = path
+        .file_name()
+        .ok_or_else(|| anyhow!("Invalid datastore path {}", path.display()))?;
+    let mut staging_name = file_name.to_os_string();
+    staging_name.push(".restoring");
+    let staging = path.with_file_name(staging_name);
+
+    if tokio::fs::try_exists(&staging).await? {
+        tokio::fs::remove_dir_all(&staging).await?;
+    }
+    copy_dir(source, &staging).await?;
+
+    if tokio::fs::try_exists(&path).await? {
+        tokio::fs::remove_dir_all(&path).await?;
+    }
+    tokio::fs::rename(&staging, &path).await?;
+
+    Ok(())
+}
+
+async fn copy_dir(source: &Path, destination: &Path) -> anyhow::Result<()> {
+    let mut pending = vec![(source.to_path_buf(), destination.to_path_buf())];
+    while let Some((source, destination)) = pending.pop() {
+        tokio::fs::create_dir_all(&destination).await?;
+
+        let mut entries = tokio::fs::read_dir(&source).await?;
+        while let Some(entry) = entries.next_entry().await? {
+            let target = destination.join(entry.file_name());
+            if entry.file_type().await?.is_dir() {
+                pending.push((entry.path(), target));
+            } else {
+                tokio::fs::copy(entry.path(), target).await?;
+            }
+        }
+    }
+    Ok(())
+}
@@ -1,19 +1,38 @@
-use anyhow::anyhow;
+use anyhow::{Context, anyhow};
+use bytes::Bytes;
 use dashmap::DashMap;
+use futures::{Stream, StreamExt};
 use std::collections::BTreeMap;
+use std::path::PathBuf;
 use std::sync::Arc;
 use surrealdb::dbs::Session;
+use surrealdb::dbs::capabilities::MethodTarget;
 use surrealdb::kvs::export::Config;
-use surrealdb::kvs::{self, Datastore, LockType, Transaction, TransactionType};
+use surrealdb::kvs::{self, Datastore, LockType, TransactionType};
 use surrealdb::rpc::format::cbor::{decode, encode};
 use surrealdb::rpc::{self, DbResult, Method, RpcProtocol};
-use surrealdb_types::{Array, HashMap, SurrealValue, Value};
-use tokio::sync::RwLock;
+use surrealdb_types::{Action, Array, HashMap, Notification, SurrealValue, Value};
+use tokio::sync::{RwLock, RwLockReadGuard};
+use tokio::task::JoinHandle;
+use tokio_util::io::ReaderStream;
 use uuid::Uuid;
 
 use crate::models::connection_options::ConnectionOptions;
-
-pub struct SurrealEmbeddedEngines(RwLock<BTreeMap<i32, SurrealEmbeddedEngine>>);
+use crate::models::error::EmbeddedError;
+use crate::models::transaction_options::TransactionOptions;
+use crate::runtime::get_global_runtime;
+use crate::runtime::logs::with_engine_id;
+use crate::runtime::metrics::EngineMetrics;
+use crate::runtime::notifications::send_notification;
+use strict::ScriptStatement;
+use transactions::{Transactions, reap_transactions};
+
+mod backup;
+pub mod server;
+mod strict;
+mod transactions;
+
+pub struct SurrealEmbeddedEngines(RwLock<BTreeMap<i32, Arc<SurrealEmbeddedEngine>>>);
 
 impl SurrealEmbeddedEngines {
     pub fn new() -> Self {
@@ -26,44 +45,111 @@ impl SurrealEmbeddedEngines {
         method: Method,
         session_id: Option<Uuid>,
         transaction_id: Option<Uuid>,
-        params: Vec<u8>,
+        params: impl AsRef<[u8]>,
     ) -> anyhow::Result<Vec<u8>> {
-        let read_lock = self.0.read().await;
-        let Some(engine) = read_lock.get(&id) else {
-            return Err(anyhow!("Engine not found"));
-        };
+        let engine = self.get(id).await?;
         engine
             .execute(method, session_id, transaction_id, params)
             .await
     }
 
-    pub async fn import(&self, id: i32, input: String) -> anyhow::Result<()> {
-        let read_lock = self.0.read().await;
-        let Some(engine) = read_lock.get(&id) else {
-            return Err(anyhow!("Engine not found"));
-        };
-        engine.import(input).await
+    pub async fn import(
+        &self,
+        id: i32,
+        session_id: Option<Uuid>,
+        transaction_id: Option<Uuid>,
+        input: String,
+    ) -> anyhow::Result<()> {
+        let engine = self.get(id).await?;
+        engine.import(session_id, transaction_id, input).await
     }
 
-    pub async fn export(&self, id: i32, params: Vec<u8>) -> anyhow::Result<Vec<u8>> {
-        let read_lock = self.0.read().await;
-        let Some(engine) = read_lock.get(&id) else {
-            return Err(anyhow!("Engine not found"));
-        };
-        engine.export(params).await
+    pub async fn import_file(
+        &self,
+        id: i32,
+        session_id: Option<Uuid>,
+        transaction_id: Option<Uuid>,
+        path: PathBuf,
+    ) -> anyhow::Result<()> {
+        let engine = self.get(id).await?;
+        engine.import_file(session_id, transaction_id, path).await
+    }
+
+    pub async fn export(
+        &self,
+        id: i32,
+        session_id: Option<Uuid>,
+        params: Vec<u8>,
+    ) -> anyhow::Result<Vec<u8>> {
+        let engine = self.get(id).await?;
+        engine.export(session_id, params).await
+    }
+
+    pub async fn export_stream(
+        &self,
+        id: i32,
+        session_id: Option<Uuid>,
+        params: Vec<u8>,
+        on_chunk: impl FnMut(Vec<u8>),
+    ) -> anyhow::Result<()> {
+        let engine = self.get(id).await?;
+        engine.export_stream(session_id, params, on_chunk).await
+    }
+
+    pub async fn backup(&self, id: i32, target: PathBuf) -> anyhow::Result<()> {
+        let engine = self.get(id).await?;
+        engine.backup(target).await
+    }
+
+    /// Gets a SurrealDB engine (given its id), without holding the engines while using it.
+    pub async fn get(&self, id: i32) -> Result<Arc<SurrealEmbeddedEngine>, EmbeddedError> {
+        self.0
+            .read()
+            .await
+            .get(&id)
+            .cloned()
+            .ok_or_else(EmbeddedError::engine_missing)
     }
 
     pub async fn insert(
         &self,
         id: i32,
         engine: SurrealEmbeddedEngine,
-    ) -> Option<SurrealEmbeddedEngine> {
-        self.0.write().await.insert(id, engine)
+    ) -> Option<Arc<SurrealEmbeddedEngine>> {
+        self.0.write().await.insert(id, Arc::new(engine))
     }
 
-    pub async fn remove(&self, id: i32) -> Option<SurrealEmbeddedEngine> {
+    pub async fn contains(&self, id: i32) -> bool {
+        self.0.read().await.contains_key(&id)
+    }
+
+    /// Current state of every engine, along with its id
+    pub async fn metrics(&self) -> Vec<(i32, EngineMetrics)> {
+        let engines: Vec<_> = self
+            .0
+            .read()
+            .await
+            .iter()
+            .map(|(id, engine)| (*id, engine.clone()))
+            .collect();
+        let mut metrics = Vec::with_capacity(engines.len());
+        for (id, engine) in engines {
+            if let Some(engine_metrics) = engine.metrics().await {
+                metrics.push((id, engine_metrics));
+            }
+        }
+        metrics
+    }
+
+    pub async fn remove(&self, id: i32) -> Option<Arc<SurrealEmbeddedEngine>> {
         self.0.write().await.remove(&id)
     }
+
+    pub async fn remove_all(&self) -> Vec<Arc<SurrealEmbeddedEngine>> {
+        std::mem::take(&mut *self.0.write().await)
+            .into_values()
+            .collect()
+    }
 }
 
 impl Default for SurrealEmbeddedEngines {
@@ -72,24 +158,46 @@ impl Default for SurrealEmbeddedEngines {
     }
 }
 
-pub struct SurrealEmbeddedEngine(RwLock<SurrealEmbeddedEngineInner>);
+/// A SurrealDB engine, whose state is dropped once closed.
+pub struct SurrealEmbeddedEngine(RwLock<Option<SurrealEmbeddedEngineInner>>);
 
 impl SurrealEmbeddedEngine {
+    /// State of the engine, shared by the requests being executed on it.
+    async fn inner(
+        &self,
+    ) -> Result<RwLockReadGuard<'_, SurrealEmbeddedEngineInner>, EmbeddedError> {
+        RwLockReadGuard::try_map(self.0.read().await, Option::as_ref)
+            .map_err(|_| EmbeddedError::engine_missing())
+    }
+
     pub async fn execute(
         &self,
         method: Method,
         session_id: Option<Uuid>,
         transaction_id: Option<Uuid>,
-        params: Vec<u8>,
+        params: impl AsRef<[u8]>,
     ) -> anyhow::Result<Vec<u8>> {
-        let params =
-            crate::cbor::get_params(params).map_err(|_| anyhow!("Failed to deserialize params"))?;
-        let rpc = self.0.read().await;
-        let res = RpcProtocol::execute(&*rpc, transaction_id, session_id, method, params).await?;
-        encode(res.into_value())
+        let params = crate::cbor::get_params(params.as_ref())
+            .map_err(|_| EmbeddedError::deserialization("Failed to deserialize params"))?;
+        let rpc = self.inner().await?;
+        let res = rpc
+            .execute_method(method, session_id, transaction_id, params)
+            .await?;
+        encode(res)
+    }
+
+    /// Current state of the engine, unless closed
+    pub async fn metrics(&self) -> Option<EngineMetrics> {
+        let rpc = self.inner().await.ok()?;
+        Some(EngineMetrics {
+            sessions: rpc.sessions.len(),
+            transactions: rpc.transactions.len(),
+            live_queries: rpc.live_queries.len(),
+        })
     }
 
     pub async fn connect(
+        id: i32,
         endpoint: String,
         options: Vec<u8>,
     ) -> anyhow::Result<SurrealEmbeddedEngine> {
@@ -98,72 +206,605 @@ impl SurrealEmbeddedEngine {
             s => s,
         };
 
-        let in_options = decode(&options)?;
-        let options = ConnectionOptions::try_from(&in_options).map_err(|e| anyhow!(e))?;
+        let options = ConnectionOptions::from_bytes(&options)?;
+
+        let capabilities = options.capabilities()?;
+        let auth = options.auth.unwrap_or_default();
+
+        if let Some(source) = &options.restore {
+            backup::restore(endpoint, source)
+                .await
+                .context("Cannot restore the backup")?;
+        }
 
         let kvs = Datastore::new(endpoint)
             .await?
             .with_notifications()
-            .with_capabilities(
-                options
-                    .capabilities
-                    .map_or(Ok(Default::default()), |a| a.try_into())?,
-            );
+            .with_auth_enabled(auth.enabled.unwrap_or_default())
+            .with_capabilities(capabilities);
+
+        // Registers the node, which is removed again when the engine is closed
+        kvs.bootstrap().await?;
+
+        if let (Some(username), Some(password)) = (&auth.username, &auth.password) {
+            kvs.initialise_credentials(username, password).await?;
+        }
+
+        let live_queries = Arc::new(DashMap::new());
+        let notifications = kvs.notifications().map(|channel| {
+            tokio::spawn(with_engine_id(
+                id,
+                forward_notifications(id, channel, live_queries.clone()),
+            ))
+        });
+
+        let transactions = Arc::new(Transactions::default());
+        let reaper = options.transactions.map(|config| {
+            tokio::spawn(with_engine_id(
+                id,
+                reap_transactions(transactions.clone(), config),
+            ))
+        });
+
+        tracing::info!(endpoint, "Engine connected");
 
         let inner = SurrealEmbeddedEngineInner {
             kvs,
+            endpoint: endpoint.to_string(),
+            strict: options.strict.unwrap_or_default(),
             sessions: HashMap::new(),
-            transactions: DashMap::new(),
+            transactions,
+            reaper,
+            live_queries,
+            notifications,
         };
         // Store the default session with None key
-        let session = Session::default();
+        let session = Session::default().with_rt(SurrealEmbeddedEngineInner::LQ_SUPPORT);
         inner.sessions.insert(None, Arc::new(RwLock::new(session)));
 
-        Ok(SurrealEmbeddedEngine(RwLock::new(inner)))
+        Ok(SurrealEmbeddedEngine(RwLock::new(Some(inner))))
+    }
+
+    pub async fn export(
+        &self,
+        session_id: Option<Uuid>,
+        config: Vec<u8>,
+    ) -> anyhow::Result<Vec<u8>> {
+        let mut buffer = Vec::new();
+        self.export_stream(session_id, config, |chunk| buffer.push(chunk))
+            .await?;
+
+        let result = String::from_utf8(buffer.concat()).map_err(|e| anyhow!(e))?;
+        encode(result.into_value())
     }
 
-    pub async fn export(&self, config: Vec<u8>) -> anyhow::Result<Vec<u8>> {
-        let (tx, rx) = channel::unbounded();
+    pub async fn export_stream(
+        &self,
+        session_id: Option<Uuid>,
+        config: Vec<u8>,
+        mut on_chunk: impl FnMut(Vec<u8>),
+    ) -> anyhow::Result<()> {
+        // A bounded channel prevents the datastore from producing chunks faster than they are consumed
+        let (tx, rx) = channel::bounded(EXPORT_STREAM_CAPACITY);
 
-        let inner = self.0.read().await;
+        let inner = self.inner().await?;
 
         let in_config = decode(&config)?;
         let config = Config::from_value(in_config)?;
 
-        let lock = inner.get_session(&None)?;
+        let lock = inner.get_session(&session_id)?;
         let session = lock.read().await;
 
-        inner
-            .kvs
-            .export_with_config(&session, tx, config)
-            .await?
-            .await?;
+        let export = inner.kvs.export_with_config(&session, tx, config).await?;
+        let forward = async {
+            while let Ok(chunk) = rx.recv().await {
+                on_chunk(chunk);
+            }
+        };
 
-        let mut buffer = Vec::new();
-        while let Ok(item) = rx.try_recv() {
-            buffer.push(item);
+        let (result, _) = tokio::join!(export, forward);
+        result
+    }
+
+    /// Backs the datastore up into a new RocksDB or SurrealKV datastore (same kind as this engine),
+    /// created in the target directory. Writers are not blocked while the backup is in progress.
+    pub async fn backup(&self, target: PathBuf) -> anyhow::Result<()> {
+        let inner = self.inner().await?;
+        backup::backup(&inner.kvs, &inner.endpoint, &target).await
+    }
+
+    /// Imports a SurrealQL script, within the given transaction if any.
+    pub async fn import(
+        &self,
+        session_id: Option<Uuid>,
+        transaction_id: Option<Uuid>,
+        input: String,
+    ) -> anyhow::Result<()> {
+        let inner = self.inner().await?;
+
+        let lock = inner.get_session(&session_id)?;
+        let session = lock.write().await;
+
+        if inner.strict {
+            inner.ensure_script_is_defined(&session, &input).await?;
         }
 
-        let result = String::from_utf8(buffer.concat()).map_err(|e| anyhow!(e))?;
-        encode(result.into_value())
+        match transaction_id {
+            Some(transaction_id) => {
+                let _busy = inner.transactions.mark_busy(transaction_id);
+                let tx = inner.get_tx(transaction_id).await?;
+                inner
+                    .kvs
+                    .execute_with_transaction(&input, &session, None, tx)
+                    .await?;
+            }
+            None => {
+                inner.kvs.import(&input, &session).await?;
+            }
+        }
+
+        Ok(())
     }
 
-    pub async fn import(&self, input: String) -> anyhow::Result<()> {
-        let inner = self.0.read().await;
+    /// Imports a SurrealQL file, streamed as it is read, with the restrictions of a streamed import.
+    pub async fn import_file(
+        &self,
+        session_id: Option<Uuid>,
+        transaction_id: Option<Uuid>,
+        path: PathBuf,
+    ) -> anyhow::Result<()> {
+        let file = tokio::fs::File::open(&path)
+            .await
+            .map_err(|e| anyhow!("Cannot open file {}: {}", path.display(), e))?;
+        let stream = ReaderStream::new(file).map(|chunk| chunk.map_err(anyhow::Error::from));
 
-        let lock = inner.get_session(&None)?;
+        self.import_stream(session_id, transaction_id, stream).await
+    }
+
+    /// Imports a streamed SurrealQL script.
+    /// A streamed script cannot be imported within a transaction, as it would have to be buffered as a whole.
+    /// For the same reason, it cannot be imported in strict mode, where its statements are checked beforehand.
+    pub async fn import_stream(
+        &self,
+        session_id: Option<Uuid>,
+        transaction_id: Option<Uuid>,
+        stream: impl Stream<Item = anyhow::Result<Bytes>>,
+    ) -> anyhow::Result<()> {
+        ensure_streamed_import(transaction_id)?;
+
+        let inner = self.inner().await?;
+
+        if inner.strict {
+            return Err(EmbeddedError::invalid_params(
+                "A streamed import cannot run in strict mode",
+            )
+            .into());
+        }
+
+        let lock = inner.get_session(&session_id)?;
         let session = lock.write().await;
 
-        inner.kvs.import(&input, &session).await?;
+        inner.kvs.import_stream(&session, stream).await?;
+
+        Ok(())
+    }
+
+    /// Kills every live query of this engine and stops forwarding notifications.
+    /// Closes the engine, releasing the underlying storage once done.
+    /// Waits for the requests being executed on the engine to complete, later requests fail as if the engine was missing.
+    pub async fn close(&self) -> anyhow::Result<()> {
+        let Some(inner) = self.0.write().await.take() else {
+            return Ok(());
+        };
+
+        inner.cleanup_all_lqs().await;
+
+        if let Some(notifications) = &inner.notifications {
+            notifications.abort();
+        }
+
+        if let Some(reaper) = &inner.reaper {
+            reaper.abort();
+        }
+
+        // Cancel open transactions, so they do not hold storage locks during the shutdown
+        for transaction in inner.transactions.drain() {
+            let _ = transaction.cancel().await;
+        }
+
+        inner.kvs.shutdown().await?;
+        tracing::info!("Engine closed");
 
+        drop(inner);
         Ok(())
     }
 }
 
+/// A chunked import of a SurrealDB engine, fed by C# and consumed by the datastore.
+/// The import holds the engine itself rather than the engines, so other engines can be opened or disposed meanwhile.
+pub struct ImportSession {
+    engine_id: i32,
+    writer: ImportWriter,
+    task: JoinHandle<anyhow::Result<()>>,
+}
+
+impl ImportSession {
+    pub fn begin(
+        engine_id: i32,
+        session_id: Option<Uuid>,
+        transaction_id: Option<Uuid>,
+    ) -> Result<Self, EmbeddedError> {
+        ensure_streamed_import(transaction_id)?;
+        let runtime = get_global_runtime()?;
+
+        // A bounded channel prevents C# from writing chunks faster than they are imported
+        let (sender, receiver) = channel::bounded(IMPORT_STREAM_CAPACITY);
+
+        let task = runtime.spawn(async move {
+            let engine = crate::runtime::engines::ENGINES.get(engine_id).await?;
+            engine
+                .import_stream(session_id, transaction_id, receiver)
+                .await
+        });
+
+        Ok(Self {
+            engine_id,
+            writer: ImportWriter(sender),
+            task,
+        })
+    }
+
+    pub fn engine_id(&self) -> i32 {
+        self.engine_id
+    }
+
+    pub fn writer(&self) -> ImportWriter {
+        self.writer.clone()
+    }
+
+    /// Stops the import, without waiting for the remaining chunks.
+    pub fn abort(self) {
+        self.task.abort();
+    }
+
+    /// Marks the end of the SurrealQL script and waits for the import to complete.
+    pub async fn finish(self) -> anyhow::Result<()> {
+        self.writer.0.close();
+        self.task.await?
+    }
+}
+
+#[derive(Clone)]
+pub struct ImportWriter(channel::Sender<anyhow::Result<Bytes>>);
+
+impl ImportWriter {
+    /// Queues a chunk of the SurrealQL script, waiting if too many chunks are pending.
+    pub async fn write(&self, chunk: Vec<u8>) -> anyhow::Result<()> {
+        self.0
+            .send(Ok(Bytes::from(chunk)))
+            .await
+            .map_err(|_| anyhow!("Import has already ended"))
+    }
+}
+
+/// Rejects a streamed import within a transaction, which the datastore only supports for a whole script.
+fn ensure_streamed_import(transaction_id: Option<Uuid>) -> Result<(), EmbeddedError> {
+    match transaction_id {
+        Some(_) => Err(EmbeddedError::invalid_params(
+            "A streamed import cannot run within a transaction",
+        )),
+        None => Ok(()),
+    }
+}
+
+/// Drains the notification channel of a datastore and forwards each notification
+/// to C#, along with the id of the session that started the live query.
+async fn forward_notifications(
+    id: i32,
+    channel: channel::Receiver<Notification>,
+    live_queries: Arc<DashMap<Uuid, Option<Uuid>>>,
+) {
+    while let Ok(mut notification) = channel.recv().await {
+        let lqid = notification.id.into_inner();
+        // Ignore notifications of live queries that are no longer tracked (e.g. killed)
+        let Some(session_id) = live_queries.get(&lqid).map(|session_id| *session_id) else {
+            continue;
+        };
+        notification.session = session_id.map(surrealdb_types::Uuid::from);
+
+        // Live queries killed by a statement (rather than the "kill" method) are untracked here
+        if notification.action == Action::Killed {
+            live_queries.remove(&lqid);
+        }
+
+        if let Ok(bytes) = encode(notification.into_value()) {
+            send_notification(id, bytes);
+        }
+    }
+}
+
 struct SurrealEmbeddedEngineInner {
     pub kvs: Datastore,
+    /// endpoint the datastore was opened with, e.g. "rocksdb://path/to/db"
+    pub endpoint: String,
+    /// Rejects undefined namespaces and databases, instead of creating them,
+    /// whether switched to by the "use" method or by the `USE` statements of queries and imports.
+    /// Databases must be defined as `STRICT`, so undefined tables are rejected as well.
+    pub strict: bool,
     pub sessions: HashMap<Option<Uuid>, Arc<RwLock<Session>>>,
-    pub transactions: DashMap<Uuid, Arc<Transaction>>,
+    pub transactions: Arc<Transactions>,
+    /// Cancels expired transactions, if limits are configured
+    pub reaper: Option<JoinHandle<()>>,
+    /// Live query ids, with the id of the session that started them
+    pub live_queries: Arc<DashMap<Uuid, Option<Uuid>>>,
+    pub notifications: Option<JoinHandle<()>>,
+}
+
+impl SurrealEmbeddedEngineInner {
+    async fn execute_method(
+        &self,
+        method: Method,
+        session_id: Option<Uuid>,
+        transaction_id: Option<Uuid>,
+        params: Array,
+    ) -> anyhow::Result<Value> {
+        if self.strict {
+            match (method, params.first()) {
+                (Method::Use, _) => self.ensure_use_is_defined(session_id, &params).await?,
+                (Method::Query, Some(Value::String(query))) => {
+                    let session = self.get_session(&session_id)?.read().await.clone();
+                    self.ensure_script_is_defined(&session, query).await?;
+                }
+                _ => {}
+            }
+        }
+        // A transaction does not expire as idle while a request runs within it
+        let _busy = transaction_id.map(|id| self.transactions.mark_busy(id));
+        let res = match method {
+            // The options of the "begin" method are not forwarded by the RPC protocol
+            Method::Begin => self.begin_with_options(session_id, &params).await,
+            Method::Kill => self.kill(session_id, transaction_id, params).await,
+            _ => RpcProtocol::execute(self, transaction_id, session_id, method, params).await,
+        };
+        Ok(res?.into_value())
+    }
+
+    /// The "use" method implicitly creates the namespace and database it switches to,
+    /// or those configured as defaults when called without arguments on a session using no namespace.
+    /// In strict mode, both must have been defined beforehand, and defaults are rejected.
+    async fn ensure_use_is_defined(
+        &self,
+        session_id: Option<Uuid>,
+        params: &Array,
+    ) -> anyhow::Result<()> {
+        let session = self.get_session(&session_id)?.read().await.clone();
+
+        let mut args = params.iter();
+        let (ns_arg, db_arg) = (args.next(), args.next());
+
+        let (ns, db) = match (ns_arg, db_arg) {
+            (None | Some(Value::None), None | Some(Value::None)) if session.ns.is_none() => {
+                return Err(EmbeddedError::invalid_params(
+                    "The default namespace and database cannot be used in strict mode",
+                )
+                .into());
+            }
+            (Some(Value::String(ns)), _) => {
+                self.ensure_namespace_is_defined(ns).await?;
+                (Some(ns), db_arg)
+            }
+            (Some(Value::Null), _) => (None, db_arg),
+            _ => (session.ns.as_ref(), db_arg),
+        };
+
+        let db = match db {
+            Some(Value::String(db)) => Some(db),
+            Some(Value::Null) => None,
+            _ => session.db.as_ref(),
+        };
+
+        // Switching to another namespace keeps the database, which must be defined in it too
+        if let (Some(ns), Some(db)) = (ns, db) {
+            self.ensure_database_is_defined(ns, db).await?;
+        }
+
+        Ok(())
+    }
+
+    /// The `USE` statements of a script implicitly create the namespaces and databases they switch to.
+    /// In strict mode, each one must have been defined beforehand, possibly earlier in the script.
+    async fn ensure_script_is_defined(
+        &self,
+        session: &Session,
+        script: &str,
+    ) -> anyhow::Result<()> {
+        let (mut ns, mut db) = (session.ns.clone(), session.db.clone());
+        let mut namespaces = Vec::new();
+        let mut databases = Vec::new();
+
+        for statement in strict::scan(script, self.kvs.get_capabilities())? {
+            match statement {
+                ScriptStatement::DefineNamespace(name) => namespaces.push(name),
+                ScriptStatement::DefineDatabase(name) => {
+                    if let Some(ns) = &ns {
+                        databases.push((ns.clone(), name));
+                    }
+                }
+                ScriptStatement::Use {
+                    ns: next_ns,
+                    db: next_db,
+                } => {
+                    if let Some(next_ns) = next_ns {
+                        if !namespaces.contains(&next_ns) {
+                            self.ensure_namespace_is_defined(&next_ns).await?;
+                        }
+                        ns = Some(next_ns);
+                    }
+                    if next_db.is_some() {
+                        db = next_db;
+                    }
+
+                    // Switching to another namespace keeps the database, which must be defined in it too
+                    if let (Some(ns), Some(db)) = (&ns, &db)
+                        && !databases.contains(&(ns.clone(), db.clone()))
+                    {
+                        self.ensure_database_is_defined(ns, db).await?;
+                    }
+                }
+            }
+        }
+
+        Ok(())
+    }
+
+    async fn ensure_namespace_is_defined(&self, ns: &str) -> anyhow::Result<()> {
+        if !self
+            .is_defined(Session::owner(), "INFO FOR ROOT", "namespaces", ns)
+            .await?
+        {
+            return Err(
+                EmbeddedError::not_found(format!("The namespace '{ns}' does not exist")).into(),
+            );
+        }
+        Ok(())
+    }
+
+    async fn ensure_database_is_defined(&self, ns: &str, db: &str) -> anyhow::Result<()> {
+        if !self
+            .is_defined(Session::owner().with_ns(ns), "INFO FOR NS", "databases", db)
+            .await?
+        {
+            return Err(
+                EmbeddedError::not_found(format!("The database '{db}' does not exist")).into(),
+            );
+        }
+        if !self.is_strict(ns, db).await? {
+            return Err(EmbeddedError::invalid_params(format!(
+                "The database '{db}' is not defined as STRICT"
+            ))
+            .into());
+        }
+        Ok(())
+    }
+
+    /// Whether a definition is listed by an INFO statement.
+    /// The statement runs as the owner, as the session of the caller may not be allowed to run it.
+    async fn is_defined(
+        &self,
+        session: Session,
+        statement: &str,
+        kind: &str,
+        name: &str,
+    ) -> anyhow::Result<bool> {
+        let mut res = self.kvs.execute(statement, &session, None).await?;
+
+        match res.remove(0).result? {
+            Value::Object(info) => Ok(
+                matches!(info.get(kind), Some(Value::Object(definitions)) if definitions.get(name).is_some()),
+            ),
+            _ => Ok(false),
+        }
+    }
+
+    /// Whether a database rejects undefined tables, which its INFO statement does not tell.
+    /// Probes it by creating a record in an undefined table, within a transaction which is then cancelled.
+    async fn is_strict(&self, ns: &str, db: &str) -> anyhow::Result<bool> {
+        let session = Session::owner().with_ns(ns).with_db(db);
+        let table = format!("strict_probe_{}", Uuid::now_v7().simple());
+
+        let tx = Arc::new(
+            self.kvs
+                .transaction(TransactionType::Write, LockType::Optimistic)
+                .await?,
+        );
+        let res = self
+            .kvs
+            .execute_with_transaction(
+                &format!("CREATE {table} RETURN NONE"),
+                &session,
+                None,
+                tx.clone(),
+            )
+            .await;
+        tx.cancel().await?;
+
+        match res?.remove(0).result {
+            Ok(_) => Ok(false),
+            Err(error) if error.message() == format!("The table '{table}' does not exist") => {
+                Ok(true)
+            }
+            Err(error) => Err(error.into()),
+        }
+    }
+
+    /// The "kill" statement returns no id, so the RPC protocol never reports killed live queries.
+    async fn kill(
+        &self,
+        session_id: Option<Uuid>,
+        transaction_id: Option<Uuid>,
+        params: Array,
+    ) -> TxResult<DbResult> {
+        let lqid = match params.first() {
+            Some(Value::Uuid(lqid)) => Some(lqid.into_inner()),
+            _ => None,
+        };
+        let res =
+            RpcProtocol::execute(self, transaction_id, session_id, Method::Kill, params).await?;
+        if let Some(lqid) = lqid {
+            self.handle_kill(&lqid).await;
+        }
+        Ok(res)
+    }
+
+    /// Begins a transaction of the given session, with the options passed to the "begin" method.
+    async fn begin_with_options(
+        &self,
+        session_id: Option<Uuid>,
+        params: &Array,
+    ) -> TxResult<DbResult> {
+        let options = TransactionOptions::try_from(params.first())
+            .map_err(|e| rpc::invalid_params(format!("Invalid transaction options: {}", e)))?;
+
+        let id = self.open_transaction(session_id, &options).await?;
+        // Return the transaction ID to the client
+        Ok(DbResult::Other(Value::Uuid(surrealdb_types::Uuid::from(
+            id,
+        ))))
+    }
+
+    /// Opens a transaction of the given session, and returns its id.
+    async fn open_transaction(
+        &self,
+        session_id: Option<Uuid>,
+        options: &TransactionOptions,
+    ) -> TxResult<Uuid> {
+        let target = MethodTarget {
+            method: Method::Begin,
+        };
+        if !self.kvs.get_capabilities().allows_rpc_method(&target) {
+            return Err(rpc::method_not_allowed(Method::Begin.to_string()));
+        }
+
+        // Create a new transaction
+        let tx = self
+            .kvs()
+            .transaction(options.transaction_type(), options.lock_type())
+            .await
+            .map_err(rpc::types_error_from_anyhow)?;
+        // Generate a unique transaction ID
+        let id = Uuid::now_v7();
+        // Store the transaction in the map, tied to the session that opened it
+        self.transactions.insert(id, Arc::new(tx), session_id);
+        Ok(id)
+    }
+
+    async fn delete_live_queries(&self, ids: Vec<Uuid>) {
+        if ids.is_empty() {
+            return;
+        }
+        // Failing to delete live queries should not prevent the session from being cleaned up
+        let _ = self.kvs.delete_queries(ids).await;
+    }
 }
 
 type TxError = surrealdb_types::Error;
@@ -182,10 +823,49 @@ impl RpcProtocol for SurrealEmbeddedEngineInner {
         DbResult::Other(Value::String(format!("surrealdb-{}", SURREALDB_VERSION)))
     }
 
-    const LQ_SUPPORT: bool = false;
+    // ------------------------------
+    // Realtime
+    // ------------------------------
+
+    const LQ_SUPPORT: bool = true;
+
+    async fn handle_live(&self, lqid: &Uuid, session_id: Option<Uuid>) {
+        self.live_queries.insert(*lqid, session_id);
+    }
+
+    async fn handle_kill(&self, lqid: &Uuid) {
+        self.live_queries.remove(lqid);
+    }
+
+    async fn del_session(&self, id: &Uuid) {
+        self.session_map().remove(&Some(*id));
+        // Cleanup live queries
+        self.cleanup_lqs(Some(id)).await;
+        // Cancel the transactions opened by the session, as they can no longer be finished
+        for tx in self.transactions.remove_session(&Some(*id)) {
+            let _ = tx.cancel().await;
+        }
+    }
+
+    async fn cleanup_lqs(&self, session_id: Option<&Uuid>) {
+        let session_id = session_id.copied();
+        let mut ids = Vec::new();
+        self.live_queries.retain(|lqid, lq_session_id| {
+            if *lq_session_id == session_id {
+                ids.push(*lqid);
+                false
+            } else {
+                true
+            }
+        });
+        self.delete_live_queries(ids).await;
+    }
 
-    async fn cleanup_lqs(&self, _: Option<&Uuid>) {}
-    async fn cleanup_all_lqs(&self) {}
+    async fn cleanup_all_lqs(&self) {
+        let ids = self.live_queries.iter().map(|lq| *lq.key()).collect();
+        self.live_queries.clear();
+        self.delete_live_queries(ids).await;
+    }
 
     // ------------------------------
     // Transactions
@@ -193,15 +873,12 @@ impl RpcProtocol for SurrealEmbeddedEngineInner {
 
     /// Retrieves a transaction by ID
     async fn get_tx(&self, id: Uuid) -> TxResult<Arc<kvs::Transaction>> {
-        self.transactions
-            .get(&id)
-            .map(|tx| tx.clone())
-            .ok_or_else(|| rpc::invalid_params("Transaction not found"))
+        self.transactions.get(&id)
     }
 
     /// Stores a transaction
     async fn set_tx(&self, id: Uuid, tx: Arc<kvs::Transaction>) -> TxResult<()> {
-        self.transactions.insert(id, tx);
+        self.transactions.insert(id, tx, None);
         Ok(())
     }
 
@@ -210,21 +887,8 @@ impl RpcProtocol for SurrealEmbeddedEngineInner {
     // ------------------------------
 
     /// Begin a new transaction
-    async fn begin(&self, _txn: Option<Uuid>, _session_id: Option<Uuid>) -> TxResult<DbResult> {
-        // Create a new transaction
-        let tx = self
-            .kvs()
-            .transaction(TransactionType::Write, LockType::Optimistic)
-            .await
-            .map_err(rpc::types_error_from_anyhow)?;
-        // Generate a unique transaction ID
-        let id = Uuid::now_v7();
-        // Store the transaction in the map
-        self.transactions.insert(id, Arc::new(tx));
-        // Return the transaction ID to the client
-        Ok(DbResult::Other(Value::Uuid(surrealdb_types::Uuid::from(
-            id,
-        ))))
+    async fn begin(&self, _txn: Option<Uuid>, session_id: Option<Uuid>) -> TxResult<DbResult> {
+        self.begin_with_options(session_id, &Array::new()).await
     }
 
     /// Commit a transaction
@@ -239,9 +903,7 @@ impl RpcProtocol for SurrealEmbeddedEngineInner {
             return Err(rpc::invalid_params("Expected transaction UUID"));
         };
         let txn_id = txn_id.into_inner();
-        let Some((_, tx)) = self.transactions.remove(&txn_id) else {
-            return Err(rpc::invalid_params("Transaction not found"));
-        };
+        let tx = self.transactions.remove(&txn_id)?;
         tx.commit().await.map_err(rpc::types_error_from_anyhow)?;
         Ok(DbResult::Other(Value::None))
     }
@@ -258,9 +920,7 @@ impl RpcProtocol for SurrealEmbeddedEngineInner {
             return Err(rpc::invalid_params("Expected transaction UUID"));
         };
         let txn_id = txn_id.into_inner();
-        let Some((_, tx)) = self.transactions.remove(&txn_id) else {
-            return Err(rpc::invalid_params("Transaction not found"));
-        };
+        let tx = self.transactions.remove(&txn_id)?;
         tx.cancel().await.map_err(rpc::types_error_from_anyhow)?;
 
         // Return success
@@ -268,4 +928,10 @@ impl RpcProtocol for SurrealEmbeddedEngineInner {
     }
 }
 
+/// Maximum number of export chunks waiting to be consumed
+const EXPORT_STREAM_CAPACITY: usize = 16;
+
+/// Maximum number of import chunks waiting to be consumed
+const IMPORT_STREAM_CAPACITY: usize = 16;
+
 static SURREALDB_VERSION: &str = include_str!("../surreal-version.txt");
@@ -0,0 +1,431 @@
+use std::net::SocketAddr;
+use std::sync::Arc;
+
+use axum::Router;
+use axum::body::Bytes;
+use axum::extract::State;
+use axum::extract::ws::{Message, WebSocket, WebSocketUpgrade};
+use axum::http::{HeaderMap, StatusCode, header};
+use axum::response::{IntoResponse, Response};
+use axum::routing::{get, post};
+use base64::Engine;
+use base64::engine::general_purpose::STANDARD as BASE64;
+use surrealdb::dbs::Session;
+use surrealdb::iam::verify;
+use surrealdb::rpc::format::{Format, cbor, json};
+use surrealdb::rpc::{self, DbResponse, DbResult, Method, Request, RpcProtocol};
+use surrealdb_types::{Array, Error as TypesError, SurrealValue, Value};
+use tokio::net::TcpListener;
+use tokio::sync::RwLock;
+use tokio::task::JoinHandle;
+use tokio_util::sync::CancellationToken;
+use uuid::Uuid;
+
+use crate::models::error::EmbeddedError;
+use crate::models::serve_options::ServeOptions;
+use crate::runtime::engines::ENGINES;
+use crate::runtime::logs::with_engine_id;
+
+use super::{SurrealEmbeddedEngine, SurrealEmbeddedEngines};
+
+/// Methods a client can call before signing in, when authentication is required
+const UNAUTHENTICATED_METHODS: [Method; 5] = [
+    Method::Ping,
+    Method::Version,
+    Method::Signin,
+    Method::Signup,
+    Method::Authenticate,
+];
+
+/// Methods a client cannot call: notifications are forwarded to .NET rather than to the clients,
+/// and each connection is bound to a session of its own
+const SESSION_OR_LIVE_METHODS: [Method; 4] = [
+    Method::Live,
+    Method::Sessions,
+    Method::Attach,
+    Method::Detach,
+];
+
+/// Serves an engine over the `/rpc` (WebSocket) and `/sql` (HTTP) endpoints of SurrealDB, for debugging.
+pub struct DebugServer {
+    engine_id: i32,
+    address: SocketAddr,
+    shutdown: CancellationToken,
+    task: JoinHandle<()>,
+}
+
+#[derive(Clone)]
+struct ServerState {
+    engine_id: i32,
+    auth: bool,
+    shutdown: CancellationToken,
+}
+
+impl DebugServer {
+    /// Binds the given address and starts serving the engine (given its id).
+    pub async fn start(engine_id: i32, options: ServeOptions) -> anyhow::Result<Self> {
+        let listener = TcpListener::bind(options.address).await?;
+        let address = listener.local_addr()?;
+        let shutdown = CancellationToken::new();
+
+        let state = ServerState {
+            engine_id,
+            auth: options.auth,
+            shutdown: shutdown.clone(),
+        };
+        let router = Router::new()
+            .route("/rpc", get(rpc_handler))
+            .route("/sql", post(sql_handler))
+            .with_state(state);
+
+        let task = tokio::spawn(with_engine_id(engine_id, {
+            let shutdown = shutdown.clone();
+            async move {
+                let server = axum::serve(listener, router)
+                    .with_graceful_shutdown(shutdown.cancelled_owned());
+                if let Err(error) = server.await {
+                    tracing::warn!(%error, "Debug server failed");
+                }
+            }
+        }));
+
+        tracing::info!(%address, auth = options.auth, "Debug server started");
+
+        Ok(Self {
+            engine_id,
+            address,
+            shutdown,
+            task,
+        })
+    }
+
+    pub fn engine_id(&self) -> i32 {
+        self.engine_id
+    }
+
+    /// The bound address, with the actual port when any available port was requested
+    pub fn address(&self) -> SocketAddr {
+        self.address
+    }
+
+    /// Stops accepting connections and closes the open ones.
+    pub async fn stop(self) {
+        self.shutdown.cancel();
+        let _ = self.task.await;
+
+        tracing::info!(address = %self.address, "Debug server stopped");
+    }
+}
+
+/// Credentials sent to the `/sql` endpoint
+enum Credentials {
+    Basic { user: String, pass: String },
+    Bearer(String),
+}
+
+impl Credentials {
+    fn from_headers(headers: &HeaderMap) -> Option<Self> {
+        let authorization = headers.get(header::AUTHORIZATION)?.to_str().ok()?;
+
+        if let Some(token) = authorization.strip_prefix("Bearer ") {
+            return Some(Credentials::Bearer(token.trim().to_string()));
+        }
+
+        let encoded = authorization.strip_prefix("Basic ")?;
+        let decoded = String::from_utf8(BASE64.decode(encoded.trim()).ok()?).ok()?;
+        let (user, pass) = decoded.split_once(':')?;
+        Some(Credentials::Basic {
+            user: user.to_string(),
+            pass: pass.to_string(),
+        })
+    }
+}
+
+fn engine_missing() -> TypesError {
+    rpc::types_error_from_anyhow(EmbeddedError::engine_missing().into())
+}
+
+impl SurrealEmbeddedEngines {
+    async fn open_server_session(&self, id: i32, auth: bool) -> Result<Uuid, TypesError> {
+        let engine = self.get(id).await.map_err(|_| engine_missing())?;
+        engine.open_server_session(auth).await
+    }
+
+    async fn close_server_session(&self, id: i32, session_id: Uuid) {
+        if let Ok(engine) = self.get(id).await {
+            engine.close_server_session(session_id).await;
+        }
+    }
+
+    async fn execute_server_request(
+        &self,
+        id: i32,
+        session_id: Uuid,
+        auth: bool,
+        request: Request,
+    ) -> Result<Value, TypesError> {
+        let engine = self.get(id).await.map_err(|_| engine_missing())?;
+        engine
+            .execute_server_request(session_id, auth, request)
+            .await
+    }
+
+    async fn execute_server_sql(
+        &self,
+        id: i32,
+        session: Session,
+        credentials: Option<Credentials>,
+        sql: &str,
+    ) -> Result<Value, (StatusCode, TypesError)> {
+        let engine = self
+            .get(id)
+            .await
+            .map_err(|_| (StatusCode::SERVICE_UNAVAILABLE, engine_missing()))?;
+        engine.execute_server_sql(session, credentials, sql).await
+    }
+}
+
+impl SurrealEmbeddedEngine {
+    /// Opens the session of a debug server connection, with owner rights unless clients must sign in.
+    /// Without authentication, any client able to reach the server can therefore read and change every namespace.
+    async fn open_server_session(&self, auth: bool) -> Result<Uuid, TypesError> {
+        let rpc = self.inner().await.map_err(|_| engine_missing())?;
+        let session = match auth {
+            true => Session::default(),
+            false => Session::owner(),
+        };
+        let id = Uuid::now_v7();
+        rpc.set_session(Some(id), Arc::new(RwLock::new(session)));
+        Ok(id)
+    }
+
+    /// Closes the session of a debug server connection, cancelling the transactions it left open.
+    async fn close_server_session(&self, session_id: Uuid) {
+        if let Ok(rpc) = self.inner().await {
+            rpc.del_session(&session_id).await;
+        }
+    }
+
+    /// Executes a request received by the debug server, within the session of its connection.
+    /// The session a client may specify is ignored, so it cannot act on behalf of another connection or of .NET.
+    async fn execute_server_request(
+        &self,
+        session_id: Uuid,
+        auth: bool,
+        request: Request,
+    ) -> Result<Value, TypesError> {
+        if SESSION_OR_LIVE_METHODS.contains(&request.method) {
+            return Err(rpc::method_not_allowed(request.method.to_string()));
+        }
+
+        let rpc = self.inner().await.map_err(|_| engine_missing())?;
+
+        if auth && !UNAUTHENTICATED_METHODS.contains(&request.method) {
+            let session = rpc.get_session(&Some(session_id))?;
+            if session.read().await.au.is_anon() {
+                return Err(TypesError::not_allowed(
+                    "Sign in before calling this method".to_string(),
+                    None,
+                ));
+            }
+        }
+
+        rpc.execute_method(
+            request.method,
+            Some(session_id),
+            request.txn.map(Uuid::from),
+            request.params,
+        )
+        .await
+        .map_err(rpc::types_error_from_anyhow)
+    }
+
+    /// Executes the statements received by the `/sql` endpoint, within a session of their own.
+    async fn execute_server_sql(
+        &self,
+        mut session: Session,
+        credentials: Option<Credentials>,
+        sql: &str,
+    ) -> Result<Value, (StatusCode, TypesError)> {
+        let rpc = self
+            .inner()
+            .await
+            .map_err(|_| (StatusCode::SERVICE_UNAVAILABLE, engine_missing()))?;
+
+        let verified = match credentials {
+            Some(Credentials::Basic { user, pass }) => {
+                let (ns, db) = (session.ns.clone(), session.db.clone());
+                verify::basic(
+                    rpc.kvs(),
+                    &mut session,
+                    &user,
+                    &pass,
+                    ns.as_deref(),
+                    db.as_deref(),
+                )
+                .await
+            }
+            Some(Credentials::Bearer(token)) => {
+                verify::token(rpc.kvs(), &mut session, &token).await
+            }
+            None => Ok(()),
+        };
+        if let Err(error) = verified {
+            return Err((
+                StatusCode::UNAUTHORIZED,
+                rpc::types_error_from_anyhow(error),
+            ));
+        }
+        if session.au.is_anon() {
+            return Err((
+                StatusCode::UNAUTHORIZED,
+                TypesError::not_allowed("Authentication required".to_string(), None),
+            ));
+        }
+
+        let results = rpc
+            .kvs()
+            .execute(sql, &session, None)
+            .await
+            .map_err(|error| (StatusCode::BAD_REQUEST, error))?;
+
+        Ok(Value::Array(Array::from(
+            results
+                .into_iter()
+                .map(SurrealValue::into_value)
+                .collect::<Vec<_>>(),
+        )))
+    }
+}
+
+async fn rpc_handler(ws: WebSocketUpgrade, State(state): State<ServerState>) -> Response {
+    let ws = ws.protocols(["cbor", "json"]);
+    let format = match ws.selected_protocol().and_then(|v| v.to_str().ok()) {
+        Some(protocol) => Format::from(protocol),
+        None => Format::Json,
+    };
+    ws.on_upgrade(move |socket| {
+        with_engine_id(state.engine_id, serve_socket(socket, state, format))
+    })
+}
+
+/// Answers the requests of a WebSocket connection, until closed by either side.
+async fn serve_socket(mut socket: WebSocket, state: ServerState, format: Format) {
+    let session_id = match ENGINES
+        .open_server_session(state.engine_id, state.auth)
+        .await
+    {
+        Ok(session_id) => session_id,
+        Err(_) => return,
+    };
+
+    loop {
+        let message = tokio::select! {
+            _ = state.shutdown.cancelled() => break,
+            message = socket.recv() => message,
+        };
+
+        let request = match message {
+            Some(Ok(Message::Text(text))) => json::decode(text.as_bytes()),
+            Some(Ok(Message::Binary(bytes))) => match format {
+                Format::Json => json::decode(&bytes),
+                _ => cbor::decode(&bytes),
+            },
+            Some(Ok(Message::Ping(_) | Message::Pong(_))) => continue,
+            Some(Ok(Message::Close(_)) | Err(_)) | None => break,
+        };
+
+        let response = match request {
+            Ok(Value::Object(request)) => match Request::from_object(request) {
+                Ok(request) => {
+                    let id = request.id.clone();
+                    let result = ENGINES
+                        .execute_server_request(state.engine_id, session_id, state.auth, request)
+                        .await;
+                    DbResponse::new(id, None, result.map(DbResult::Other))
+                }
+                Err(error) => DbResponse::failure(None, None, error),
+            },
+            _ => DbResponse::failure(None, None, rpc::invalid_request()),
+        };
+
+        let response = response.into_value();
+        let message = match format {
+            Format::Json => json::encode_str(response).map(|text| Message::Text(text.into())),
+            _ => cbor::encode(response).map(|bytes| Message::Binary(bytes.into())),
+        };
+        let Ok(message) = message else {
+            continue;
+        };
+        if socket.send(message).await.is_err() {
+            break;
+        }
+    }
+
+    ENGINES
+        .close_server_session(state.engine_id, session_id)
+        .await;
+}
+
+/// Executes the SurrealQL statements of the request body, authenticated by the `Authorization` header
+/// within the namespace and database given by the `surreal-ns` and `surreal-db` headers.
+/// Answers in CBOR when accepted by the client, otherwise in JSON.
+async fn sql_handler(
+    State(state): State<ServerState>,
+    headers: HeaderMap,
+    body: Bytes,
+) -> Response {
+    let header_value = |names: &[&str]| {
+        names
+            .iter()
+            .find_map(|name| headers.get(*name))
+            .and_then(|value| value.to_str().ok())
+            .map(str::to_string)
+    };
+    let cbor = headers
+        .get(header::ACCEPT)
+        .and_then(|value| value.to_str().ok())
+        .is_some_and(|accept| accept.contains("application/cbor"));
+
+    let mut session = match state.auth {
+        true => Session::default(),
+        false => Session::owner(),
+    };
+    session.ns = header_value(&["surreal-ns", "ns"]);
+    session.db = header_value(&["surreal-db", "db"]);
+
+    let result = match std::str::from_utf8(&body) {
+        Ok(sql) => {
+            with_engine_id(
+                state.engine_id,
+                ENGINES.execute_server_sql(
+                    state.engine_id,
+                    session,
+                    Credentials::from_headers(&headers),
+                    sql,
+                ),
+            )
+            .await
+        }
+        Err(_) => Err((
+            StatusCode::BAD_REQUEST,
+            TypesError::validation("The request body is not valid UTF-8".to_string(), None),
+        )),
+    };
+
+    let (status, value) = match result {
+        Ok(value) => (StatusCode::OK, value),
+        Err((status, error)) => (status, error.into_value()),
+    };
+
+    let body = match cbor {
+        true => cbor::encode(value).map(|bytes| ("application/cbor", bytes)),
+        false => json::encode(value).map(|bytes| ("application/json", bytes)),
+    };
+    match body {
+        Ok((content_type, bytes)) => {
+            (status, [(header::CONTENT_TYPE, content_type)], bytes).into_response()
+        }
+        Err(_) => StatusCode::INTERNAL_SERVER_ERROR.into_response(),
+    }
+}
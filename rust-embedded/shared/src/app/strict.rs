@@ -0,0 +1,237 @@
+use std::iter::Peekable;
+use std::str::Chars;
+
+use surrealdb::dbs::capabilities::Capabilities;
+use surrealdb::syn;
+use surrealdb_types::ToSql;
+
+use crate::models::error::EmbeddedError;
+
+/// A statement of a SurrealQL script that defines or switches to a namespace or database.
+#[derive(Debug, PartialEq, Eq)]
+pub enum ScriptStatement {
+    DefineNamespace(String),
+    /// Defined as `STRICT`, in the namespace used when the statement is reached
+    DefineDatabase(String),
+    Use {
+        ns: Option<String>,
+        db: Option<String>,
+    },
+}
+
+/// Lists the `DEFINE NAMESPACE`, `DEFINE DATABASE` and `USE` statements of a script, in order.
+///
+/// The script is parsed as the datastore does, then read back from its formatted form, free of comments.
+/// `USE` statements are only accepted at the top level, so only top-level statements are listed.
+/// Definitions within a transaction are left out, as they may be rolled back.
+/// A script that cannot be parsed lists nothing, as the datastore fails to run it.
+///
+/// Statements whose effect cannot be known beforehand are rejected:
+/// a `USE` statement not naming its namespace and database, a database not defined as `STRICT`,
+/// and the removal of a namespace or database, which a session still using it would implicitly recreate.
+pub fn scan(
+    script: &str,
+    capabilities: &Capabilities,
+) -> Result<Vec<ScriptStatement>, EmbeddedError> {
+    let Ok(ast) = syn::parse_with_capabilities(script, capabilities) else {
+        return Ok(Vec::new());
+    };
+    let tokens = tokenize(&ast.to_sql());
+    let words = tokens.iter().map(|(_, token)| token).collect::<Vec<_>>();
+
+    // Databases and removals are checked at any depth, e.g. within a function or an event
+    for (index, window) in words.windows(2).enumerate() {
+        let [Token::Word(keyword), Token::Word(kind)] = window else {
+            continue;
+        };
+        let is_namespace = kind == "NS" || kind == "NAMESPACE";
+        let is_database = kind == "DB" || kind == "DATABASE";
+
+        if keyword == "REMOVE" && (is_namespace || is_database) {
+            return Err(EmbeddedError::invalid_params(
+                "Namespaces and databases cannot be removed in strict mode",
+            ));
+        }
+        if keyword == "DEFINE"
+            && is_database
+            && !matches!(skip_define_kind(&words[index + 2..]), [_, Token::Word(strict), ..] if strict == "STRICT")
+        {
+            return Err(EmbeddedError::invalid_params(
+                "Databases must be defined as STRICT in strict mode",
+            ));
+        }
+    }
+
+    let mut statements = Vec::new();
+    let mut in_transaction = false;
+
+    let top_level = tokens
+        .split(|(depth, token)| *depth == 0 && *token == Token::Symbol(';'))
+        .map(|statement| statement.iter().map(|(_, token)| token).collect::<Vec<_>>());
+
+    for statement in top_level {
+        let [Token::Word(keyword), rest @ ..] = statement.as_slice() else {
+            continue;
+        };
+
+        match keyword.as_str() {
+            "BEGIN" => in_transaction = true,
+            "COMMIT" | "CANCEL" => in_transaction = false,
+            "USE" => match parse_use(rest) {
+                Some(statement) => statements.push(statement),
+                None => {
+                    return Err(EmbeddedError::invalid_params(
+                        "A USE statement must name its namespace and database in strict mode",
+                    ));
+                }
+            },
+            "DEFINE" if !in_transaction => {
+                let [Token::Word(kind), rest @ ..] = rest else {
+                    continue;
+                };
+                let name = skip_define_kind(rest)
+                    .first()
+                    .and_then(|name| name_of(name));
+                match (kind.as_str(), name) {
+                    ("NS" | "NAMESPACE", Some(name)) => {
+                        statements.push(ScriptStatement::DefineNamespace(name))
+                    }
+                    ("DB" | "DATABASE", Some(name)) => {
+                        statements.push(ScriptStatement::DefineDatabase(name))
+                    }
+                    _ => {}
+                }
+            }
+            _ => {}
+        }
+    }
+
+    Ok(statements)
+}
+
+/// A token of a formatted SurrealQL script.
+#[derive(Debug, PartialEq, Eq)]
+enum Token {
+    /// A keyword, an identifier or a number
+    Word(String),
+    /// An escaped identifier or a string, unless it contains an unsupported escape sequence
+    Quoted(Option<String>),
+    Param,
+    Symbol(char),
+}
+
+/// Skips the `OVERWRITE` or `IF NOT EXISTS` clause of a `DEFINE` statement, following its kind.
+fn skip_define_kind<'a>(tokens: &'a [&'a Token]) -> &'a [&'a Token] {
+    let is_word =
+        |token: &Token, expected: &str| matches!(token, Token::Word(word) if word == expected);
+    match tokens {
+        [overwrite, rest @ ..] if is_word(overwrite, "OVERWRITE") => rest,
+        [r#if, not, exists, rest @ ..]
+            if is_word(r#if, "IF") && is_word(not, "NOT") && is_word(exists, "EXISTS") =>
+        {
+            rest
+        }
+        _ => tokens,
+    }
+}
+
+/// The name given by a token, if it is an identifier or a string.
+fn name_of(token: &Token) -> Option<String> {
+    match token {
+        Token::Word(word) if !word.starts_with(|c: char| c.is_ascii_digit()) => Some(word.clone()),
+        Token::Quoted(name) => name.clone(),
+        _ => None,
+    }
+}
+
+/// Reads the namespace and database of a `USE` statement, following its keyword.
+/// Each one must be named, rather than given by an expression.
+fn parse_use(tokens: &[&Token]) -> Option<ScriptStatement> {
+    let (mut ns, mut db) = (None, None);
+    let mut tokens = tokens.iter();
+
+    while let Some(token) = tokens.next() {
+        let (Token::Word(kind), Some(name)) = (token, tokens.next().and_then(|name| name_of(name)))
+        else {
+            return None;
+        };
+        match kind.as_str() {
+            "NS" | "NAMESPACE" if ns.is_none() && db.is_none() => ns = Some(name),
+            "DB" | "DATABASE" if db.is_none() => db = Some(name),
+            _ => return None,
+        }
+    }
+
+    (ns.is_some() || db.is_some()).then_some(ScriptStatement::Use { ns, db })
+}
+
+/// Splits a formatted script into its tokens, each one with its depth within blocks and brackets.
+fn tokenize(script: &str) -> Vec<(usize, Token)> {
+    let mut tokens = Vec::new();
+    let mut depth = 0usize;
+    let mut chars = script.chars().peekable();
+
+    while let Some(c) = chars.next() {
+        let token = match c {
+            c if c.is_whitespace() => continue,
+            '{' | '(' | '[' => {
+                depth += 1;
+                Token::Symbol(c)
+            }
+            '}' | ')' | ']' => {
+                depth = depth.saturating_sub(1);
+                Token::Symbol(c)
+            }
+            '\'' | '"' | '`' => Token::Quoted(read_quoted(&mut chars, c)),
+            '⟨' => Token::Quoted(read_quoted(&mut chars, '⟩')),
+            '$' => {
+                read_word(&mut chars, String::new());
+                Token::Param
+            }
+            c if c.is_alphanumeric() || c == '_' => Token::Word(read_word(&mut chars, c.into())),
+            c => Token::Symbol(c),
+        };
+        tokens.push((depth, token));
+    }
+
+    tokens
+}
+
+fn read_word(chars: &mut Peekable<Chars>, mut word: String) -> String {
+    while let Some(&c) = chars.peek() {
+        if !(c.is_alphanumeric() || c == '_') {
+            break;
+        }
+        word.push(c);
+        chars.next();
+    }
+    word
+}
+
+/// Reads the content of a quoted string or identifier, up to its closing quote.
+/// Only the escape sequences of quotes, backslashes and common control characters are supported.
+fn read_quoted(chars: &mut Peekable<Chars>, quote: char) -> Option<String> {
+    let mut content = Some(String::new());
+    while let Some(c) = chars.next() {
+        let c = match c {
+            '\\' => match chars.next() {
+                Some('n') => '\n',
+                Some('r') => '\r',
+                Some('t') => '\t',
+                Some('0') => '\0',
+                Some('f') => '\x0C',
+                Some(c) if c == '\\' || c == quote => c,
+                _ => {
+                    content = None;
+                    continue;
+                }
+            },
+            c if c == quote => break,
+            c => c,
+        };
+        if let Some(content) = &mut content {
+            content.push(c);
+        }
+    }
+    content
+}
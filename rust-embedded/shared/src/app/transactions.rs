@@ -0,0 +1,257 @@
+use dashmap::DashMap;
+use std::sync::Arc;
+use std::time::{Duration, Instant};
+use surrealdb::kvs::Transaction;
+use surrealdb::rpc;
+use surrealdb_types::{Error as TypesError, QueryError};
+use tokio::time::MissedTickBehavior;
+use uuid::Uuid;
+
+use crate::models::connection_options::TransactionsConfig;
+
+/// Longest delay between two checks for expired transactions
+const MAX_REAPER_INTERVAL: Duration = Duration::from_secs(1);
+
+/// Shortest delay between two checks for expired transactions
+const MIN_REAPER_INTERVAL: Duration = Duration::from_millis(10);
+
+/// How long an expired transaction is remembered, to report it as expired rather than not found
+const EXPIRED_RETENTION: Duration = Duration::from_secs(60 * 60);
+
+/// Most expired transactions remembered at once, the oldest ones being forgotten first
+const MAX_EXPIRED: usize = 1024;
+
+struct OpenTransaction {
+    tx: Arc<Transaction>,
+    /// the session that opened the transaction
+    session_id: Option<Uuid>,
+    started_at: Instant,
+    last_used_at: Instant,
+    /// number of requests running within the transaction, which is not idle meanwhile
+    busy: usize,
+}
+
+struct ExpiredTransaction {
+    expired_at: Instant,
+    reason: String,
+    limit: Duration,
+}
+
+/// Transactions opened via the "begin" method, until committed, cancelled or expired.
+#[derive(Default)]
+pub struct Transactions {
+    open: DashMap<Uuid, OpenTransaction>,
+    expired: DashMap<Uuid, ExpiredTransaction>,
+}
+
+impl Transactions {
+    pub fn insert(&self, id: Uuid, tx: Arc<Transaction>, session_id: Option<Uuid>) {
+        let now = Instant::now();
+        self.open.insert(
+            id,
+            OpenTransaction {
+                tx,
+                session_id,
+                started_at: now,
+                last_used_at: now,
+                busy: 0,
+            },
+        );
+    }
+
+    /// Number of open transactions
+    pub fn len(&self) -> usize {
+        self.open.len()
+    }
+
+    /// Retrieves a transaction, marking it as used.
+    pub fn get(&self, id: &Uuid) -> Result<Arc<Transaction>, TypesError> {
+        match self.open.get_mut(id) {
+            Some(mut open) => {
+                open.last_used_at = Instant::now();
+                Ok(open.tx.clone())
+            }
+            None => Err(self.missing(id)),
+        }
+    }
+
+    /// Marks a transaction as in use until the returned guard is dropped, so it does not expire as idle meanwhile.
+    /// Does nothing if the transaction is not open, `get` reporting why.
+    pub fn mark_busy(&self, id: Uuid) -> BusyTransaction<'_> {
+        let busy = match self.open.get_mut(&id) {
+            Some(mut open) => {
+                open.busy += 1;
+                true
+            }
+            None => false,
+        };
+        BusyTransaction {
+            transactions: self,
+            id,
+            busy,
+        }
+    }
+
+    /// Removes a transaction, to commit or cancel it.
+    pub fn remove(&self, id: &Uuid) -> Result<Arc<Transaction>, TypesError> {
+        match self.open.remove(id) {
+            Some((_, open)) => Ok(open.tx),
+            None => {
+                let error = self.missing(id);
+                // The transaction has been finished, as far as the caller is concerned
+                self.expired.remove(id);
+                Err(error)
+            }
+        }
+    }
+
+    /// Removes every open transaction, e.g. when the engine is closed.
+    pub fn drain(&self) -> Vec<Arc<Transaction>> {
+        let ids = self.open.iter().map(|open| *open.key()).collect::<Vec<_>>();
+        ids.into_iter()
+            .filter_map(|id| self.open.remove(&id).map(|(_, open)| open.tx))
+            .collect()
+    }
+
+    /// Removes the transactions opened by a session, e.g. when the session is detached.
+    pub fn remove_session(&self, session_id: &Option<Uuid>) -> Vec<Arc<Transaction>> {
+        let mut removed = Vec::new();
+        self.open.retain(|_, open| {
+            if open.session_id == *session_id {
+                removed.push(open.tx.clone());
+                false
+            } else {
+                true
+            }
+        });
+        removed
+    }
+
+    fn missing(&self, id: &Uuid) -> TypesError {
+        match self.expired.get(id) {
+            Some(expired) => TypesError::query(
+                format!("Transaction expired {}", expired.reason),
+                QueryError::TimedOut {
+                    duration: expired.limit,
+                },
+            ),
+            None => rpc::invalid_params("Transaction not found"),
+        }
+    }
+
+    /// Removes the transactions that exceeded the configured limits, so they can be cancelled.
+    fn take_expired(&self, config: &TransactionsConfig) -> Vec<Arc<Transaction>> {
+        let now = Instant::now();
+
+        self.expired
+            .retain(|_, expired| now.duration_since(expired.expired_at) < EXPIRED_RETENTION);
+
+        let mut expired = Vec::new();
+        self.open.retain(|id, open| {
+            let reason = match (config.max_lifetime, config.idle_timeout) {
+                // A request running within the transaction is not cut short, the transaction expires once it completes
+                (Some(max_lifetime), _)
+                    if open.busy == 0 && now.duration_since(open.started_at) >= max_lifetime =>
+                {
+                    Some((
+                        format!("after reaching its maximum lifetime of {max_lifetime:?}"),
+                        max_lifetime,
+                    ))
+                }
+                (_, Some(idle_timeout))
+                    if open.busy == 0 && now.duration_since(open.last_used_at) >= idle_timeout =>
+                {
+                    Some((
+                        format!("after being idle for {idle_timeout:?}"),
+                        idle_timeout,
+                    ))
+                }
+                _ => None,
+            };
+
+            match reason {
+                Some((reason, limit)) => {
+                    self.expired.insert(
+                        *id,
+                        ExpiredTransaction {
+                            expired_at: now,
+                            reason,
+                            limit,
+                        },
+                    );
+                    expired.push(open.tx.clone());
+                    false
+                }
+                None => true,
+            }
+        });
+        self.forget_oldest_expired();
+        expired
+    }
+
+    /// Keeps at most `MAX_EXPIRED` expired transactions.
+    fn forget_oldest_expired(&self) {
+        let excess = self.expired.len().saturating_sub(MAX_EXPIRED);
+        if excess == 0 {
+            return;
+        }
+
+        let mut expired = self
+            .expired
+            .iter()
+            .map(|expired| (expired.expired_at, *expired.key()))
+            .collect::<Vec<_>>();
+        expired.sort_unstable();
+        for (_, id) in expired.into_iter().take(excess) {
+            self.expired.remove(&id);
+        }
+    }
+}
+
+/// A transaction in use by a request, see `Transactions::mark_busy`.
+pub struct BusyTransaction<'a> {
+    transactions: &'a Transactions,
+    id: Uuid,
+    busy: bool,
+}
+
+impl Drop for BusyTransaction<'_> {
+    fn drop(&mut self) {
+        if !self.busy {
+            return;
+        }
+        // Idle from now on, unless still in use by another request
+        if let Some(mut open) = self.transactions.open.get_mut(&self.id) {
+            open.busy -= 1;
+            open.last_used_at = Instant::now();
+        }
+    }
+}
+
+/// Periodically cancels the transactions that exceeded the configured limits.
+/// Returns immediately if no limit is configured.
+pub async fn reap_transactions(transactions: Arc<Transactions>, config: TransactionsConfig) {
+    let Some(shortest_limit) = [config.idle_timeout, config.max_lifetime]
+        .into_iter()
+        .flatten()
+        .min()
+    else {
+        return;
+    };
+
+    let period = (shortest_limit / 2).clamp(MIN_REAPER_INTERVAL, MAX_REAPER_INTERVAL);
+    let mut interval = tokio::time::interval(period);
+    interval.set_missed_tick_behavior(MissedTickBehavior::Delay);
+
+    loop {
+        interval.tick().await;
+
+        let expired = transactions.take_expired(&config);
+        if !expired.is_empty() {
+            tracing::warn!(count = expired.len(), "Cancelling expired transactions");
+        }
+        for tx in expired {
+            let _ = tx.cancel().await;
+        }
+    }
+}
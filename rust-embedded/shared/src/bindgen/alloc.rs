@@ -1,6 +1,5 @@
-use super::byte_buffer::ByteBuffer;
+use super::{byte_buffer::ByteBuffer, pool::wrap_buffer};
 
 pub fn alloc_u8_buffer(vec: Vec<u8>) -> *mut ByteBuffer {
-    let buffer = ByteBuffer::from_vec(vec);
-    Box::into_raw(Box::new(buffer))
+    wrap_buffer(vec)
 }
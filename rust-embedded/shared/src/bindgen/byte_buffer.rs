@@ -1,25 +1,43 @@
 #[repr(C)]
 pub struct ByteBuffer {
     ptr: *mut u8,
-    length: i32,
-    capacity: i32,
+    length: usize,
+    capacity: usize,
 }
 
 impl ByteBuffer {
     pub fn len(&self) -> usize {
         self.length
-            .try_into()
-            .expect("buffer length negative or overflowed")
     }
 
     pub fn is_empty(&self) -> bool {
-        self.length <= 0
+        self.length == 0
+    }
+
+    pub fn as_slice(&self) -> &[u8] {
+        if self.ptr.is_null() || self.length == 0 {
+            &[]
+        } else {
+            unsafe { std::slice::from_raw_parts(self.ptr, self.length) }
+        }
+    }
+
+    /// Takes the buffer out, leaving an empty one.
+    pub fn take_vec(&mut self) -> Vec<u8> {
+        let buffer = std::mem::replace(
+            self,
+            Self {
+                ptr: std::ptr::null_mut(),
+                length: 0,
+                capacity: 0,
+            },
+        );
+        buffer.destroy_into_vec()
     }
 
     pub fn from_vec(bytes: Vec<u8>) -> Self {
-        let length = i32::try_from(bytes.len()).expect("buffer length cannot fit into a i32.");
-        let capacity =
-            i32::try_from(bytes.capacity()).expect("buffer capacity cannot fit into a i32.");
+        let length = bytes.len();
+        let capacity = bytes.capacity();
 
         // keep memory until call delete
         let mut v = std::mem::ManuallyDrop::new(bytes);
@@ -32,10 +50,10 @@ impl ByteBuffer {
     }
 
     pub fn from_vec_struct<T: Sized>(bytes: Vec<T>) -> Self {
-        let element_size = std::mem::size_of::<T>() as i32;
+        let element_size = std::mem::size_of::<T>();
 
-        let length = (bytes.len() as i32) * element_size;
-        let capacity = (bytes.capacity() as i32) * element_size;
+        let length = bytes.len() * element_size;
+        let capacity = bytes.capacity() * element_size;
 
         let mut v = std::mem::ManuallyDrop::new(bytes);
 
@@ -50,16 +68,7 @@ impl ByteBuffer {
         if self.ptr.is_null() {
             vec![]
         } else {
-            let capacity: usize = self
-                .capacity
-                .try_into()
-                .expect("buffer capacity negative or overflowed");
-            let length: usize = self
-                .length
-                .try_into()
-                .expect("buffer length negative or overflowed");
-
-            unsafe { Vec::from_raw_parts(self.ptr, length, capacity) }
+            unsafe { Vec::from_raw_parts(self.ptr, self.length, self.capacity) }
         }
     }
 
@@ -67,9 +76,9 @@ impl ByteBuffer {
         if self.ptr.is_null() {
             vec![]
         } else {
-            let element_size = std::mem::size_of::<T>() as i32;
-            let length = (self.length * element_size) as usize;
-            let capacity = (self.capacity * element_size) as usize;
+            let element_size = std::mem::size_of::<T>();
+            let length = self.length / element_size;
+            let capacity = self.capacity / element_size;
 
             unsafe { Vec::from_raw_parts(self.ptr as *mut T, length, capacity) }
         }
@@ -1,6 +1,8 @@
 use surrealdb::rpc::format::cbor::encode;
 use surrealdb_types::Value;
 
+use crate::models::error::EmbeddedError;
+
 use super::{alloc::alloc_u8_buffer, byte_buffer::ByteBuffer};
 
 type GCHandlePtr = isize;
@@ -11,6 +13,13 @@ pub struct RustGCHandle {
     drop_callback: extern "C" fn(GCHandlePtr),
 }
 
+impl RustGCHandle {
+    /// Wraps a handle of the host, released with the given callback once the action is dropped.
+    pub fn new(ptr: GCHandlePtr, drop_callback: extern "C" fn(GCHandlePtr)) -> Self {
+        Self { ptr, drop_callback }
+    }
+}
+
 impl Drop for RustGCHandle {
     fn drop(&mut self) {
         (self.drop_callback)(self.ptr);
@@ -24,6 +33,13 @@ pub struct SuccessAction {
 }
 
 impl SuccessAction {
+    pub fn new(
+        handle: RustGCHandle,
+        callback: unsafe extern "C" fn(GCHandlePtr, *mut ByteBuffer),
+    ) -> Self {
+        Self { handle, callback }
+    }
+
     /// # Safety
     ///
     /// Invokes the expected Success action.
@@ -41,6 +57,13 @@ pub struct FailureAction {
 }
 
 impl FailureAction {
+    pub fn new(
+        handle: RustGCHandle,
+        callback: unsafe extern "C" fn(GCHandlePtr, *mut ByteBuffer),
+    ) -> Self {
+        Self { handle, callback }
+    }
+
     /// # Safety
     ///
     /// Invokes the expected Failure action.
@@ -51,6 +74,77 @@ impl FailureAction {
     }
 }
 
+#[repr(C)]
+pub struct ChunkAction {
+    handle: RustGCHandle,
+    callback: unsafe extern "C" fn(GCHandlePtr, *mut ByteBuffer),
+}
+
+impl ChunkAction {
+    /// # Safety
+    ///
+    /// Invokes the expected Chunk action.
+    pub unsafe fn invoke(&self, value: *mut ByteBuffer) {
+        unsafe {
+            (self.callback)(self.handle.ptr, value);
+        }
+    }
+}
+
+#[repr(C)]
+pub struct NotificationAction {
+    handle: RustGCHandle,
+    callback: unsafe extern "C" fn(GCHandlePtr, i32, *mut ByteBuffer),
+}
+
+impl NotificationAction {
+    pub fn new(
+        handle: RustGCHandle,
+        callback: unsafe extern "C" fn(GCHandlePtr, i32, *mut ByteBuffer),
+    ) -> Self {
+        Self { handle, callback }
+    }
+
+    /// # Safety
+    ///
+    /// Invokes the expected Notification action, for the given engine id.
+    pub unsafe fn invoke(&self, id: i32, value: *mut ByteBuffer) {
+        unsafe {
+            (self.callback)(self.handle.ptr, id, value);
+        }
+    }
+}
+
+#[repr(C)]
+pub struct LogAction {
+    handle: RustGCHandle,
+    callback: unsafe extern "C" fn(GCHandlePtr, *mut ByteBuffer),
+}
+
+impl LogAction {
+    /// # Safety
+    ///
+    /// Invokes the expected Log action.
+    pub unsafe fn invoke(&self, value: *mut ByteBuffer) {
+        unsafe {
+            (self.callback)(self.handle.ptr, value);
+        }
+    }
+}
+
+/// Releases a buffer borrowed from .NET (e.g. unpins it), once it is no longer read.
+#[repr(C)]
+pub struct ReleaseAction {
+    ptr: GCHandlePtr,
+    callback: extern "C" fn(GCHandlePtr),
+}
+
+impl Drop for ReleaseAction {
+    fn drop(&mut self) {
+        (self.callback)(self.ptr);
+    }
+}
+
 fn value_to_buffer(value: Value) -> Result<*mut ByteBuffer, ()> {
     let output = encode(value).map_err(|_| ())?;
     Ok(alloc_u8_buffer(output))
@@ -61,8 +155,13 @@ pub fn send_success(bytes: Vec<u8>, success: SuccessAction) {
     unsafe { success.invoke(buffer) };
 }
 
-pub fn send_failure(error: &str, action: FailureAction) {
-    let value = Value::String(error.into());
+pub fn send_chunk(bytes: Vec<u8>, chunk: &ChunkAction) {
+    let buffer = alloc_u8_buffer(bytes);
+    unsafe { chunk.invoke(buffer) };
+}
+
+pub fn send_failure(error: impl Into<EmbeddedError>, action: FailureAction) {
+    let value = error.into().into_value();
 
     match value_to_buffer(value) {
         Ok(buffer) => unsafe { action.invoke(buffer) },
@@ -1,15 +1,50 @@
+use uuid::Uuid;
+
+use crate::models::error::EmbeddedError;
+use crate::runtime::metrics::record_bytes_in;
+
+use super::pool::take_buffer;
+
+/// # Safety
+///
+/// This function converts a C# byte array into a Vec<u8>, taken from the buffer pool.
+pub unsafe fn convert_csharp_to_rust_bytes(bytes: *const u8, len: usize) -> Vec<u8> {
+    // An empty C# array can be pinned as a null pointer
+    if bytes.is_null() || len == 0 {
+        return Vec::new();
+    }
+    let slice = unsafe { std::slice::from_raw_parts(bytes, len) };
+    record_bytes_in(len);
+    let mut buffer = take_buffer(len);
+    buffer.extend_from_slice(slice);
+    buffer
+}
+
 /// # Safety
 ///
-/// This function converts a C# byte array into a Vec<u8>.
-pub unsafe fn convert_csharp_to_rust_bytes(bytes: *const u8, len: i32) -> Vec<u8> {
-    let slice = unsafe { std::slice::from_raw_parts(bytes, len as usize) };
-    slice.to_vec()
+/// This function converts a C# Guid (16 bytes array) into a Uuid.
+pub unsafe fn convert_csharp_to_rust_uuid(bytes: *const u8, len: usize) -> Option<Uuid> {
+    if bytes.is_null() || len != 16 {
+        return None;
+    }
+    let slice = unsafe { std::slice::from_raw_parts(bytes, len) };
+    Uuid::from_slice(slice).ok()
 }
 
 /// # Safety
 ///
 /// This function converts an (UTF-16) C# string (u16 array) into a Rust String.
-pub unsafe fn convert_csharp_to_rust_string_utf16(bytes: *const u16, len: i32) -> String {
-    let slice = unsafe { std::slice::from_raw_parts(bytes, len as usize) };
-    String::from_utf16(slice).unwrap()
+/// A C# string can hold unpaired surrogates, which are rejected.
+pub unsafe fn convert_csharp_to_rust_string_utf16(
+    bytes: *const u16,
+    len: usize,
+) -> Result<String, EmbeddedError> {
+    // An empty C# string can be pinned as a null pointer
+    if bytes.is_null() || len == 0 {
+        return Ok(String::new());
+    }
+    let slice = unsafe { std::slice::from_raw_parts(bytes, len) };
+    record_bytes_in(len * size_of::<u16>());
+    String::from_utf16(slice)
+        .map_err(|_| EmbeddedError::deserialization("String is not valid UTF-16"))
 }
@@ -1,11 +1,14 @@
-use super::byte_buffer::ByteBuffer;
+use super::{
+    byte_buffer::ByteBuffer,
+    pool::{release_buffer, unwrap_buffer},
+};
 
 /// # Safety
 ///
 /// This function is used to free Rust memory from a C# binding.
+/// The memory is kept for reuse by the buffer pool, when possible.
 #[unsafe(no_mangle)]
 pub unsafe extern "C" fn free_u8_buffer(buffer: *mut ByteBuffer) {
-    let buf = unsafe { Box::from_raw(buffer) };
-    // drop inner buffer, if you need Vec<u8>, use buf.destroy_into_vec() instead.
-    buf.destroy();
+    let bytes = unsafe { unwrap_buffer(buffer) };
+    release_buffer(bytes);
 }
@@ -3,3 +3,5 @@ pub mod byte_buffer;
 pub mod callback;
 pub mod csharp_to_rust;
 pub mod free;
+pub mod pinned;
+pub mod pool;
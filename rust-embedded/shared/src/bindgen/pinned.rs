@@ -0,0 +1,38 @@
+use crate::runtime::metrics::record_bytes_in;
+
+use super::callback::ReleaseAction;
+
+/// A byte buffer borrowed from (pinned) .NET memory, released once dropped.
+pub struct PinnedBytes {
+    ptr: *const u8,
+    len: usize,
+    _release: ReleaseAction,
+}
+
+// SAFETY: the buffer is pinned and left untouched by .NET until released, so it can be read from any thread.
+unsafe impl Send for PinnedBytes {}
+unsafe impl Sync for PinnedBytes {}
+
+impl PinnedBytes {
+    /// # Safety
+    ///
+    /// The buffer must stay valid (and unchanged) until the release action is invoked.
+    pub unsafe fn new(ptr: *const u8, len: usize, release: ReleaseAction) -> Self {
+        record_bytes_in(len);
+        Self {
+            ptr,
+            len,
+            _release: release,
+        }
+    }
+}
+
+impl AsRef<[u8]> for PinnedBytes {
+    fn as_ref(&self) -> &[u8] {
+        // An empty C# array can be pinned as a null pointer
+        if self.ptr.is_null() || self.len == 0 {
+            return &[];
+        }
+        unsafe { std::slice::from_raw_parts(self.ptr, self.len) }
+    }
+}
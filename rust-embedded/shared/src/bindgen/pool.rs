@@ -0,0 +1,277 @@
+use std::sync::atomic::{AtomicU64, AtomicUsize, Ordering};
+use std::sync::{Mutex, MutexGuard, RwLock};
+
+use once_cell::sync::Lazy;
+use surrealdb::rpc::format::cbor::encode;
+use surrealdb_types::{Object, Value};
+
+use crate::bindgen::callback::{FailureAction, SuccessAction, send_failure, send_success};
+use crate::bindgen::csharp_to_rust::convert_csharp_to_rust_bytes;
+use crate::models::buffer_pool_options::BufferPoolOptions;
+use crate::runtime::metrics::record_bytes_out;
+
+use super::byte_buffer::ByteBuffer;
+
+static POOL: Lazy<BufferPool> = Lazy::new(BufferPool::default);
+
+/// An empty `ByteBuffer` allocation, kept to wrap the next result.
+struct Shell(Box<ByteBuffer>);
+
+// SAFETY: a pooled shell never points to a buffer.
+unsafe impl Send for Shell {}
+
+/// Number of size classes, one per power of two a capacity can start from.
+const BUCKETS: usize = usize::BITS as usize;
+
+/// Buffers freed by C# (results) or consumed by Rust (params), kept to avoid allocating new ones.
+/// They are bucketed by capacity, the bucket `n` holding capacities from `2^n` to `2^(n+1) - 1`,
+/// each bucket having its own lock.
+struct BufferPool {
+    options: RwLock<BufferPoolOptions>,
+    buckets: [Mutex<Vec<Vec<u8>>>; BUCKETS],
+    shells: Mutex<Vec<Shell>>,
+    /// buffers currently kept for reuse, across all buckets
+    pooled: AtomicUsize,
+    /// buffers taken from the pool
+    hits: AtomicU64,
+    /// buffers allocated because none was large enough
+    misses: AtomicU64,
+    /// buffers given back to the pool
+    released: AtomicU64,
+    /// buffers freed because too large or the pool was full
+    discarded: AtomicU64,
+}
+
+impl Default for BufferPool {
+    fn default() -> Self {
+        Self {
+            options: RwLock::default(),
+            buckets: std::array::from_fn(|_| Mutex::default()),
+            shells: Mutex::default(),
+            pooled: AtomicUsize::new(0),
+            hits: AtomicU64::new(0),
+            misses: AtomicU64::new(0),
+            released: AtomicU64::new(0),
+            discarded: AtomicU64::new(0),
+        }
+    }
+}
+
+impl BufferPool {
+    fn options(&self) -> BufferPoolOptions {
+        *self.options.read().unwrap_or_else(|e| e.into_inner())
+    }
+
+    fn bucket(&self, index: usize) -> MutexGuard<'_, Vec<Vec<u8>>> {
+        self.buckets[index]
+            .lock()
+            .unwrap_or_else(|e| e.into_inner())
+    }
+
+    fn shells(&self) -> MutexGuard<'_, Vec<Shell>> {
+        self.shells.lock().unwrap_or_else(|e| e.into_inner())
+    }
+
+    /// Reserves a place for one more buffer, unless the pool is full.
+    fn reserve(&self, max_buffers: usize) -> bool {
+        self.pooled
+            .fetch_update(Ordering::Relaxed, Ordering::Relaxed, |pooled| {
+                (pooled < max_buffers).then_some(pooled + 1)
+            })
+            .is_ok()
+    }
+}
+
+/// The bucket holding a buffer of the given (non-zero) capacity.
+fn bucket_of(capacity: usize) -> usize {
+    capacity.ilog2() as usize
+}
+
+/// The first bucket whose buffers all have at least the given capacity.
+fn first_bucket_fitting(min_capacity: usize) -> usize {
+    match min_capacity {
+        0 | 1 => 0,
+        _ => bucket_of(min_capacity - 1) + 1,
+    }
+}
+
+/// Takes an empty buffer of at least the given capacity, from the pool if possible.
+pub fn take_buffer(min_capacity: usize) -> Vec<u8> {
+    // The smallest size class that fits, to keep the larger ones for larger payloads
+    let buffer =
+        (first_bucket_fitting(min_capacity)..BUCKETS).find_map(|index| POOL.bucket(index).pop());
+
+    match buffer {
+        Some(buffer) => {
+            POOL.pooled.fetch_sub(1, Ordering::Relaxed);
+            POOL.hits.fetch_add(1, Ordering::Relaxed);
+            buffer
+        }
+        None => {
+            POOL.misses.fetch_add(1, Ordering::Relaxed);
+            Vec::with_capacity(min_capacity)
+        }
+    }
+}
+
+/// Gives a buffer back to the pool, unless too large or the pool is full.
+pub fn release_buffer(mut buffer: Vec<u8>) {
+    let options = POOL.options();
+    if buffer.capacity() == 0
+        || buffer.capacity() > options.max_buffer_size
+        || !POOL.reserve(options.max_buffers)
+    {
+        POOL.discarded.fetch_add(1, Ordering::Relaxed);
+        return;
+    }
+
+    buffer.clear();
+    POOL.bucket(bucket_of(buffer.capacity())).push(buffer);
+    POOL.released.fetch_add(1, Ordering::Relaxed);
+}
+
+/// Wraps a result into a `ByteBuffer`, reusing a pooled allocation if possible.
+pub fn wrap_buffer(bytes: Vec<u8>) -> *mut ByteBuffer {
+    record_bytes_out(bytes.len());
+
+    let shell = POOL.shells().pop();
+
+    match shell {
+        Some(Shell(mut shell)) => {
+            *shell = ByteBuffer::from_vec(bytes);
+            Box::into_raw(shell)
+        }
+        None => Box::into_raw(Box::new(ByteBuffer::from_vec(bytes))),
+    }
+}
+
+/// # Safety
+///
+/// Frees a `ByteBuffer` allocated by `wrap_buffer`, giving its allocations back to the pool.
+pub unsafe fn unwrap_buffer(buffer: *mut ByteBuffer) -> Vec<u8> {
+    let mut shell = unsafe { Box::from_raw(buffer) };
+    let bytes = shell.take_vec();
+
+    let max_buffers = POOL.options().max_buffers;
+    let mut shells = POOL.shells();
+    if shells.len() < max_buffers {
+        shells.push(Shell(shell));
+    }
+
+    bytes
+}
+
+/// Applies new options, trimming the buffers that no longer fit.
+pub fn configure_pool(options: BufferPoolOptions) {
+    *POOL.options.write().unwrap_or_else(|e| e.into_inner()) = options;
+
+    let mut kept = 0;
+    let mut trimmed = 0;
+    for index in 0..BUCKETS {
+        let mut bucket = POOL.bucket(index);
+        let before = bucket.len();
+        bucket.retain(|buffer| buffer.capacity() <= options.max_buffer_size);
+        bucket.truncate(options.max_buffers.saturating_sub(kept));
+        kept += bucket.len();
+        trimmed += before - bucket.len();
+    }
+    POOL.pooled.fetch_sub(trimmed, Ordering::Relaxed);
+    POOL.shells().truncate(options.max_buffers);
+
+    POOL.discarded.fetch_add(trimmed as u64, Ordering::Relaxed);
+}
+
+/// Statistics of the buffer pool, since the library was loaded.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct BufferPoolStats {
+    pub hits: u64,
+    pub misses: u64,
+    pub released: u64,
+    pub discarded: u64,
+    /// buffers currently kept for reuse
+    pub pooled_buffers: usize,
+    /// capacity of the buffers currently kept for reuse
+    pub pooled_bytes: usize,
+}
+
+impl BufferPoolStats {
+    pub fn into_value(self) -> Value {
+        let mut stats = Object::new();
+        stats.insert("hits".to_string(), Value::from_t(self.hits as i64));
+        stats.insert("misses".to_string(), Value::from_t(self.misses as i64));
+        stats.insert("released".to_string(), Value::from_t(self.released as i64));
+        stats.insert(
+            "discarded".to_string(),
+            Value::from_t(self.discarded as i64),
+        );
+        stats.insert(
+            "pooled_buffers".to_string(),
+            Value::from_t(self.pooled_buffers as i64),
+        );
+        stats.insert(
+            "pooled_bytes".to_string(),
+            Value::from_t(self.pooled_bytes as i64),
+        );
+        Value::Object(stats)
+    }
+}
+
+pub fn pool_stats() -> BufferPoolStats {
+    let (pooled_buffers, pooled_bytes) = (0..BUCKETS)
+        .map(|index| {
+            let bucket = POOL.bucket(index);
+            (
+                bucket.len(),
+                bucket.iter().map(Vec::capacity).sum::<usize>(),
+            )
+        })
+        .fold((0, 0), |(buffers, bytes), (count, capacity)| {
+            (buffers + count, bytes + capacity)
+        });
+
+    BufferPoolStats {
+        hits: POOL.hits.load(Ordering::Relaxed),
+        misses: POOL.misses.load(Ordering::Relaxed),
+        released: POOL.released.load(Ordering::Relaxed),
+        discarded: POOL.discarded.load(Ordering::Relaxed),
+        pooled_buffers,
+        pooled_bytes,
+    }
+}
+
+/// # Safety
+///
+/// Configures the pool of result buffers, given its options (`max_buffer_size`, `max_buffers`).
+/// An empty buffer stands for the default options, and 0 `max_buffers` disables pooling.
+#[unsafe(no_mangle)]
+pub unsafe extern "C" fn configure_buffer_pool(
+    bytes: *const u8,
+    len: usize,
+    success: SuccessAction,
+    failure: FailureAction,
+) {
+    let opts_bytes = unsafe { convert_csharp_to_rust_bytes(bytes, len) };
+
+    match BufferPoolOptions::from_bytes(&opts_bytes) {
+        Ok(options) => {
+            configure_pool(options);
+            send_success(vec![], success);
+        }
+        Err(error) => {
+            send_failure(error, failure);
+        }
+    }
+}
+
+/// Sends the statistics of the pool of result buffers to the success callback, as a CBOR object.
+#[unsafe(no_mangle)]
+pub extern "C" fn get_buffer_pool_stats(success: SuccessAction, failure: FailureAction) {
+    match encode(pool_stats().into_value()) {
+        Ok(output) => {
+            send_success(output, success);
+        }
+        Err(error) => {
+            send_failure(error, failure);
+        }
+    }
+}
@@ -0,0 +1,75 @@
+use crate::{
+    abort_import, apply_connect, backup, begin_import, bindgen, execute, execute_pinned, export,
+    export_stream, finish_import, import, import_file, runtime, validate_options,
+    write_import_chunk,
+};
+
+/// A `#[no_mangle]` function exposed to C#, along with its symbol name.
+pub struct ExportedSymbol(pub &'static str, pub *const ());
+
+// SAFETY: the pointer is only a function address, it is never dereferenced.
+unsafe impl Sync for ExportedSymbol {}
+
+/// Every function that must be part of the native library of an engine.
+/// Checked against the entry points generated by csbindgen (see `build.rs`).
+pub static EXPORTED_SYMBOLS: &[ExportedSymbol] = &[
+    ExportedSymbol("apply_connect", apply_connect as *const ()),
+    ExportedSymbol("validate_options", validate_options as *const ()),
+    ExportedSymbol("execute", execute as *const ()),
+    ExportedSymbol("execute_pinned", execute_pinned as *const ()),
+    ExportedSymbol("import", import as *const ()),
+    ExportedSymbol("import_file", import_file as *const ()),
+    ExportedSymbol("begin_import", begin_import as *const ()),
+    ExportedSymbol("write_import_chunk", write_import_chunk as *const ()),
+    ExportedSymbol("finish_import", finish_import as *const ()),
+    ExportedSymbol("abort_import", abort_import as *const ()),
+    ExportedSymbol("export", export as *const ()),
+    ExportedSymbol("export_stream", export_stream as *const ()),
+    ExportedSymbol("backup", backup as *const ()),
+    ExportedSymbol("free_u8_buffer", bindgen::free::free_u8_buffer as *const ()),
+    ExportedSymbol(
+        "configure_buffer_pool",
+        bindgen::pool::configure_buffer_pool as *const (),
+    ),
+    ExportedSymbol(
+        "get_buffer_pool_stats",
+        bindgen::pool::get_buffer_pool_stats as *const (),
+    ),
+    ExportedSymbol("dispose", runtime::engines::dispose as *const ()),
+    ExportedSymbol(
+        "create_global_runtime",
+        runtime::create_global_runtime as *const (),
+    ),
+    ExportedSymbol(
+        "shutdown_global_runtime",
+        runtime::shutdown_global_runtime as *const (),
+    ),
+    ExportedSymbol(
+        "cancel_request",
+        runtime::requests::cancel_request as *const (),
+    ),
+    ExportedSymbol(
+        "register_log_action",
+        runtime::logs::register_log_action as *const (),
+    ),
+    ExportedSymbol("set_log_filter", runtime::logs::set_log_filter as *const ()),
+    ExportedSymbol("get_metrics", runtime::metrics::get_metrics as *const ()),
+    ExportedSymbol("serve", runtime::servers::serve as *const ()),
+    ExportedSymbol("stop_serving", runtime::servers::stop_serving as *const ()),
+    ExportedSymbol(
+        "register_notification_action",
+        runtime::notifications::register_notification_action as *const (),
+    ),
+];
+
+/// Exposes the FFI surface of `shared` from an engine crate.
+/// Every engine crate must call this macro (once) from its `lib.rs`.
+#[macro_export]
+macro_rules! export_ffi {
+    () => {
+        /// Keeps every exported symbol referenced, so the linker cannot discard any of them.
+        #[used]
+        static EXPORTED_SYMBOLS: &&[$crate::exports::ExportedSymbol] =
+            &$crate::exports::EXPORTED_SYMBOLS;
+    };
+}
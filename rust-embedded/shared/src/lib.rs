@@ -1,17 +1,32 @@
 #![recursion_limit = "256"]
 
-use app::SurrealEmbeddedEngine;
+use anyhow::Context;
+use app::{ImportSession, SurrealEmbeddedEngine};
 use bindgen::{
-    callback::{FailureAction, SuccessAction, send_failure, send_success},
-    csharp_to_rust::{convert_csharp_to_rust_bytes, convert_csharp_to_rust_string_utf16},
+    callback::{
+        ChunkAction, FailureAction, ReleaseAction, SuccessAction, send_chunk, send_failure,
+        send_success,
+    },
+    csharp_to_rust::{
+        convert_csharp_to_rust_bytes, convert_csharp_to_rust_string_utf16,
+        convert_csharp_to_rust_uuid,
+    },
+    pinned::PinnedBytes,
+    pool::release_buffer,
 };
-use models::method::Method;
-use runtime::{engines::ENGINES, get_global_runtime};
+use models::{connection_options::ConnectionOptions, error::EmbeddedError, method::Method};
+use runtime::{
+    engines::{ENGINES, IMPORTS},
+    requests::{NO_REQUEST, spawn_engine_request},
+};
+use surrealdb::rpc::format::cbor::encode;
+use surrealdb_types::Value;
 use uuid::Uuid;
 
 pub mod app;
 pub mod bindgen;
 pub mod cbor;
+pub mod exports;
 pub mod models;
 pub mod runtime;
 
@@ -19,143 +34,500 @@ pub mod runtime;
 ///
 /// Apply connection for the SurrealDB engine (given its id).
 /// 💡 "connect" is a reserved keyword
+/// Returns the request id, which can be used to cancel the request.
 #[unsafe(no_mangle)]
 pub unsafe extern "C" fn apply_connect(
     id: i32,
     utf16_str: *const u16,
-    utf16_len: i32,
+    utf16_len: usize,
+    bytes: *const u8,
+    len: usize,
+    success: SuccessAction,
+    failure: FailureAction,
+) -> u64 {
+    let endpoint = match unsafe { convert_csharp_to_rust_string_utf16(utf16_str, utf16_len) } {
+        Ok(endpoint) => endpoint,
+        Err(error) => {
+            send_failure(error, failure);
+            return NO_REQUEST;
+        }
+    };
+    let opts_bytes = unsafe { convert_csharp_to_rust_bytes(bytes, len) };
+
+    spawn_engine_request(id, "connect", success, failure, async move {
+        let engine = SurrealEmbeddedEngine::connect(id, endpoint, opts_bytes)
+            .await
+            .context("Cannot connect to db")?;
+        ENGINES.insert(id, engine).await;
+        Ok(vec![])
+    })
+}
+
+/// # Safety
+///
+/// Validates connection options without opening a datastore.
+/// Every invalid entry is reported at once through the failure callback.
+#[unsafe(no_mangle)]
+pub unsafe extern "C" fn validate_options(
     bytes: *const u8,
-    len: i32,
+    len: usize,
     success: SuccessAction,
     failure: FailureAction,
 ) {
-    let endpoint = unsafe { convert_csharp_to_rust_string_utf16(utf16_str, utf16_len) };
     let opts_bytes = unsafe { convert_csharp_to_rust_bytes(bytes, len) };
 
-    get_global_runtime().spawn(async move {
-        match SurrealEmbeddedEngine::connect(endpoint, opts_bytes).await {
-            Ok(engine) => {
-                ENGINES.insert(id, engine).await;
-                send_success(vec![], success);
-            }
-            Err(e) => {
-                send_failure(&format!("Cannot connect to db: {}", e), failure);
-            }
-        }
-    });
+    match ConnectionOptions::from_bytes(&opts_bytes).and_then(|options| options.capabilities()) {
+        Ok(_) => {
+            send_success(vec![], success);
+        }
+        Err(error) => {
+            send_failure(error, failure);
+        }
+    }
 }
 
 /// # Safety
 ///
 /// Executes a specific method of a SurrealDB engine (given its id).
 /// To execute a method, you should pass down the Method, the params and the callback functions (success, failure).
+/// Returns the request id, which can be used to cancel the request.
 #[unsafe(no_mangle)]
 pub unsafe extern "C" fn execute(
     id: i32,
     method: Method,
     session_bytes: *const u8,
-    session_len: i32,
+    session_len: usize,
     transaction_bytes: *const u8,
-    transaction_len: i32,
+    transaction_len: usize,
     params_bytes: *const u8,
-    params_len: i32,
+    params_len: usize,
     success: SuccessAction,
     failure: FailureAction,
-) {
+) -> u64 {
     let method: surrealdb::rpc::Method = method.into();
 
-    let session_id = if session_len == 16 {
-        let session_bytes = unsafe { convert_csharp_to_rust_bytes(session_bytes, session_len) };
-        Uuid::try_from(session_bytes).map(Some)
-    } else {
-        Ok(None)
-    };
-    if session_id.is_err() {
-        send_failure("Failed to deserialize session id", failure);
-        return;
-    }
-
-    let transaction_id = if transaction_len == 16 {
-        let transaction_bytes =
-            unsafe { convert_csharp_to_rust_bytes(transaction_bytes, transaction_len) };
-        Uuid::try_from(transaction_bytes).map(Some)
-    } else {
-        Ok(None)
+    let (session_id, transaction_id) = match unsafe {
+        convert_execution_ids(
+            session_bytes,
+            session_len,
+            transaction_bytes,
+            transaction_len,
+        )
+    } {
+        Ok(ids) => ids,
+        Err(error) => {
+            send_failure(error, failure);
+            return NO_REQUEST;
+        }
     };
-    if transaction_id.is_err() {
-        send_failure("Failed to deserialize transaction id", failure);
-        return;
-    }
 
     let params_bytes = unsafe { convert_csharp_to_rust_bytes(params_bytes, params_len) };
 
-    get_global_runtime().spawn(async move {
-        match ENGINES
-            .execute(
-                id,
-                method,
-                session_id.unwrap(),
-                transaction_id.unwrap(),
-                params_bytes,
-            )
+    spawn_engine_request(id, method.to_str(), success, failure, async move {
+        let result = ENGINES
+            .execute(id, method, session_id, transaction_id, &params_bytes)
+            .await;
+        release_buffer(params_bytes);
+        result
+    })
+}
+
+/// # Safety
+///
+/// Executes a specific method of a SurrealDB engine (given its id), like "execute",
+/// reading the params directly from a pinned C# buffer instead of copying them.
+/// The buffer must stay pinned until the release callback is invoked, once the request has completed or been cancelled.
+/// Returns the request id, which can be used to cancel the request.
+#[unsafe(no_mangle)]
+pub unsafe extern "C" fn execute_pinned(
+    id: i32,
+    method: Method,
+    session_bytes: *const u8,
+    session_len: usize,
+    transaction_bytes: *const u8,
+    transaction_len: usize,
+    params_bytes: *const u8,
+    params_len: usize,
+    release: ReleaseAction,
+    success: SuccessAction,
+    failure: FailureAction,
+) -> u64 {
+    let method: surrealdb::rpc::Method = method.into();
+    let params_bytes = unsafe { PinnedBytes::new(params_bytes, params_len, release) };
+
+    let (session_id, transaction_id) = match unsafe {
+        convert_execution_ids(
+            session_bytes,
+            session_len,
+            transaction_bytes,
+            transaction_len,
+        )
+    } {
+        Ok(ids) => ids,
+        Err(error) => {
+            send_failure(error, failure);
+            return NO_REQUEST;
+        }
+    };
+
+    spawn_engine_request(id, method.to_str(), success, failure, async move {
+        ENGINES
+            .execute(id, method, session_id, transaction_id, params_bytes)
             .await
-        {
-            Ok(output) => {
-                send_success(output, success);
-            }
-            Err(error) => {
-                send_failure(&error.to_string(), failure);
-            }
-        }
-    });
+    })
+}
+
+/// # Safety
+///
+/// Converts the (optional) session and transaction ids of a method execution.
+unsafe fn convert_execution_ids(
+    session_bytes: *const u8,
+    session_len: usize,
+    transaction_bytes: *const u8,
+    transaction_len: usize,
+) -> Result<(Option<Uuid>, Option<Uuid>), EmbeddedError> {
+    let session_id = unsafe { convert_optional_id(session_bytes, session_len, "session") }?;
+    let transaction_id =
+        unsafe { convert_optional_id(transaction_bytes, transaction_len, "transaction") }?;
+
+    Ok((session_id, transaction_id))
+}
+
+/// # Safety
+///
+/// Converts an optional id, which is not set if empty.
+/// A malformed id is rejected rather than ignored, so the request does not run on the default session.
+unsafe fn convert_optional_id(
+    bytes: *const u8,
+    len: usize,
+    name: &str,
+) -> Result<Option<Uuid>, EmbeddedError> {
+    if len == 0 {
+        return Ok(None);
+    }
+    unsafe { convert_csharp_to_rust_uuid(bytes, len) }
+        .map(Some)
+        .ok_or_else(|| EmbeddedError::invalid_params(format!("Failed to deserialize {name} id")))
 }
 
 /// # Safety
 ///
-/// Executes the "import" method of a SurrealDB engine (given its id).
+/// Executes the "import" method of a SurrealDB engine (given its id), using the given session (default session if empty).
+/// The import runs within the given transaction, if any.
+/// Returns the request id, which can be used to cancel the request.
 #[unsafe(no_mangle)]
 pub unsafe extern "C" fn import(
     id: i32,
+    session_bytes: *const u8,
+    session_len: usize,
+    transaction_bytes: *const u8,
+    transaction_len: usize,
     utf16_str: *const u16,
-    utf16_len: i32,
+    utf16_len: usize,
+    success: SuccessAction,
+    failure: FailureAction,
+) -> u64 {
+    let (session_id, transaction_id) = match unsafe {
+        convert_execution_ids(
+            session_bytes,
+            session_len,
+            transaction_bytes,
+            transaction_len,
+        )
+    } {
+        Ok(ids) => ids,
+        Err(error) => {
+            send_failure(error, failure);
+            return NO_REQUEST;
+        }
+    };
+    let input = match unsafe { convert_csharp_to_rust_string_utf16(utf16_str, utf16_len) } {
+        Ok(input) => input,
+        Err(error) => {
+            send_failure(error, failure);
+            return NO_REQUEST;
+        }
+    };
+
+    spawn_engine_request(id, "import", success, failure, async move {
+        ENGINES
+            .import(id, session_id, transaction_id, input)
+            .await?;
+        Ok(vec![])
+    })
+}
+
+/// # Safety
+///
+/// Executes the "import" method of a SurrealDB engine (given its id),
+/// reading the SurrealQL script from a file (given its path).
+/// The script is streamed, so giving a transaction is rejected.
+/// Returns the request id, which can be used to cancel the request.
+#[unsafe(no_mangle)]
+pub unsafe extern "C" fn import_file(
+    id: i32,
+    session_bytes: *const u8,
+    session_len: usize,
+    transaction_bytes: *const u8,
+    transaction_len: usize,
+    utf16_str: *const u16,
+    utf16_len: usize,
+    success: SuccessAction,
+    failure: FailureAction,
+) -> u64 {
+    let (session_id, transaction_id) = match unsafe {
+        convert_execution_ids(
+            session_bytes,
+            session_len,
+            transaction_bytes,
+            transaction_len,
+        )
+    } {
+        Ok(ids) => ids,
+        Err(error) => {
+            send_failure(error, failure);
+            return NO_REQUEST;
+        }
+    };
+    let path = match unsafe { convert_csharp_to_rust_string_utf16(utf16_str, utf16_len) } {
+        Ok(path) => path,
+        Err(error) => {
+            send_failure(error, failure);
+            return NO_REQUEST;
+        }
+    };
+
+    spawn_engine_request(id, "import_file", success, failure, async move {
+        ENGINES
+            .import_file(id, session_id, transaction_id, path.into())
+            .await?;
+        Ok(vec![])
+    })
+}
+
+/// # Safety
+///
+/// Starts a chunked import of a SurrealDB engine (given its id).
+/// The script is streamed, so giving a transaction is rejected.
+/// The import id is sent to the success callback, to be used with "write_import_chunk" and "finish_import".
+#[unsafe(no_mangle)]
+pub unsafe extern "C" fn begin_import(
+    id: i32,
+    session_bytes: *const u8,
+    session_len: usize,
+    transaction_bytes: *const u8,
+    transaction_len: usize,
     success: SuccessAction,
     failure: FailureAction,
 ) {
-    let input = unsafe { convert_csharp_to_rust_string_utf16(utf16_str, utf16_len) };
-
-    get_global_runtime().spawn(async move {
-        match ENGINES.import(id, input).await {
-            Ok(_) => {
-                send_success(vec![], success);
-            }
-            Err(error) => {
-                send_failure(&error.to_string(), failure);
-            }
-        }
-    });
+    let (session_id, transaction_id) = match unsafe {
+        convert_execution_ids(
+            session_bytes,
+            session_len,
+            transaction_bytes,
+            transaction_len,
+        )
+    } {
+        Ok(ids) => ids,
+        Err(error) => {
+            send_failure(error, failure);
+            return;
+        }
+    };
+
+    let import = match ImportSession::begin(id, session_id, transaction_id) {
+        Ok(import) => import,
+        Err(error) => {
+            send_failure(error, failure);
+            return;
+        }
+    };
+
+    let import_id = Uuid::now_v7();
+    IMPORTS.insert(import_id, import);
+
+    match encode(Value::Uuid(import_id.into())) {
+        Ok(output) => {
+            send_success(output, success);
+        }
+        Err(error) => {
+            IMPORTS.remove(&import_id);
+            send_failure(error, failure);
+        }
+    }
+}
+
+/// # Safety
+///
+/// Sends the next chunk of the SurrealQL script of a chunked import (given its id).
+/// The success callback is invoked once the chunk is queued for import.
+/// Returns the request id, which can be used to cancel the request.
+#[unsafe(no_mangle)]
+pub unsafe extern "C" fn write_import_chunk(
+    id: i32,
+    import_bytes: *const u8,
+    import_len: usize,
+    bytes: *const u8,
+    len: usize,
+    success: SuccessAction,
+    failure: FailureAction,
+) -> u64 {
+    let Some(import_id) = (unsafe { convert_csharp_to_rust_uuid(import_bytes, import_len) }) else {
+        send_failure(
+            EmbeddedError::invalid_params("Failed to deserialize import id"),
+            failure,
+        );
+        return NO_REQUEST;
+    };
+    let chunk = unsafe { convert_csharp_to_rust_bytes(bytes, len) };
+
+    spawn_engine_request(id, "write_import_chunk", success, failure, async move {
+        let writer = match IMPORTS.get(&import_id) {
+            Some(import) if import.engine_id() == id => import.writer(),
+            _ => return Err(EmbeddedError::not_found("Import not found").into()),
+        };
+        writer.write(chunk).await?;
+        Ok(vec![])
+    })
+}
+
+/// # Safety
+///
+/// Completes a chunked import (given its id), once every chunk has been written.
+/// The success callback is invoked once the whole SurrealQL script has been imported.
+/// Returns the request id, which can be used to cancel the request.
+#[unsafe(no_mangle)]
+pub unsafe extern "C" fn finish_import(
+    id: i32,
+    import_bytes: *const u8,
+    import_len: usize,
+    success: SuccessAction,
+    failure: FailureAction,
+) -> u64 {
+    let Some(import_id) = (unsafe { convert_csharp_to_rust_uuid(import_bytes, import_len) }) else {
+        send_failure(
+            EmbeddedError::invalid_params("Failed to deserialize import id"),
+            failure,
+        );
+        return NO_REQUEST;
+    };
+    let Some((_, import)) = IMPORTS.remove_if(&import_id, |_, import| import.engine_id() == id)
+    else {
+        send_failure(EmbeddedError::not_found("Import not found"), failure);
+        return NO_REQUEST;
+    };
+
+    spawn_engine_request(id, "finish_import", success, failure, async move {
+        import.finish().await?;
+        Ok(vec![])
+    })
+}
+
+/// # Safety
+///
+/// Aborts a chunked import (given its id), discarding the chunks that have not been imported yet.
+/// Does nothing if the import has already been finished or aborted.
+#[unsafe(no_mangle)]
+pub unsafe extern "C" fn abort_import(id: i32, import_bytes: *const u8, import_len: usize) {
+    let Some(import_id) = (unsafe { convert_csharp_to_rust_uuid(import_bytes, import_len) }) else {
+        return;
+    };
+    if let Some((_, import)) = IMPORTS.remove_if(&import_id, |_, import| import.engine_id() == id) {
+        import.abort();
+    }
 }
 
 /// # Safety
 ///
-/// Executes the "export" method of a SurrealDB engine (given its id).
+/// Executes the "export" method of a SurrealDB engine (given its id), using the given session (default session if empty).
+/// Returns the request id, which can be used to cancel the request.
 #[unsafe(no_mangle)]
 pub unsafe extern "C" fn export(
     id: i32,
+    session_bytes: *const u8,
+    session_len: usize,
     bytes: *const u8,
-    len: i32,
+    len: usize,
     success: SuccessAction,
     failure: FailureAction,
-) {
+) -> u64 {
+    let session_id = match unsafe { convert_optional_id(session_bytes, session_len, "session") } {
+        Ok(session_id) => session_id,
+        Err(error) => {
+            send_failure(error, failure);
+            return NO_REQUEST;
+        }
+    };
     let params_bytes = unsafe { convert_csharp_to_rust_bytes(bytes, len) };
 
-    get_global_runtime().spawn(async move {
-        match ENGINES.export(id, params_bytes).await {
-            Ok(output) => {
-                send_success(output, success);
-            }
-            Err(error) => {
-                send_failure(&error.to_string(), failure);
-            }
+    spawn_engine_request(id, "export", success, failure, async move {
+        ENGINES.export(id, session_id, params_bytes).await
+    })
+}
+
+/// # Safety
+///
+/// Executes the "export" method of a SurrealDB engine (given its id), using the given session (default session if empty),
+/// streaming the output.
+/// Each chunk produced by the datastore is sent to the chunk callback, in order,
+/// then the success callback is invoked once the export is complete.
+/// Returns the request id, which can be used to cancel the request.
+#[unsafe(no_mangle)]
+pub unsafe extern "C" fn export_stream(
+    id: i32,
+    session_bytes: *const u8,
+    session_len: usize,
+    bytes: *const u8,
+    len: usize,
+    chunk: ChunkAction,
+    success: SuccessAction,
+    failure: FailureAction,
+) -> u64 {
+    let session_id = match unsafe { convert_optional_id(session_bytes, session_len, "session") } {
+        Ok(session_id) => session_id,
+        Err(error) => {
+            send_failure(error, failure);
+            return NO_REQUEST;
         }
-    });
+    };
+    let params_bytes = unsafe { convert_csharp_to_rust_bytes(bytes, len) };
+
+    spawn_engine_request(id, "export_stream", success, failure, async move {
+        ENGINES
+            .export_stream(id, session_id, params_bytes, |bytes| {
+                send_chunk(bytes, &chunk)
+            })
+            .await?;
+        Ok(vec![])
+    })
+}
+
+/// # Safety
+///
+/// Backs a RocksDB or SurrealKV engine (given its id) up into a target directory (given its path),
+/// which must not exist or be empty.
+/// The backup is a consistent copy of the datastore, usable with the "restore" connection option.
+/// Writers are not blocked while the backup is in progress.
+/// The cluster keys of the engine (nodes, heartbeats, leases) are left out.
+/// Returns the request id, which can be used to cancel the request.
+#[unsafe(no_mangle)]
+pub unsafe extern "C" fn backup(
+    id: i32,
+    utf16_str: *const u16,
+    utf16_len: usize,
+    success: SuccessAction,
+    failure: FailureAction,
+) -> u64 {
+    let path = match unsafe { convert_csharp_to_rust_string_utf16(utf16_str, utf16_len) } {
+        Ok(path) => path,
+        Err(error) => {
+            send_failure(error, failure);
+            return NO_REQUEST;
+        }
+    };
+
+    spawn_engine_request(id, "backup", success, failure, async move {
+        ENGINES.backup(id, path.into()).await?;
+        Ok(vec![])
+    })
 }
@@ -0,0 +1,72 @@
+use anyhow::anyhow;
+use surrealdb::rpc::format::cbor::decode;
+use surrealdb_types::{Number, Value};
+
+use super::error::EmbeddedError;
+
+/// Largest buffer kept for reuse, by default
+const DEFAULT_MAX_BUFFER_SIZE: usize = 64 * 1024;
+
+/// Number of buffers kept for reuse, by default
+const DEFAULT_MAX_BUFFERS: usize = 64;
+
+#[derive(Debug, Clone, Copy)]
+pub struct BufferPoolOptions {
+    /// buffers with a larger capacity are freed rather than reused
+    pub max_buffer_size: usize,
+    /// 0 to disable pooling
+    pub max_buffers: usize,
+}
+
+impl Default for BufferPoolOptions {
+    fn default() -> Self {
+        Self {
+            max_buffer_size: DEFAULT_MAX_BUFFER_SIZE,
+            max_buffers: DEFAULT_MAX_BUFFERS,
+        }
+    }
+}
+
+impl BufferPoolOptions {
+    /// Decodes the CBOR options sent by C#, an empty buffer meaning default options.
+    pub fn from_bytes(bytes: &[u8]) -> Result<Self, EmbeddedError> {
+        if bytes.is_empty() {
+            return Ok(BufferPoolOptions::default());
+        }
+
+        let value = decode(bytes)
+            .map_err(|_| EmbeddedError::deserialization("Options are not valid CBOR."))?;
+
+        BufferPoolOptions::try_from(&value).map_err(|e| {
+            EmbeddedError::invalid_params(format!("Invalid buffer pool options: {}", e))
+        })
+    }
+}
+
+fn get_size(value: Option<&Value>, name: &str) -> anyhow::Result<Option<usize>> {
+    match value {
+        None | Some(Value::None) | Some(Value::Null) => Ok(None),
+        Some(Value::Number(Number::Int(v))) if *v >= 0 => Ok(Some(*v as usize)),
+        Some(_) => Err(anyhow!("{} must be a non-negative integer", name)),
+    }
+}
+
+impl TryFrom<&Value> for BufferPoolOptions {
+    type Error = anyhow::Error;
+    fn try_from(value: &Value) -> Result<Self, Self::Error> {
+        match value {
+            Value::None | Value::Null => Ok(BufferPoolOptions::default()),
+            Value::Object(obj) => {
+                let defaults = BufferPoolOptions::default();
+
+                Ok(BufferPoolOptions {
+                    max_buffer_size: get_size(obj.get("max_buffer_size"), "max_buffer_size")?
+                        .unwrap_or(defaults.max_buffer_size),
+                    max_buffers: get_size(obj.get("max_buffers"), "max_buffers")?
+                        .unwrap_or(defaults.max_buffers),
+                })
+            }
+            _ => Err(anyhow!("Failed to convert to object")),
+        }
+    }
+}
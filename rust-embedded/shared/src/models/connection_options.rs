@@ -1,16 +1,48 @@
 use anyhow::anyhow;
 use std::collections::HashSet;
+use std::path::PathBuf;
+use std::time::Duration;
 use surrealdb::dbs::capabilities;
-use surrealdb_types::Value;
+use surrealdb::rpc::format::cbor::decode;
+use surrealdb_types::{Object, Value};
+
+use super::error::EmbeddedError;
 
 #[derive(Debug, Default)]
 pub struct ConnectionOptions {
     pub strict: Option<bool>,
     pub capabilities: Option<CapabilitiesConfig>,
+    pub auth: Option<AuthConfig>,
+    pub transactions: Option<TransactionsConfig>,
+    /// backup directory (see `backup`) replacing the datastore before it is opened
+    pub restore: Option<PathBuf>,
+}
+
+#[derive(Debug, Default)]
+pub struct AuthConfig {
+    pub enabled: Option<bool>,
+    /// root user created on connect if no root user exists yet
+    pub username: Option<String>,
+    pub password: Option<String>,
+}
+
+#[derive(Debug, Default, Clone, Copy)]
+pub struct TransactionsConfig {
+    /// transactions unused for this long are cancelled
+    pub idle_timeout: Option<Duration>,
+    /// transactions open for this long are cancelled, once the requests running within them complete
+    pub max_lifetime: Option<Duration>,
 }
 
 #[derive(Debug, Default)]
 pub struct CapabilitiesConfig {
+    pub scripting: Option<bool>,
+    pub guest_access: Option<bool>,
+    pub functions: Option<Targets>,
+    pub network_targets: Option<Targets>,
+    pub rpc_methods: Option<Targets>,
+    pub http_routes: Option<Targets>,
+    pub arbitrary_query: Option<Targets>,
     pub experimental: Option<Targets>,
 }
 
@@ -27,180 +59,372 @@ pub struct TargetsConfig {
     pub array: Option<HashSet<String>>,
 }
 
-impl TryFrom<&Value> for ConnectionOptions {
-    type Error = anyhow::Error;
-    fn try_from(value: &Value) -> Result<Self, Self::Error> {
-        match value {
-            Value::None | Value::Null => Ok(ConnectionOptions::default()),
-            Value::Object(obj) => {
-                let mut connection = ConnectionOptions::default();
-
-                match obj.get("strict") {
-                    Some(Value::None) => (),
-                    Some(Value::Bool(v)) => {
-                        connection.strict = Some(v.to_owned());
-                    }
-                    Some(_) => {
-                        return Err(anyhow!("Failed to convert to boolean"));
-                    }
-                    _ => (),
-                }
+/// Name of a field, prefixed with the path of its parent object (e.g. `auth.enabled`)
+fn field_name(path: &str, key: &str) -> String {
+    if path.is_empty() {
+        key.to_string()
+    } else {
+        format!("{}.{}", path, key)
+    }
+}
 
-                if let Some(v) = obj.get("capabilities") {
-                    connection.capabilities = Some(v.try_into()?);
-                }
+fn bool_field(obj: &Object, path: &str, key: &str, errors: &mut Vec<String>) -> Option<bool> {
+    match obj.get(key) {
+        None | Some(Value::None) => None,
+        Some(Value::Bool(v)) => Some(*v),
+        Some(_) => {
+            errors.push(format!(
+                "Failed to convert {} to boolean",
+                field_name(path, key)
+            ));
+            None
+        }
+    }
+}
 
-                Ok(connection)
-            }
-            _ => Err(anyhow!("Failed to convert to object")),
+fn string_field(obj: &Object, path: &str, key: &str, errors: &mut Vec<String>) -> Option<String> {
+    match obj.get(key) {
+        None | Some(Value::None) => None,
+        Some(Value::String(v)) => Some(v.to_owned()),
+        Some(_) => {
+            errors.push(format!(
+                "Failed to convert {} to string",
+                field_name(path, key)
+            ));
+            None
         }
     }
 }
 
-impl TryFrom<&Value> for CapabilitiesConfig {
-    type Error = anyhow::Error;
-    fn try_from(value: &Value) -> Result<Self, Self::Error> {
-        match value {
-            Value::None | Value::Null => Ok(CapabilitiesConfig::default()),
-            Value::Object(obj) => {
-                let mut config = CapabilitiesConfig::default();
+fn duration_field(
+    obj: &Object,
+    path: &str,
+    key: &str,
+    errors: &mut Vec<String>,
+) -> Option<Duration> {
+    match obj.get(key) {
+        None | Some(Value::None) => None,
+        Some(Value::Duration(v)) if !v.is_zero() => Some(v.into_inner()),
+        Some(_) => {
+            errors.push(format!(
+                "{} must be a positive duration",
+                field_name(path, key)
+            ));
+            None
+        }
+    }
+}
 
-                if let Some(v) = obj.get("experimental") {
-                    config.experimental = Some(v.try_into()?);
+fn strings_field(
+    obj: &Object,
+    path: &str,
+    key: &str,
+    errors: &mut Vec<String>,
+) -> Option<HashSet<String>> {
+    match obj.get(key) {
+        None | Some(Value::None) => None,
+        Some(Value::Array(v)) => {
+            let mut strings = HashSet::with_capacity(v.len());
+            for value in v.iter() {
+                match value {
+                    Value::String(s) => {
+                        strings.insert(s.to_string());
+                    }
+                    _ => errors.push(format!(
+                        "Expected string in {}, got {:?}",
+                        field_name(path, key),
+                        value
+                    )),
                 }
-
-                Ok(config)
             }
-            _ => Err(anyhow!("Failed to convert to object")),
+            Some(strings)
+        }
+        Some(_) => {
+            errors.push(format!(
+                "Failed to convert {} to array",
+                field_name(path, key)
+            ));
+            None
         }
     }
 }
 
-impl TryFrom<&Value> for Targets {
+/// Parses a nested configuration object, a missing or null object being the default configuration
+fn object_field<T: Default>(
+    obj: &Object,
+    path: &str,
+    key: &str,
+    errors: &mut Vec<String>,
+    parse: fn(&Object, &str, &mut Vec<String>) -> T,
+) -> Option<T> {
+    match obj.get(key) {
+        None => None,
+        Some(Value::None | Value::Null) => Some(T::default()),
+        Some(Value::Object(v)) => Some(parse(v, &field_name(path, key), errors)),
+        Some(_) => {
+            errors.push(format!(
+                "Failed to convert {} to object",
+                field_name(path, key)
+            ));
+            None
+        }
+    }
+}
+
+impl TryFrom<&Value> for ConnectionOptions {
     type Error = anyhow::Error;
     fn try_from(value: &Value) -> Result<Self, Self::Error> {
         match value {
-            Value::None | Value::Null => Ok(Targets::default()),
+            Value::None | Value::Null => Ok(ConnectionOptions::default()),
             Value::Object(obj) => {
-                let mut targets = Targets::default();
+                let mut errors = Vec::new();
+                let connection = ConnectionOptions::parse(obj, "", &mut errors);
 
-                if let Some(v) = obj.get("allow") {
-                    targets.allow = Some(v.try_into()?);
+                // Invalid targets are reported along with the invalid fields
+                if let Some(capabilities) = &connection.capabilities {
+                    capabilities.build(&mut errors);
                 }
 
-                if let Some(v) = obj.get("deny") {
-                    targets.deny = Some(v.try_into()?);
+                if !errors.is_empty() {
+                    return Err(anyhow!(errors.join("; ")));
                 }
 
-                Ok(targets)
+                Ok(connection)
             }
             _ => Err(anyhow!("Failed to convert to object")),
         }
     }
 }
 
-impl TryFrom<&Value> for TargetsConfig {
-    type Error = anyhow::Error;
-    fn try_from(value: &Value) -> Result<Self, Self::Error> {
-        match value {
-            Value::None | Value::Null => Ok(TargetsConfig::default()),
-            Value::Object(obj) => {
-                let mut config = TargetsConfig::default();
+impl ConnectionOptions {
+    fn parse(obj: &Object, path: &str, errors: &mut Vec<String>) -> Self {
+        ConnectionOptions {
+            strict: bool_field(obj, path, "strict", errors),
+            capabilities: object_field(
+                obj,
+                path,
+                "capabilities",
+                errors,
+                CapabilitiesConfig::parse,
+            ),
+            auth: object_field(obj, path, "auth", errors, AuthConfig::parse),
+            transactions: object_field(
+                obj,
+                path,
+                "transactions",
+                errors,
+                TransactionsConfig::parse,
+            ),
+            restore: string_field(obj, path, "restore", errors).map(PathBuf::from),
+        }
+    }
+}
 
-                match obj.get("bool") {
-                    Some(Value::None) => (),
-                    Some(Value::Bool(v)) => {
-                        config.bool = Some(v.to_owned());
-                    }
-                    Some(_) => {
-                        return Err(anyhow!("Failed to convert to boolean"));
-                    }
-                    _ => (),
-                }
+impl CapabilitiesConfig {
+    fn parse(obj: &Object, path: &str, errors: &mut Vec<String>) -> Self {
+        CapabilitiesConfig {
+            scripting: bool_field(obj, path, "scripting", errors),
+            guest_access: bool_field(obj, path, "guest_access", errors),
+            functions: object_field(obj, path, "functions", errors, Targets::parse),
+            network_targets: object_field(obj, path, "network_targets", errors, Targets::parse),
+            rpc_methods: object_field(obj, path, "rpc_methods", errors, Targets::parse),
+            http_routes: object_field(obj, path, "http_routes", errors, Targets::parse),
+            arbitrary_query: object_field(obj, path, "arbitrary_query", errors, Targets::parse),
+            experimental: object_field(obj, path, "experimental", errors, Targets::parse),
+        }
+    }
+}
 
-                match obj.get("array") {
-                    Some(Value::None) => (),
-                    Some(Value::Array(v)) => {
-                        config.array = Some(
-                            v.iter()
-                                .map(|v| match v {
-                                    Value::String(s) => Ok(s.to_string()),
-                                    _ => Err(anyhow::anyhow!("Expected string, got {:?}", v)),
-                                })
-                                .collect::<Result<HashSet<_>, _>>()?,
-                        );
-                    }
-                    Some(_) => {
-                        return Err(anyhow!("Failed to convert to array"));
-                    }
-                    _ => (),
-                }
+impl AuthConfig {
+    fn parse(obj: &Object, path: &str, errors: &mut Vec<String>) -> Self {
+        let invalid = errors.len();
+        let config = AuthConfig {
+            enabled: bool_field(obj, path, "enabled", errors),
+            username: string_field(obj, path, "username", errors),
+            password: string_field(obj, path, "password", errors),
+        };
 
-                Ok(config)
-            }
-            _ => Err(anyhow!("Failed to convert to object")),
+        // An invalid username or password is already reported
+        if errors.len() == invalid && config.username.is_some() != config.password.is_some() {
+            errors.push(format!(
+                "Both {} and {} are required to create the root user",
+                field_name(path, "username"),
+                field_name(path, "password")
+            ));
         }
+
+        config
     }
 }
 
+impl TransactionsConfig {
+    fn parse(obj: &Object, path: &str, errors: &mut Vec<String>) -> Self {
+        TransactionsConfig {
+            idle_timeout: duration_field(obj, path, "idle_timeout", errors),
+            max_lifetime: duration_field(obj, path, "max_lifetime", errors),
+        }
+    }
+}
+
+impl Targets {
+    fn parse(obj: &Object, path: &str, errors: &mut Vec<String>) -> Self {
+        Targets {
+            allow: object_field(obj, path, "allow", errors, TargetsConfig::parse),
+            deny: object_field(obj, path, "deny", errors, TargetsConfig::parse),
+        }
+    }
+}
+
+impl TargetsConfig {
+    fn parse(obj: &Object, path: &str, errors: &mut Vec<String>) -> Self {
+        TargetsConfig {
+            bool: bool_field(obj, path, "bool", errors),
+            array: strings_field(obj, path, "array", errors),
+        }
+    }
+}
+
+/// Parses a set of targets, collecting the invalid ones into `$errors`
 macro_rules! process_targets {
-    ($set:ident) => {{
-        let mut functions = HashSet::with_capacity($set.len());
-        for function in $set {
-            functions.insert(function.parse().expect("invalid function name"));
+    ($set:ident, $errors:ident, $name:literal) => {{
+        let mut targets = HashSet::with_capacity($set.len());
+        for target in $set {
+            match target.parse() {
+                Ok(value) => {
+                    targets.insert(value);
+                }
+                Err(error) => {
+                    $errors.push(format!("{} '{}': {}", $name, target, error));
+                }
+            }
         }
-        capabilities::Targets::Some(functions)
+        capabilities::Targets::Some(targets)
     }};
 }
 
-impl TryFrom<CapabilitiesConfig> for capabilities::Capabilities {
-    type Error = anyhow::Error;
+macro_rules! process_targets_config {
+    ($config:ident, $errors:ident, $name:literal) => {{
+        match (&$config.array, $config.bool) {
+            (Some(set), _) => Some(process_targets!(set, $errors, $name)),
+            (None, Some(true)) => Some(capabilities::Targets::All),
+            (None, Some(false)) => Some(capabilities::Targets::None),
+            (None, None) => None,
+        }
+    }};
+}
 
-    fn try_from(config: CapabilitiesConfig) -> Result<Self, Self::Error> {
-        let mut capabilities = Self::default();
-
-        if let Some(experimental) = config.experimental {
-            if let Some(allow) = experimental.allow {
-                if let Some(set) = allow.array {
-                    capabilities = capabilities.with_experimental(process_targets!(set));
-                } else {
-                    match allow.bool {
-                        Some(true) => {
-                            capabilities =
-                                capabilities.with_experimental(capabilities::Targets::All);
-                        }
-                        Some(false) => {
-                            capabilities =
-                                capabilities.with_experimental(capabilities::Targets::None);
-                        }
-                        None => (),
-                    }
+/// Applies the allow/deny lists of a `Targets` configuration,
+/// using the matching `with_*` and `without_*` methods of `Capabilities`.
+macro_rules! apply_targets {
+    ($capabilities:ident, $targets:expr, $with:ident, $without:ident, $name:literal, $errors:ident) => {
+        if let Some(targets) = $targets {
+            if let Some(allow) = &targets.allow {
+                if let Some(allow) = process_targets_config!(allow, $errors, $name) {
+                    $capabilities = $capabilities.$with(allow);
                 }
             }
 
-            if let Some(deny) = experimental.deny {
-                if let Some(set) = deny.array {
-                    capabilities = capabilities.without_experimental(process_targets!(set));
-                } else {
-                    match deny.bool {
-                        Some(true) => {
-                            capabilities =
-                                capabilities.without_experimental(capabilities::Targets::All);
-                        }
-                        Some(false) => {
-                            capabilities =
-                                capabilities.without_experimental(capabilities::Targets::None);
-                        }
-                        None => (),
-                    }
+            if let Some(deny) = &targets.deny {
+                if let Some(deny) = process_targets_config!(deny, $errors, $name) {
+                    $capabilities = $capabilities.$without(deny);
                 }
             }
         }
+    };
+}
+
+impl ConnectionOptions {
+    /// Decodes the CBOR options sent by C#.
+    pub fn from_bytes(bytes: &[u8]) -> Result<Self, EmbeddedError> {
+        let value = decode(bytes)
+            .map_err(|_| EmbeddedError::deserialization("Options are not valid CBOR."))?;
+
+        ConnectionOptions::try_from(&value)
+            .map_err(|e| EmbeddedError::invalid_params(format!("Invalid options: {}", e)))
+    }
+
+    /// Builds the datastore capabilities, reporting every invalid target at once.
+    pub fn capabilities(&self) -> Result<capabilities::Capabilities, EmbeddedError> {
+        let Some(config) = &self.capabilities else {
+            return Ok(Default::default());
+        };
+
+        let mut errors = Vec::new();
+        let capabilities = config.build(&mut errors);
+
+        if !errors.is_empty() {
+            return Err(EmbeddedError::invalid_params(format!(
+                "Invalid capabilities: {}",
+                errors.join("; ")
+            )));
+        }
+
+        Ok(capabilities)
+    }
+}
+
+impl CapabilitiesConfig {
+    /// Builds the datastore capabilities, collecting the invalid targets into `errors`.
+    fn build(&self, errors: &mut Vec<String>) -> capabilities::Capabilities {
+        let mut capabilities = capabilities::Capabilities::default();
+
+        if let Some(scripting) = self.scripting {
+            capabilities = capabilities.with_scripting(scripting);
+        }
+
+        if let Some(guest_access) = self.guest_access {
+            capabilities = capabilities.with_guest_access(guest_access);
+        }
+
+        apply_targets!(
+            capabilities,
+            &self.functions,
+            with_functions,
+            without_functions,
+            "Invalid function",
+            errors
+        );
+        apply_targets!(
+            capabilities,
+            &self.network_targets,
+            with_network_targets,
+            without_network_targets,
+            "Invalid network target",
+            errors
+        );
+        apply_targets!(
+            capabilities,
+            &self.rpc_methods,
+            with_rpc_methods,
+            without_rpc_methods,
+            "Invalid RPC method",
+            errors
+        );
+        apply_targets!(
+            capabilities,
+            &self.http_routes,
+            with_http_routes,
+            without_http_routes,
+            "Invalid HTTP route",
+            errors
+        );
+        apply_targets!(
+            capabilities,
+            &self.arbitrary_query,
+            with_arbitrary_query,
+            without_arbitrary_query,
+            "Invalid arbitrary query target",
+            errors
+        );
+        apply_targets!(
+            capabilities,
+            &self.experimental,
+            with_experimental,
+            without_experimental,
+            "Invalid experimental target",
+            errors
+        );
 
-        Ok(capabilities
-            // Always allow arbitrary quering in embedded mode,
-            // There is no use in configuring that here
-            .with_arbitrary_query(capabilities::Targets::All)
-            .without_arbitrary_query(capabilities::Targets::None))
+        capabilities
     }
 }
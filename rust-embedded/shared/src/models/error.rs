@@ -0,0 +1,131 @@
+use std::fmt;
+
+use surrealdb::rpc::types_error_from_anyhow;
+use surrealdb_types::{
+    Error as TypesError, QueryError, SerializationError, SurrealValue, ValidationError, Value,
+};
+
+#[repr(u8)]
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum ErrorKind {
+    Internal = 0,
+    NotFound = 1,
+    InvalidParams = 2,
+    TransactionConflict = 3,
+    ParseError = 4,
+    Permission = 5,
+    EngineMissing = 6,
+    Serialization = 7,
+    Cancelled = 8,
+    TimedOut = 9,
+}
+
+/// An error sent to C# through the failure callback.
+/// Encoded as a CBOR object: `{ kind, code, message, details }`.
+#[derive(Debug)]
+pub struct EmbeddedError {
+    kind: ErrorKind,
+    error: TypesError,
+}
+
+impl EmbeddedError {
+    pub fn engine_missing() -> Self {
+        Self {
+            kind: ErrorKind::EngineMissing,
+            error: TypesError::internal("Engine not found".to_string()),
+        }
+    }
+
+    pub fn runtime_missing() -> Self {
+        Self::from(TypesError::internal("Runtime not created".to_string()))
+    }
+
+    pub fn invalid_params(message: impl Into<String>) -> Self {
+        Self::from(surrealdb::rpc::invalid_params(message))
+    }
+
+    pub fn not_found(message: impl Into<String>) -> Self {
+        Self::from(TypesError::not_found(message.into(), None))
+    }
+
+    pub fn deserialization(message: impl Into<String>) -> Self {
+        Self::from(TypesError::serialization(
+            message.into(),
+            SerializationError::Deserialization,
+        ))
+    }
+
+    pub fn cancelled(message: impl Into<String>) -> Self {
+        Self::from(TypesError::query(message.into(), QueryError::Cancelled))
+    }
+
+    pub fn into_value(self) -> Value {
+        let message = self.error.message().to_string();
+
+        // The wire format of the error contains the RPC error code and the structured details
+        let Value::Object(mut error) = self.error.into_value() else {
+            unreachable!("An error is always serialized as an object")
+        };
+
+        let mut output = surrealdb_types::Object::new();
+        output.insert("kind".to_string(), (self.kind as u8 as i64).into_value());
+        output.insert(
+            "code".to_string(),
+            error.remove("code").unwrap_or(Value::None),
+        );
+        output.insert("message".to_string(), Value::String(message));
+        if let Some(details) = error.remove("details") {
+            output.insert("details".to_string(), details);
+        }
+
+        Value::Object(output)
+    }
+}
+
+impl From<TypesError> for EmbeddedError {
+    fn from(error: TypesError) -> Self {
+        let kind = if error.is_not_found() {
+            ErrorKind::NotFound
+        } else if error.is_not_allowed() {
+            ErrorKind::Permission
+        } else if error.is_serialization() {
+            ErrorKind::Serialization
+        } else {
+            match (error.validation_details(), error.query_details()) {
+                (Some(ValidationError::Parse), _) => ErrorKind::ParseError,
+                (Some(_), _) => ErrorKind::InvalidParams,
+                (_, Some(QueryError::TransactionConflict)) => ErrorKind::TransactionConflict,
+                (_, Some(QueryError::Cancelled)) => ErrorKind::Cancelled,
+                (_, Some(QueryError::TimedOut { .. })) => ErrorKind::TimedOut,
+                _ if error.is_validation() => ErrorKind::InvalidParams,
+                _ => ErrorKind::Internal,
+            }
+        };
+
+        Self { kind, error }
+    }
+}
+
+impl From<anyhow::Error> for EmbeddedError {
+    fn from(error: anyhow::Error) -> Self {
+        // Keep any context (e.g. "Cannot connect to db") as part of the message
+        let message = format!("{:#}", error);
+
+        let mut output = match error.downcast::<EmbeddedError>() {
+            Ok(error) => error,
+            Err(error) => Self::from(types_error_from_anyhow(error)),
+        };
+        if output.error.message() != message {
+            output.error = TypesError::from_details(message, output.error.details().clone());
+        }
+        output
+    }
+}
+
+impl fmt::Display for EmbeddedError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{}", self.error)
+    }
+}
+
+impl std::error::Error for EmbeddedError {}
@@ -23,6 +23,13 @@ pub enum Method {
     Begin = 21,
     Commit = 22,
     Cancel = 23,
+    Live = 24,
+    Kill = 25,
+    Signin = 26,
+    Signup = 27,
+    Authenticate = 28,
+    Invalidate = 29,
+    Info = 30,
 }
 
 impl From<Method> for surrealdb::rpc::Method {
@@ -51,6 +58,13 @@ impl From<Method> for surrealdb::rpc::Method {
             Method::Begin => surrealdb::rpc::Method::Begin,
             Method::Commit => surrealdb::rpc::Method::Commit,
             Method::Cancel => surrealdb::rpc::Method::Cancel,
+            Method::Live => surrealdb::rpc::Method::Live,
+            Method::Kill => surrealdb::rpc::Method::Kill,
+            Method::Signin => surrealdb::rpc::Method::Signin,
+            Method::Signup => surrealdb::rpc::Method::Signup,
+            Method::Authenticate => surrealdb::rpc::Method::Authenticate,
+            Method::Invalidate => surrealdb::rpc::Method::Invalidate,
+            Method::Info => surrealdb::rpc::Method::Info,
         }
     }
 }
@@ -1,2 +1,7 @@
+pub mod buffer_pool_options;
 pub mod connection_options;
+pub mod error;
 pub mod method;
+pub mod runtime_options;
+pub mod serve_options;
+pub mod transaction_options;
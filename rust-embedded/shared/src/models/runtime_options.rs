@@ -0,0 +1,83 @@
+use anyhow::anyhow;
+use surrealdb::rpc::format::cbor::decode;
+use surrealdb_types::{Number, Value};
+
+use super::error::EmbeddedError;
+
+#[derive(Debug, Default)]
+pub struct RuntimeOptions {
+    pub worker_threads: Option<usize>,
+    pub max_blocking_threads: Option<usize>,
+    pub thread_name: Option<String>,
+    pub thread_stack_size: Option<usize>,
+    /// single-threaded runtime, driven by a dedicated thread
+    pub current_thread: Option<bool>,
+}
+
+impl RuntimeOptions {
+    /// Decodes the CBOR options sent by C#, an empty buffer meaning default options.
+    pub fn from_bytes(bytes: &[u8]) -> Result<Self, EmbeddedError> {
+        if bytes.is_empty() {
+            return Ok(RuntimeOptions::default());
+        }
+
+        let value = decode(bytes)
+            .map_err(|_| EmbeddedError::deserialization("Options are not valid CBOR."))?;
+
+        RuntimeOptions::try_from(&value)
+            .map_err(|e| EmbeddedError::invalid_params(format!("Invalid runtime options: {}", e)))
+    }
+}
+
+fn get_size(value: Option<&Value>, name: &str) -> anyhow::Result<Option<usize>> {
+    match value {
+        None | Some(Value::None) | Some(Value::Null) => Ok(None),
+        Some(Value::Number(Number::Int(v))) if *v > 0 => Ok(Some(*v as usize)),
+        Some(_) => Err(anyhow!("{} must be a positive integer", name)),
+    }
+}
+
+impl TryFrom<&Value> for RuntimeOptions {
+    type Error = anyhow::Error;
+    fn try_from(value: &Value) -> Result<Self, Self::Error> {
+        match value {
+            Value::None | Value::Null => Ok(RuntimeOptions::default()),
+            Value::Object(obj) => {
+                let mut options = RuntimeOptions {
+                    worker_threads: get_size(obj.get("worker_threads"), "worker_threads")?,
+                    max_blocking_threads: get_size(
+                        obj.get("max_blocking_threads"),
+                        "max_blocking_threads",
+                    )?,
+                    thread_stack_size: get_size(obj.get("thread_stack_size"), "thread_stack_size")?,
+                    ..Default::default()
+                };
+
+                match obj.get("thread_name") {
+                    Some(Value::None) => (),
+                    Some(Value::String(v)) => {
+                        options.thread_name = Some(v.to_owned());
+                    }
+                    Some(_) => {
+                        return Err(anyhow!("Failed to convert to string"));
+                    }
+                    _ => (),
+                }
+
+                match obj.get("current_thread") {
+                    Some(Value::None) => (),
+                    Some(Value::Bool(v)) => {
+                        options.current_thread = Some(v.to_owned());
+                    }
+                    Some(_) => {
+                        return Err(anyhow!("Failed to convert to boolean"));
+                    }
+                    _ => (),
+                }
+
+                Ok(options)
+            }
+            _ => Err(anyhow!("Failed to convert to object")),
+        }
+    }
+}
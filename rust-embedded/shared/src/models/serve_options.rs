@@ -0,0 +1,79 @@
+use std::net::SocketAddr;
+
+use anyhow::anyhow;
+use surrealdb::rpc::format::cbor::decode;
+use surrealdb_types::Value;
+
+use super::error::EmbeddedError;
+
+/// Address bound by default, only reachable from the local machine
+const DEFAULT_ADDRESS: &str = "127.0.0.1:8000";
+
+#[derive(Debug, Clone)]
+pub struct ServeOptions {
+    /// port 0 binds any available port
+    pub address: SocketAddr,
+    /// requires clients to sign in, rather than giving them owner rights
+    pub auth: bool,
+}
+
+impl Default for ServeOptions {
+    fn default() -> Self {
+        Self {
+            address: DEFAULT_ADDRESS.parse().expect("valid default address"),
+            auth: true,
+        }
+    }
+}
+
+impl ServeOptions {
+    /// Decodes the CBOR options sent by C#, an empty buffer meaning default options.
+    pub fn from_bytes(bytes: &[u8]) -> Result<Self, EmbeddedError> {
+        if bytes.is_empty() {
+            return Ok(ServeOptions::default());
+        }
+
+        let value = decode(bytes)
+            .map_err(|_| EmbeddedError::deserialization("Options are not valid CBOR."))?;
+
+        ServeOptions::try_from(&value)
+            .map_err(|e| EmbeddedError::invalid_params(format!("Invalid serve options: {}", e)))
+    }
+}
+
+impl TryFrom<&Value> for ServeOptions {
+    type Error = anyhow::Error;
+    fn try_from(value: &Value) -> Result<Self, Self::Error> {
+        match value {
+            Value::None | Value::Null => Ok(ServeOptions::default()),
+            Value::Object(obj) => {
+                let mut options = ServeOptions::default();
+
+                match obj.get("address") {
+                    None | Some(Value::None) | Some(Value::Null) => (),
+                    Some(Value::String(v)) => {
+                        options.address = v
+                            .parse()
+                            .map_err(|_| anyhow!("address must be an IP address and a port"))?;
+                    }
+                    Some(_) => {
+                        return Err(anyhow!("address must be a string"));
+                    }
+                }
+
+                match obj.get("auth") {
+                    None | Some(Value::None) | Some(Value::Null) => (),
+                    Some(Value::Bool(v)) => {
+                        options.auth = *v;
+                    }
+                    Some(_) => {
+                        return Err(anyhow!("auth must be a boolean"));
+                    }
+                }
+
+                Ok(options)
+            }
+            _ => Err(anyhow!("Failed to convert to object")),
+        }
+    }
+}
@@ -0,0 +1,62 @@
+use anyhow::anyhow;
+use surrealdb::kvs::{LockType, TransactionType};
+use surrealdb_types::Value;
+
+/// Options of the "begin" method, sent as its first (optional) parameter.
+#[derive(Debug, Default)]
+pub struct TransactionOptions {
+    /// read-only transactions never conflict, as they read from a snapshot
+    pub read_only: Option<bool>,
+    /// locks the keys when read/written, instead of checking for conflicts on commit
+    pub pessimistic: Option<bool>,
+}
+
+impl TryFrom<Option<&Value>> for TransactionOptions {
+    type Error = anyhow::Error;
+    fn try_from(value: Option<&Value>) -> Result<Self, Self::Error> {
+        match value {
+            None | Some(Value::None) | Some(Value::Null) => Ok(TransactionOptions::default()),
+            Some(Value::Object(obj)) => {
+                let mut options = TransactionOptions::default();
+
+                match obj.get("read_only") {
+                    Some(Value::None) => (),
+                    Some(Value::Bool(v)) => {
+                        options.read_only = Some(v.to_owned());
+                    }
+                    Some(_) => {
+                        return Err(anyhow!("Failed to convert to boolean"));
+                    }
+                    _ => (),
+                }
+
+                match obj.get("pessimistic") {
+                    Some(Value::None) => (),
+                    Some(Value::Bool(v)) => {
+                        options.pessimistic = Some(v.to_owned());
+                    }
+                    Some(_) => {
+                        return Err(anyhow!("Failed to convert to boolean"));
+                    }
+                    _ => (),
+                }
+
+                Ok(options)
+            }
+            Some(_) => Err(anyhow!("Failed to convert to object")),
+        }
+    }
+}
+
+impl TransactionOptions {
+    pub fn transaction_type(&self) -> TransactionType {
+        match self.read_only {
+            Some(true) => TransactionType::Read,
+            _ => TransactionType::Write,
+        }
+    }
+
+    pub fn lock_type(&self) -> LockType {
+        self.pessimistic.unwrap_or_default().into()
+    }
+}
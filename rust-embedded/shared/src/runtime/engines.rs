@@ -1,15 +1,74 @@
+use dashmap::DashMap;
 use once_cell::sync::Lazy;
+use uuid::Uuid;
 
-use crate::app::SurrealEmbeddedEngines;
+use crate::app::{ImportSession, SurrealEmbeddedEngines};
+use crate::bindgen::callback::{FailureAction, SuccessAction, send_failure, send_success};
 
 use super::get_global_runtime;
+use super::logs::with_engine_id;
+use super::servers::stop_servers;
 
 pub static ENGINES: Lazy<SurrealEmbeddedEngines> = Lazy::new(SurrealEmbeddedEngines::new);
 
+/// Chunked imports in progress, started via `begin_import`
+pub static IMPORTS: Lazy<DashMap<Uuid, ImportSession>> = Lazy::new(DashMap::new);
+
+/// Aborts pending chunked imports (of the matching engines), as they hold the engine until finished.
+fn abort_imports(predicate: impl Fn(i32) -> bool) {
+    let import_ids = IMPORTS
+        .iter()
+        .filter(|import| predicate(import.engine_id()))
+        .map(|import| *import.key())
+        .collect::<Vec<_>>();
+    for import_id in import_ids {
+        if let Some((_, import)) = IMPORTS.remove(&import_id) {
+            import.abort();
+        }
+    }
+}
+
+/// Disposes every engine, e.g. before shutting the runtime down.
+pub async fn dispose_all() {
+    abort_imports(|_| true);
+    stop_servers(|_| true).await;
+
+    for engine in ENGINES.remove_all().await {
+        let _ = engine.close().await;
+    }
+}
+
+/// Disposes a SurrealDB engine (given its id), once the requests being executed on it have completed.
+/// Requests made after this call fail as if the engine was missing, and requests on other engines are not delayed.
+/// Open transactions are cancelled and the datastore is shut down before the success callback is invoked,
+/// so the same storage can be reopened right away.
 #[unsafe(no_mangle)]
-pub extern "C" fn dispose(id: i32) {
-    // TODO : impl drop for Surreal
-    get_global_runtime().spawn(async move {
-        ENGINES.remove(id).await;
-    });
+pub extern "C" fn dispose(id: i32, success: SuccessAction, failure: FailureAction) {
+    let runtime = match get_global_runtime() {
+        Ok(runtime) => runtime,
+        Err(error) => {
+            send_failure(error, failure);
+            return;
+        }
+    };
+
+    runtime.spawn(with_engine_id(id, async move {
+        abort_imports(|engine_id| engine_id == id);
+        stop_servers(|engine_id| engine_id == id).await;
+
+        // Removed right away, closing then waits for the requests being executed on this engine only
+        let result = match ENGINES.remove(id).await {
+            Some(engine) => engine.close().await,
+            None => Ok(()),
+        };
+
+        match result {
+            Ok(()) => {
+                send_success(vec![], success);
+            }
+            Err(error) => {
+                send_failure(error, failure);
+            }
+        }
+    }));
 }
@@ -0,0 +1,281 @@
+use std::cell::Cell;
+use std::fmt::Debug;
+use std::future::Future;
+use std::sync::{Arc, RwLock};
+
+use once_cell::sync::OnceCell;
+use surrealdb::rpc::format::cbor::encode;
+use surrealdb_types::{Number, Object, Value};
+use tracing::field::{Field, Visit};
+use tracing::{Event, Level, Subscriber};
+use tracing_subscriber::layer::{Context, SubscriberExt};
+use tracing_subscriber::{EnvFilter, Layer, Registry, reload};
+
+use crate::bindgen::alloc::alloc_u8_buffer;
+use crate::bindgen::callback::{
+    FailureAction, LogAction, SuccessAction, send_failure, send_success,
+};
+use crate::bindgen::csharp_to_rust::convert_csharp_to_rust_string_utf16;
+use crate::bindgen::free::free_u8_buffer;
+use crate::models::error::EmbeddedError;
+
+/// Filter applied when no directive is given
+const DEFAULT_FILTER: &str = "info";
+
+static LOG_ACTION: RwLock<Option<Arc<LogAction>>> = RwLock::new(None);
+
+/// Handle to change the filter of the installed subscriber
+static FILTER: OnceCell<reload::Handle<EnvFilter, Registry>> = OnceCell::new();
+
+tokio::task_local! {
+    /// The engine a request is executed for, attached to the events it emits
+    static ENGINE_ID: i32;
+}
+
+thread_local! {
+    /// Set while forwarding an event, so events emitted meanwhile are dropped rather than forwarded recursively
+    static FORWARDING: Cell<bool> = const { Cell::new(false) };
+}
+
+/// Runs a future on behalf of a SurrealDB engine (given its id), so its events carry the engine id.
+pub fn with_engine_id<F: Future>(id: i32, future: F) -> impl Future<Output = F::Output> {
+    ENGINE_ID.scope(id, future)
+}
+
+/// Parses filter directives (e.g. "warn" or "surrealdb=debug,shared=trace"), an empty string meaning the default filter.
+fn parse_filter(directives: &str) -> Result<EnvFilter, EmbeddedError> {
+    let directives = match directives.trim() {
+        "" => DEFAULT_FILTER,
+        directives => directives,
+    };
+
+    EnvFilter::try_new(directives).map_err(|e| {
+        EmbeddedError::invalid_params(format!("Invalid log filter \"{}\": {}", directives, e))
+    })
+}
+
+/// Applies a filter, installing the forwarding subscriber the first time.
+fn apply_filter(filter: EnvFilter) -> Result<(), EmbeddedError> {
+    let mut filter = Some(filter);
+
+    let handle = FILTER.get_or_try_init(|| {
+        let (layer, handle) = reload::Layer::new(filter.take().unwrap_or_default());
+        let subscriber = Registry::default().with(layer).with(LogForwarder);
+        tracing::subscriber::set_global_default(subscriber)
+            .map_err(|e| EmbeddedError::from(anyhow::Error::from(e)))?;
+        Ok::<_, EmbeddedError>(handle)
+    })?;
+
+    if let Some(filter) = filter {
+        handle
+            .reload(filter)
+            .map_err(|e| EmbeddedError::from(anyhow::Error::from(e)))?;
+    }
+    Ok(())
+}
+
+/// Registers the callback used to forward log events to C#, along with the filter directives.
+/// Registering a new callback drops the previous one.
+pub fn register_log_forwarding(action: LogAction, directives: &str) -> Result<(), EmbeddedError> {
+    let filter = parse_filter(directives)?;
+
+    let previous = LOG_ACTION
+        .write()
+        .unwrap_or_else(|e| e.into_inner())
+        .replace(Arc::new(action));
+    drop(previous);
+
+    apply_filter(filter)
+}
+
+/// Changes the filter directives of the forwarded log events.
+pub fn set_log_filter_directives(directives: &str) -> Result<(), EmbeddedError> {
+    apply_filter(parse_filter(directives)?)
+}
+
+/// Drops the registered callback, if any, and stops collecting events.
+pub fn unregister_log_action() {
+    let previous = LOG_ACTION.write().unwrap_or_else(|e| e.into_inner()).take();
+    drop(previous);
+
+    if let Some(handle) = FILTER.get() {
+        let _ = handle.reload(EnvFilter::new("off"));
+    }
+}
+
+/// # Safety
+///
+/// Registers the callback used to forward log events (of the native library and SurrealDB) to C#,
+/// keeping the events matching the given filter directives (e.g. "warn" or "surrealdb=debug,shared=trace").
+/// Each event is a CBOR object: `{ level, target, message, fields, engine_id }`, the level matching the .NET `LogLevel`
+/// and the engine id being omitted for events not emitted on behalf of an engine.
+/// The buffer given to the callback is freed once it returns.
+/// Registering a new callback drops the previous one.
+#[unsafe(no_mangle)]
+pub unsafe extern "C" fn register_log_action(
+    action: LogAction,
+    utf16_str: *const u16,
+    utf16_len: usize,
+    success: SuccessAction,
+    failure: FailureAction,
+) {
+    let directives = match unsafe { convert_csharp_to_rust_string_utf16(utf16_str, utf16_len) } {
+        Ok(directives) => directives,
+        Err(error) => {
+            send_failure(error, failure);
+            return;
+        }
+    };
+
+    match register_log_forwarding(action, &directives) {
+        Ok(()) => {
+            send_success(vec![], success);
+        }
+        Err(error) => {
+            send_failure(error, failure);
+        }
+    }
+}
+
+/// # Safety
+///
+/// Changes the filter directives (e.g. "warn" or "surrealdb=debug,shared=trace") of the forwarded log events.
+#[unsafe(no_mangle)]
+pub unsafe extern "C" fn set_log_filter(
+    utf16_str: *const u16,
+    utf16_len: usize,
+    success: SuccessAction,
+    failure: FailureAction,
+) {
+    let directives = match unsafe { convert_csharp_to_rust_string_utf16(utf16_str, utf16_len) } {
+        Ok(directives) => directives,
+        Err(error) => {
+            send_failure(error, failure);
+            return;
+        }
+    };
+
+    match set_log_filter_directives(&directives) {
+        Ok(()) => {
+            send_success(vec![], success);
+        }
+        Err(error) => {
+            send_failure(error, failure);
+        }
+    }
+}
+
+/// The .NET `LogLevel` of an event
+fn log_level(level: &Level) -> i64 {
+    match *level {
+        Level::TRACE => 0,
+        Level::DEBUG => 1,
+        Level::INFO => 2,
+        Level::WARN => 3,
+        Level::ERROR => 4,
+    }
+}
+
+#[derive(Default)]
+struct EventVisitor {
+    message: Option<String>,
+    fields: Object,
+}
+
+impl EventVisitor {
+    fn record(&mut self, field: &Field, value: Value) {
+        match value {
+            Value::String(message) if field.name() == "message" => {
+                self.message = Some(message);
+            }
+            value => {
+                self.fields.insert(field.name().to_string(), value);
+            }
+        }
+    }
+}
+
+impl Visit for EventVisitor {
+    fn record_f64(&mut self, field: &Field, value: f64) {
+        self.record(field, Value::Number(Number::Float(value)));
+    }
+
+    fn record_i64(&mut self, field: &Field, value: i64) {
+        self.record(field, Value::Number(Number::Int(value)));
+    }
+
+    fn record_u64(&mut self, field: &Field, value: u64) {
+        match i64::try_from(value) {
+            Ok(value) => self.record(field, Value::Number(Number::Int(value))),
+            Err(_) => self.record(field, Value::String(value.to_string())),
+        }
+    }
+
+    fn record_bool(&mut self, field: &Field, value: bool) {
+        self.record(field, Value::Bool(value));
+    }
+
+    fn record_str(&mut self, field: &Field, value: &str) {
+        self.record(field, Value::String(value.to_string()));
+    }
+
+    fn record_debug(&mut self, field: &Field, value: &dyn Debug) {
+        self.record(field, Value::String(format!("{:?}", value)));
+    }
+}
+
+/// Forwards every event (that passed the filter) to the registered callback.
+struct LogForwarder;
+
+impl<S: Subscriber> Layer<S> for LogForwarder {
+    fn on_event(&self, event: &Event<'_>, _: Context<'_, S>) {
+        if FORWARDING.with(|forwarding| forwarding.replace(true)) {
+            return;
+        }
+
+        forward_event(event);
+
+        FORWARDING.with(|forwarding| forwarding.set(false));
+    }
+}
+
+fn forward_event(event: &Event<'_>) {
+    // The lock is released before calling C#, which may register another callback meanwhile
+    let action = LOG_ACTION.read().unwrap_or_else(|e| e.into_inner()).clone();
+    let Some(action) = action else {
+        return;
+    };
+
+    let metadata = event.metadata();
+    let mut visitor = EventVisitor::default();
+    event.record(&mut visitor);
+
+    let mut output = Object::new();
+    output.insert(
+        "level".to_string(),
+        Value::Number(Number::Int(log_level(metadata.level()))),
+    );
+    output.insert(
+        "target".to_string(),
+        Value::String(metadata.target().to_string()),
+    );
+    output.insert(
+        "message".to_string(),
+        Value::String(visitor.message.unwrap_or_default()),
+    );
+    output.insert("fields".to_string(), Value::Object(visitor.fields));
+    if let Ok(id) = ENGINE_ID.try_with(|id| *id) {
+        output.insert(
+            "engine_id".to_string(),
+            Value::Number(Number::Int(id as i64)),
+        );
+    }
+
+    if let Ok(bytes) = encode(Value::Object(output)) {
+        let buffer = alloc_u8_buffer(bytes);
+        unsafe {
+            action.invoke(buffer);
+            free_u8_buffer(buffer);
+        }
+    }
+}
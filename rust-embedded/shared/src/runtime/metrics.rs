@@ -0,0 +1,224 @@
+use std::future::Future;
+use std::sync::atomic::{AtomicU64, Ordering};
+use std::time::{Duration, Instant};
+
+use dashmap::DashMap;
+use once_cell::sync::Lazy;
+use surrealdb::rpc::format::cbor::encode;
+use surrealdb_types::{Array, Object, Value};
+use tokio::runtime::Handle;
+
+use crate::bindgen::callback::{FailureAction, SuccessAction};
+
+use super::engines::ENGINES;
+use super::get_global_runtime;
+use super::requests::spawn_request;
+
+/// Upper bounds (in microseconds) of the request latency buckets, slower requests falling in a last bucket
+const LATENCY_BOUNDS_US: [u64; 16] = [
+    100, 250, 500, 1_000, 2_500, 5_000, 10_000, 25_000, 50_000, 100_000, 250_000, 500_000,
+    1_000_000, 2_500_000, 5_000_000, 10_000_000,
+];
+
+/// Bytes copied from .NET buffers
+static BYTES_IN: AtomicU64 = AtomicU64::new(0);
+/// Bytes of the buffers handed over to .NET
+static BYTES_OUT: AtomicU64 = AtomicU64::new(0);
+
+/// Requests being executed, per engine id
+static IN_FLIGHT: Lazy<DashMap<i32, u64>> = Lazy::new(DashMap::new);
+
+/// Latency histograms, per request name (e.g. "query" or "import")
+static LATENCIES: Lazy<DashMap<String, LatencyHistogram>> = Lazy::new(DashMap::new);
+
+pub fn record_bytes_in(len: usize) {
+    BYTES_IN.fetch_add(len as u64, Ordering::Relaxed);
+}
+
+pub fn record_bytes_out(len: usize) {
+    BYTES_OUT.fetch_add(len as u64, Ordering::Relaxed);
+}
+
+/// Records the time taken to execute a request (given its name).
+pub fn record_latency(request: &str, elapsed: Duration) {
+    match LATENCIES.get_mut(request) {
+        Some(mut histogram) => histogram.record(elapsed),
+        None => LATENCIES
+            .entry(request.to_string())
+            .or_default()
+            .record(elapsed),
+    }
+}
+
+#[derive(Default)]
+struct LatencyHistogram {
+    count: u64,
+    total_us: u64,
+    buckets: [u64; LATENCY_BOUNDS_US.len() + 1],
+}
+
+impl LatencyHistogram {
+    fn record(&mut self, elapsed: Duration) {
+        let elapsed_us = u64::try_from(elapsed.as_micros()).unwrap_or(u64::MAX);
+        let bucket = LATENCY_BOUNDS_US.partition_point(|bound| *bound < elapsed_us);
+
+        self.count += 1;
+        self.total_us = self.total_us.saturating_add(elapsed_us);
+        self.buckets[bucket] += 1;
+    }
+
+    fn to_value(&self) -> Value {
+        let mut histogram = Object::new();
+        histogram.insert("count".to_string(), Value::from_t(self.count as i64));
+        histogram.insert("total_us".to_string(), Value::from_t(self.total_us as i64));
+        histogram.insert(
+            "buckets".to_string(),
+            Value::Array(Array::from(
+                self.buckets
+                    .iter()
+                    .map(|count| Value::from_t(*count as i64))
+                    .collect::<Vec<_>>(),
+            )),
+        );
+        Value::Object(histogram)
+    }
+}
+
+/// Counts a request of an engine (given its id) as in flight, until completed or cancelled.
+struct InFlightRequest(i32);
+
+impl InFlightRequest {
+    fn start(id: i32) -> Self {
+        *IN_FLIGHT.entry(id).or_default() += 1;
+        Self(id)
+    }
+}
+
+impl Drop for InFlightRequest {
+    fn drop(&mut self) {
+        IN_FLIGHT.remove_if_mut(&self.0, |_, count| {
+            *count -= 1;
+            *count == 0
+        });
+    }
+}
+
+/// Runs a request of an engine (given its id), counted as in flight until completed or cancelled.
+/// The latency of a completed request is recorded under its name.
+pub async fn track_request<F: Future>(id: i32, request: String, future: F) -> F::Output {
+    let _request = InFlightRequest::start(id);
+    let started_at = Instant::now();
+    let output = future.await;
+    record_latency(&request, started_at.elapsed());
+    output
+}
+
+/// Current state of an engine, as reported by `get_metrics`
+pub struct EngineMetrics {
+    /// including the default session
+    pub sessions: usize,
+    pub transactions: usize,
+    pub live_queries: usize,
+}
+
+impl EngineMetrics {
+    fn into_value(self, id: i32) -> Value {
+        let requests = IN_FLIGHT.get(&id).map(|count| *count).unwrap_or_default();
+
+        let mut engine = Object::new();
+        engine.insert("id".to_string(), Value::from_t(id as i64));
+        engine.insert("sessions".to_string(), Value::from_t(self.sessions as i64));
+        engine.insert(
+            "transactions".to_string(),
+            Value::from_t(self.transactions as i64),
+        );
+        engine.insert(
+            "live_queries".to_string(),
+            Value::from_t(self.live_queries as i64),
+        );
+        engine.insert("requests".to_string(), Value::from_t(requests as i64));
+        Value::Object(engine)
+    }
+}
+
+fn runtime_metrics(runtime: &Handle) -> Value {
+    let metrics = runtime.metrics();
+    let workers = metrics.num_workers();
+
+    let busy_duration = (0..workers)
+        .map(|worker| metrics.worker_total_busy_duration(worker))
+        .sum::<Duration>();
+    let park_count = (0..workers)
+        .map(|worker| metrics.worker_park_count(worker))
+        .sum::<u64>();
+
+    let mut output = Object::new();
+    output.insert("workers".to_string(), Value::from_t(workers as i64));
+    output.insert(
+        "alive_tasks".to_string(),
+        Value::from_t(metrics.num_alive_tasks() as i64),
+    );
+    output.insert(
+        "global_queue_depth".to_string(),
+        Value::from_t(metrics.global_queue_depth() as i64),
+    );
+    output.insert(
+        "busy_duration_us".to_string(),
+        Value::from_t(busy_duration.as_micros() as i64),
+    );
+    output.insert("park_count".to_string(), Value::from_t(park_count as i64));
+    Value::Object(output)
+}
+
+/// Collects the metrics of every engine, request and of the runtime.
+pub async fn collect_metrics(runtime: &Handle) -> Value {
+    let engines = ENGINES
+        .metrics()
+        .await
+        .into_iter()
+        .map(|(id, metrics)| metrics.into_value(id))
+        .collect::<Vec<_>>();
+
+    let mut latencies = Object::new();
+    for histogram in LATENCIES.iter() {
+        latencies.insert(histogram.key().clone(), histogram.to_value());
+    }
+
+    let mut output = Object::new();
+    output.insert("engines".to_string(), Value::Array(Array::from(engines)));
+    output.insert(
+        "latency_bounds_us".to_string(),
+        Value::Array(Array::from(
+            LATENCY_BOUNDS_US
+                .iter()
+                .map(|bound| Value::from_t(*bound as i64))
+                .collect::<Vec<_>>(),
+        )),
+    );
+    output.insert("latencies".to_string(), Value::Object(latencies));
+    output.insert(
+        "bytes_in".to_string(),
+        Value::from_t(BYTES_IN.load(Ordering::Relaxed) as i64),
+    );
+    output.insert(
+        "bytes_out".to_string(),
+        Value::from_t(BYTES_OUT.load(Ordering::Relaxed) as i64),
+    );
+    output.insert("runtime".to_string(), runtime_metrics(runtime));
+    Value::Object(output)
+}
+
+/// Sends the metrics of the native library to the success callback, as a CBOR object:
+/// - `engines`: the sessions, open transactions, live queries and in-flight requests of each engine
+/// - `latencies`: a histogram of the execution time of each request (method, "import", "export", "backup", ...), with `latency_bounds_us` as bucket bounds
+/// - `bytes_in` and `bytes_out`: the bytes exchanged with .NET since the library was loaded
+/// - `runtime`: the workers, alive tasks, queue depth, busy duration and park count of the runtime
+///
+/// Returns the request id, which can be used to cancel the request.
+#[unsafe(no_mangle)]
+pub extern "C" fn get_metrics(success: SuccessAction, failure: FailureAction) -> u64 {
+    spawn_request(success, failure, async move {
+        let runtime = get_global_runtime()?;
+        encode(collect_metrics(&runtime).await)
+    })
+}
@@ -1,26 +1,160 @@
 pub mod engines;
+pub mod logs;
+pub mod metrics;
+pub mod notifications;
+pub mod requests;
+pub mod servers;
 
-use once_cell::sync::OnceCell;
-use tokio::runtime::{Builder, Runtime};
+use std::sync::{Arc, RwLock};
+use std::thread::JoinHandle;
+use std::time::Duration;
 
-pub static RUNTIME: OnceCell<Runtime> = OnceCell::new();
+use tokio::runtime::{Builder, Handle, Runtime};
+use tokio::sync::oneshot;
+
+use crate::bindgen::callback::{FailureAction, SuccessAction, send_failure, send_success};
+use crate::bindgen::csharp_to_rust::convert_csharp_to_rust_bytes;
+use crate::models::error::EmbeddedError;
+use crate::models::runtime_options::RuntimeOptions;
+
+/// Time given to the remaining tasks to complete when the runtime is shut down
+const SHUTDOWN_TIMEOUT: Duration = Duration::from_secs(10);
+
+static RUNTIME: RwLock<Option<GlobalRuntime>> = RwLock::new(None);
+
+struct GlobalRuntime {
+    runtime: Arc<Runtime>,
+    /// Thread driving a current-thread runtime, along with the signal to stop it
+    driver: Option<(oneshot::Sender<()>, JoinHandle<()>)>,
+}
+
+impl GlobalRuntime {
+    fn build(options: RuntimeOptions) -> std::io::Result<Self> {
+        let current_thread = options.current_thread.unwrap_or_default();
+
+        let mut builder = if current_thread {
+            Builder::new_current_thread()
+        } else {
+            let mut builder = Builder::new_multi_thread();
+            builder.worker_threads(options.worker_threads.unwrap_or_else(num_cpus::get));
+            builder
+        };
+        builder.enable_all();
+        if let Some(max_blocking_threads) = options.max_blocking_threads {
+            builder.max_blocking_threads(max_blocking_threads);
+        }
+        if let Some(thread_name) = &options.thread_name {
+            builder.thread_name(thread_name);
+        }
+        if let Some(thread_stack_size) = options.thread_stack_size {
+            builder.thread_stack_size(thread_stack_size);
+        }
+
+        let runtime = Arc::new(builder.build()?);
+
+        // Tasks spawned on a current-thread runtime only make progress while it is blocked on
+        let driver = if current_thread {
+            let (stop, stopped) = oneshot::channel::<()>();
+            let driven = runtime.clone();
+
+            let mut thread = std::thread::Builder::new();
+            if let Some(thread_name) = options.thread_name {
+                thread = thread.name(thread_name);
+            }
+            if let Some(thread_stack_size) = options.thread_stack_size {
+                thread = thread.stack_size(thread_stack_size);
+            }
+            let handle = thread.spawn(move || {
+                driven.block_on(async {
+                    let _ = stopped.await;
+                });
+            })?;
+
+            Some((stop, handle))
+        } else {
+            None
+        };
+
+        Ok(Self { runtime, driver })
+    }
+
+    fn shutdown(self) {
+        if let Some((stop, driver)) = self.driver {
+            let _ = stop.send(());
+            let _ = driver.join();
+        }
+
+        if let Ok(runtime) = Arc::try_unwrap(self.runtime) {
+            runtime.shutdown_timeout(SHUTDOWN_TIMEOUT);
+        }
+    }
+}
 
 /// # Safety
 ///
-/// This function is called to initialize the async runtime (using tokio).
+/// This function is called to initialize the async runtime (using tokio), given its options.
+/// An empty buffer stands for the default options.
+/// Does nothing if the runtime has already been created.
 #[unsafe(no_mangle)]
-pub extern "C" fn create_global_runtime() {
-    RUNTIME
-        .set(
-            Builder::new_multi_thread()
-                .worker_threads(num_cpus::get())
-                .enable_all()
-                .build()
-                .unwrap(),
-        )
-        .unwrap()
+pub unsafe extern "C" fn create_global_runtime(
+    bytes: *const u8,
+    len: usize,
+    success: SuccessAction,
+    failure: FailureAction,
+) {
+    let opts_bytes = unsafe { convert_csharp_to_rust_bytes(bytes, len) };
+
+    match RuntimeOptions::from_bytes(&opts_bytes).and_then(init_global_runtime) {
+        Ok(()) => {
+            send_success(vec![], success);
+        }
+        Err(error) => {
+            send_failure(error, failure);
+        }
+    }
 }
 
-pub fn get_global_runtime<'local>() -> &'local Runtime {
-    unsafe { RUNTIME.get_unchecked() }
+/// Creates the async runtime, unless it has already been created.
+pub fn init_global_runtime(options: RuntimeOptions) -> Result<(), EmbeddedError> {
+    let mut lock = RUNTIME.write().unwrap_or_else(|e| e.into_inner());
+    if lock.is_none() {
+        let runtime = GlobalRuntime::build(options).map_err(anyhow::Error::from)?;
+        *lock = Some(runtime);
+    }
+    Ok(())
+}
+
+/// Shuts the async runtime down, cancelling pending requests and disposing every engine first.
+/// The notification and log callbacks are released as well, so the .NET assembly can be unloaded.
+/// The runtime can be created again afterwards.
+#[unsafe(no_mangle)]
+pub extern "C" fn shutdown_global_runtime() {
+    let Some(runtime) = RUNTIME.write().unwrap_or_else(|e| e.into_inner()).take() else {
+        return;
+    };
+
+    // Blocking on (or shutting down) a runtime panics within an async context, so a dedicated thread is used
+    if Handle::try_current().is_ok() {
+        let _ = std::thread::spawn(move || shutdown(runtime)).join();
+    } else {
+        shutdown(runtime);
+    }
+}
+
+fn shutdown(runtime: GlobalRuntime) {
+    requests::cancel_all_requests();
+    runtime.runtime.block_on(engines::dispose_all());
+    notifications::unregister_notification_action();
+    logs::unregister_log_action();
+
+    runtime.shutdown();
+}
+
+pub fn get_global_runtime() -> Result<Handle, EmbeddedError> {
+    RUNTIME
+        .read()
+        .unwrap_or_else(|e| e.into_inner())
+        .as_ref()
+        .map(|runtime| runtime.runtime.handle().clone())
+        .ok_or_else(EmbeddedError::runtime_missing)
 }
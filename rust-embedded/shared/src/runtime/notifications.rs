@@ -0,0 +1,40 @@
+use std::sync::{Arc, RwLock};
+
+use crate::bindgen::{alloc::alloc_u8_buffer, callback::NotificationAction};
+
+static NOTIFICATION_ACTION: RwLock<Option<Arc<NotificationAction>>> = RwLock::new(None);
+
+/// Registers the callback used to push live query notifications (of every engine) to C#.
+/// Registering a new callback drops the previous one, once no notification is being sent with it.
+#[unsafe(no_mangle)]
+pub extern "C" fn register_notification_action(action: NotificationAction) {
+    let previous = NOTIFICATION_ACTION
+        .write()
+        .unwrap_or_else(|e| e.into_inner())
+        .replace(Arc::new(action));
+    drop(previous);
+}
+
+/// Drops the registered callback, if any.
+pub fn unregister_notification_action() {
+    let previous = NOTIFICATION_ACTION
+        .write()
+        .unwrap_or_else(|e| e.into_inner())
+        .take();
+    drop(previous);
+}
+
+/// Sends a live query notification of a SurrealDB engine (given its id).
+/// Notifications are dropped if no callback has been registered.
+pub fn send_notification(id: i32, bytes: Vec<u8>) {
+    // The lock is released before calling C#, which may register another callback meanwhile
+    let action = NOTIFICATION_ACTION
+        .read()
+        .unwrap_or_else(|e| e.into_inner())
+        .clone();
+
+    if let Some(action) = action {
+        let buffer = alloc_u8_buffer(bytes);
+        unsafe { action.invoke(id, buffer) };
+    }
+}
@@ -0,0 +1,118 @@
+use std::future::Future;
+use std::sync::atomic::{AtomicU64, Ordering};
+
+use dashmap::DashMap;
+use futures::future::{AbortHandle, Abortable};
+use once_cell::sync::Lazy;
+
+use crate::bindgen::callback::{FailureAction, SuccessAction, send_failure, send_success};
+use crate::models::error::EmbeddedError;
+
+use super::get_global_runtime;
+use super::logs::with_engine_id;
+use super::metrics::track_request;
+
+/// Error message sent to the failure callback of a cancelled request
+pub const REQUEST_CANCELLED: &str = "Request cancelled";
+
+/// Request id returned when a request fails before being started
+pub const NO_REQUEST: u64 = 0;
+
+static NEXT_REQUEST_ID: AtomicU64 = AtomicU64::new(NO_REQUEST + 1);
+
+static REQUESTS: Lazy<DashMap<u64, PendingRequest>> = Lazy::new(DashMap::new);
+
+struct PendingRequest {
+    abort: AbortHandle,
+    success: SuccessAction,
+    failure: FailureAction,
+}
+
+/// Spawns a request on the global runtime and returns its id, so it can be cancelled.
+/// Exactly one of the callbacks is invoked, either once the request completes or when cancelled.
+pub fn spawn_request<F>(success: SuccessAction, failure: FailureAction, future: F) -> u64
+where
+    F: Future<Output = anyhow::Result<Vec<u8>>> + Send + 'static,
+{
+    let runtime = match get_global_runtime() {
+        Ok(runtime) => runtime,
+        Err(error) => {
+            send_failure(error, failure);
+            return NO_REQUEST;
+        }
+    };
+
+    let id = NEXT_REQUEST_ID.fetch_add(1, Ordering::Relaxed);
+
+    let (abort, registration) = AbortHandle::new_pair();
+    REQUESTS.insert(
+        id,
+        PendingRequest {
+            abort,
+            success,
+            failure,
+        },
+    );
+
+    runtime.spawn(async move {
+        let Ok(result) = Abortable::new(future, registration).await else {
+            // Cancelled, the failure callback has already been invoked
+            return;
+        };
+        let Some((_, request)) = REQUESTS.remove(&id) else {
+            return;
+        };
+        match result {
+            Ok(output) => {
+                send_success(output, request.success);
+            }
+            Err(error) => {
+                send_failure(error, request.failure);
+            }
+        }
+    });
+
+    id
+}
+
+/// Spawns a request of a SurrealDB engine (given its id), see `spawn_request`.
+/// The events it emits carry the engine id, and it counts as in flight for the engine until completed or cancelled.
+/// Its latency is recorded under the given request name (e.g. "query" or "import").
+pub fn spawn_engine_request<F>(
+    id: i32,
+    request: &str,
+    success: SuccessAction,
+    failure: FailureAction,
+    future: F,
+) -> u64
+where
+    F: Future<Output = anyhow::Result<Vec<u8>>> + Send + 'static,
+{
+    spawn_request(
+        success,
+        failure,
+        with_engine_id(id, track_request(id, request.to_string(), future)),
+    )
+}
+
+/// Cancels every pending request, e.g. before shutting the runtime down.
+pub fn cancel_all_requests() {
+    let ids = REQUESTS
+        .iter()
+        .map(|request| *request.key())
+        .collect::<Vec<_>>();
+    for id in ids {
+        cancel_request(id);
+    }
+}
+
+/// Cancels a pending request (given its id), dropping its work along with any implicit transaction.
+/// The failure callback of the request is invoked with a "Cancelled" error.
+/// Does nothing if the request has already completed.
+#[unsafe(no_mangle)]
+pub extern "C" fn cancel_request(id: u64) {
+    if let Some((_, request)) = REQUESTS.remove(&id) {
+        request.abort.abort();
+        send_failure(EmbeddedError::cancelled(REQUEST_CANCELLED), request.failure);
+    }
+}
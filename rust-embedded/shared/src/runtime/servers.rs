@@ -0,0 +1,88 @@
+use std::sync::atomic::{AtomicU64, Ordering};
+
+use dashmap::DashMap;
+use once_cell::sync::Lazy;
+use surrealdb::rpc::format::cbor::encode;
+use surrealdb_types::{Object, Value};
+
+use crate::app::server::DebugServer;
+use crate::bindgen::callback::{FailureAction, SuccessAction};
+use crate::bindgen::csharp_to_rust::convert_csharp_to_rust_bytes;
+use crate::models::error::EmbeddedError;
+use crate::models::serve_options::ServeOptions;
+
+use super::engines::ENGINES;
+use super::requests::{spawn_engine_request, spawn_request};
+
+static NEXT_SERVER_ID: AtomicU64 = AtomicU64::new(1);
+
+/// Debug servers started via `serve`, until stopped
+static SERVERS: Lazy<DashMap<u64, DebugServer>> = Lazy::new(DashMap::new);
+
+/// Stops the debug servers (of the matching engines), e.g. before disposing them.
+pub async fn stop_servers(predicate: impl Fn(i32) -> bool) {
+    let server_ids = SERVERS
+        .iter()
+        .filter(|server| predicate(server.engine_id()))
+        .map(|server| *server.key())
+        .collect::<Vec<_>>();
+    for server_id in server_ids {
+        if let Some((_, server)) = SERVERS.remove(&server_id) {
+            server.stop().await;
+        }
+    }
+}
+
+/// # Safety
+///
+/// Serves a SurrealDB engine (given its id) over the `/rpc` (WebSocket, CBOR or JSON) and `/sql` (HTTP) endpoints
+/// of SurrealDB, so that tools such as Surrealist can inspect it while debugging.
+/// The options (`address`, `auth`) are CBOR encoded, an empty buffer standing for the default options:
+/// listening on "127.0.0.1:8000" and requiring clients to sign in.
+/// With `auth` disabled, every client is given owner rights, so no untrusted client should be able to reach the address.
+/// Sends `{ id, address }` to the success callback, the server id being used to stop it (see `stop_serving`).
+/// Returns the request id, which can be used to cancel the request.
+#[unsafe(no_mangle)]
+pub unsafe extern "C" fn serve(
+    id: i32,
+    bytes: *const u8,
+    len: usize,
+    success: SuccessAction,
+    failure: FailureAction,
+) -> u64 {
+    let opts_bytes = unsafe { convert_csharp_to_rust_bytes(bytes, len) };
+
+    spawn_engine_request(id, "serve", success, failure, async move {
+        let options = ServeOptions::from_bytes(&opts_bytes)?;
+        if !ENGINES.contains(id).await {
+            return Err(EmbeddedError::engine_missing().into());
+        }
+
+        let server = DebugServer::start(id, options).await?;
+        let address = server.address();
+        let server_id = NEXT_SERVER_ID.fetch_add(1, Ordering::Relaxed);
+        SERVERS.insert(server_id, server);
+
+        let mut output = Object::new();
+        output.insert("id".to_string(), Value::from_t(server_id as i64));
+        output.insert("address".to_string(), Value::String(address.to_string()));
+        encode(Value::Object(output))
+    })
+}
+
+/// Stops a debug server (given its id), closing its connections.
+/// Does nothing if the server has already been stopped, e.g. when its engine was disposed.
+/// Returns the request id, which can be used to cancel the request.
+#[unsafe(no_mangle)]
+pub extern "C" fn stop_serving(
+    server_id: u64,
+    success: SuccessAction,
+    failure: FailureAction,
+) -> u64 {
+    spawn_request(success, failure, async move {
+        if let Some((_, server)) = SERVERS.remove(&server_id) {
+            server.stop().await;
+        }
+        Ok(vec![])
+    })
+}
@@ -1,5 +1 @@
-pub use shared::apply_connect;
-pub use shared::bindgen::free::free_u8_buffer;
-pub use shared::execute;
-pub use shared::runtime::create_global_runtime;
-pub use shared::runtime::engines::dispose;
+shared::export_ffi!();
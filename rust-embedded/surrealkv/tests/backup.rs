@@ -0,0 +1,98 @@
+#![recursion_limit = "256"]
+
+#[path = "../../memory/tests/common/mod.rs"]
+mod common;
+
+use std::path::Path;
+
+use common::{block_on, connect_endpoint, execute};
+use shared::app::SurrealEmbeddedEngine;
+use surrealdb::rpc::Method;
+use surrealdb_types::{Number, Object, Value};
+
+async fn connect(path: &Path, restore: Option<&Path>) -> Result<SurrealEmbeddedEngine, String> {
+    let mut options = Object::new();
+    if let Some(restore) = restore {
+        options.insert(
+            "restore".to_string(),
+            Value::String(restore.display().to_string()),
+        );
+    }
+
+    let endpoint = format!("surrealkv://{}", path.display());
+    let engine = connect_endpoint(&endpoint, options).await?;
+    execute(
+        &engine,
+        Method::Use,
+        None,
+        vec![
+            Value::String("test".to_string()),
+            Value::String("test".to_string()),
+        ],
+    )
+    .await?;
+    Ok(engine)
+}
+
+async fn query(engine: &SurrealEmbeddedEngine, sql: &str) -> Value {
+    execute(
+        engine,
+        Method::Query,
+        None,
+        vec![Value::String(sql.to_string())],
+    )
+    .await
+    .unwrap()
+}
+
+async fn count_persons(engine: &SurrealEmbeddedEngine) -> i64 {
+    let Value::Array(results) = query(engine, "RETURN count(SELECT * FROM person)").await else {
+        panic!("Expected the query results");
+    };
+    match &results[0] {
+        Value::Object(result) => match result.get("result") {
+            Some(Value::Number(Number::Int(count))) => *count,
+            result => panic!("Unexpected count: {result:?}"),
+        },
+        result => panic!("Unexpected result: {result:?}"),
+    }
+}
+
+async fn create_person(engine: &SurrealEmbeddedEngine) {
+    query(engine, "CREATE person").await;
+}
+// The runtime is global, so every scenario runs within a single test.
+#[test]
+fn backup_is_restored_on_connect() {
+    let dir = tempfile::tempdir().unwrap();
+    let (db, backup) = (dir.path().join("db"), dir.path().join("backup"));
+
+    block_on(async {
+        let engine = connect(&db, None).await.unwrap();
+        create_person(&engine).await;
+        create_person(&engine).await;
+
+        engine.backup(backup.clone()).await.unwrap();
+
+        // Records created afterwards are not part of the backup
+        create_person(&engine).await;
+        assert_eq!(count_persons(&engine).await, 3);
+
+        // An existing backup is never overwritten
+        assert!(engine.backup(backup.clone()).await.is_err());
+        engine.close().await.unwrap();
+
+        let engine = connect(&db, Some(&backup)).await.unwrap();
+        assert_eq!(count_persons(&engine).await, 2);
+        create_person(&engine).await;
+        engine.close().await.unwrap();
+
+        // The backup is left untouched, so that it can be restored again
+        let engine = connect(&db, Some(&backup)).await.unwrap();
+        assert_eq!(count_persons(&engine).await, 2);
+        engine.close().await.unwrap();
+
+        let missing = dir.path().join("missing");
+        assert!(connect(&db, Some(&missing)).await.is_err());
+    });
+}